@@ -128,6 +128,11 @@ impl AppCalc {
             self.bouton_insert(ui, "sin", "sin(", InsertKind::Func);
             self.bouton_insert(ui, "cos", "cos(", InsertKind::Func);
             self.bouton_insert(ui, "tan", "tan(", InsertKind::Func);
+            self.bouton_insert(ui, "abs", "abs(", InsertKind::Func);
+            self.bouton_insert(ui, "⌊x⌋", "floor(", InsertKind::Func);
+            // postfixé : collé à la valeur, comme ")"
+            self.bouton_insert(ui, "n!", "!", InsertKind::CloseParen);
+            self.bouton_insert(ui, "C(n,k)", "binom(", InsertKind::Func);
 
             ui.separator();
 
@@ -198,7 +203,9 @@ impl AppCalc {
         }
 
         // Retire tokens connus
-        for pat in ["sqrt(", "sin(", "cos(", "tan(", "pi"] {
+        for pat in [
+            "sqrt(", "sin(", "cos(", "tan(", "abs(", "floor(", "binom(", "pi",
+        ] {
             if self.entree.ends_with(pat) {
                 for _ in 0..pat.chars().count() {
                     self.entree.pop();
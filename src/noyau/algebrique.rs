@@ -0,0 +1,329 @@
+// src/noyau/algebrique.rs
+//
+// Polynôme minimal d’un nombre algébrique (certificat exact)
+// ----------------------------------------------------------
+// Récursion sur l’expression (forme exacte du pipeline) :
+// - q rationnel           : x - q
+// - √a, ⁿ√a               : p_a(x^n)
+// - a ± b, a·b, a/b, a^n  : résultants (polynome.rs) ; raccourcis si un côté est rationnel
+// - cos/sin/tan(qπ)       : Φ_n (cyclotomique) → Ψ_n (2·cos(2π/n)), sin θ = cos(π/2 - θ),
+//                           tan = sin/cos
+// À chaque étape, le polynôme annulateur est factorisé dans Z[x] (facteurs_polynome.rs)
+// et l’on garde LE facteur qui s’annule en α, choisi par encadrement certifié de α
+// (arithmétique d’intervalles rationnels, précision croissante).
+//
+// Exemple : cos(2π/7) => 8x^3+4x^2-4x-1
+//
+// IMPORTANT (SAFE):
+// - Aucun flottant : intervalles à bornes rationnelles arrondies vers l’extérieur.
+// - Un facteur n’est écarté que si son image de l’intervalle exclut 0 (certain).
+// - Plusieurs facteurs encore possibles à la précision maximale => Err (jamais de choix au hasard).
+// - Degré borné (DEGRE_MAX) : les résultants grossissent vite (deg = da·db).
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, ToPrimitive, Zero};
+
+use super::expr::{mod_rationnel, Expr};
+use super::facteurs_polynome::factorise_polynome;
+use super::polynome::{annulateur_produit, annulateur_puissance, annulateur_somme, Polynome};
+use super::signe::{encadre, Intervalle};
+
+/// Degré maximal d’un polynôme annulateur intermédiaire (au-delà, la recombinaison
+/// de Zassenhaus explose : √2+√3+√5+√7+√11, degré 32, ne termine pas en pratique).
+pub const DEGRE_MAX: usize = 24;
+
+/// Précisions successives (chiffres décimaux) pour séparer les facteurs candidats.
+const PRECISIONS: [usize; 6] = [30, 60, 120, 240, 480, 960];
+
+/* ------------------------ API ------------------------ */
+
+/// Polynôme minimal de `e` : primitif dans Z[x], irréductible, dominant > 0.
+pub fn polynome_minimal(e: &Expr) -> Result<Polynome, String> {
+    match e {
+        Expr::Rat(r) => Ok(Polynome::nouveau(vec![-r.clone(), BigRational::one()]).primitif()),
+
+        Expr::Sqrt(a) => {
+            let pa = polynome_minimal(a)?;
+            borne_degre(pa.degre() * 2)?;
+            choisit_facteur(&pa.en_puissance(2), e)
+        }
+        Expr::Root(a, k) => {
+            let pa = polynome_minimal(a)?;
+            borne_degre(pa.degre() * *k as usize)?;
+            choisit_facteur(&pa.en_puissance(*k as usize), e)
+        }
+
+        Expr::Add(a, b) => somme(a, b, false, e),
+        Expr::Sub(a, b) => somme(a, b, true, e),
+        Expr::Mul(a, b) => produit(a, b, false, e),
+        Expr::Div(a, b) => produit(a, b, true, e),
+
+        Expr::PowInt(a, n) => {
+            if *n == 0 {
+                return polynome_minimal(&Expr::Rat(BigRational::one()));
+            }
+            let mut pa = polynome_minimal(a)?;
+            if *n < 0 {
+                pa = inverse(&pa)?;
+            }
+            let n = n.unsigned_abs() as usize;
+            if n == 1 {
+                return Ok(pa);
+            }
+            choisit_facteur(&annulateur_puissance(&pa, n), e)
+        }
+
+        Expr::Cos(x) => cosinus(x, false),
+        Expr::Sin(x) => cosinus(x, true),
+        Expr::Tan(x) => {
+            let quotient = Expr::Div(
+                Box::new(Expr::Sin(x.clone())),
+                Box::new(Expr::Cos(x.clone())),
+            );
+            polynome_minimal(&quotient)
+        }
+
+        Expr::Pi => Err("polynôme minimal : π est transcendant".into()),
+        Expr::Var(v) => Err(format!("polynôme minimal : variable libre {v}")),
+        Expr::Indefini => Err("polynôme minimal : valeur indéfinie".into()),
+        _ => Err("polynôme minimal : expression non algébrique reconnue".into()),
+    }
+}
+
+/* ------------------------ Opérations ------------------------ */
+
+fn borne_degre(d: usize) -> Result<(), String> {
+    if d > DEGRE_MAX {
+        return Err(format!("polynôme minimal : degré {d} > {DEGRE_MAX}"));
+    }
+    Ok(())
+}
+
+/// Polynôme de 1/α (α ≠ 0).
+fn inverse(p: &Polynome) -> Result<Polynome, String> {
+    if p.coeff(0).is_zero() {
+        return Err("polynôme minimal : division par zéro".into());
+    }
+    Ok(p.reciproque().primitif())
+}
+
+/// a + b (ou a - b).
+fn somme(a: &Expr, b: &Expr, moins: bool, e: &Expr) -> Result<Polynome, String> {
+    let pa = polynome_minimal(a)?;
+    let mut pb = polynome_minimal(b)?;
+    if moins {
+        pb = pb.oppose_variable().primitif();
+    }
+
+    // translation rationnelle : p(x - r) reste irréductible
+    if let Some(r) = racine_rationnelle(&pb) {
+        return Ok(translate(&pa, &r));
+    }
+    if let Some(r) = racine_rationnelle(&pa) {
+        return Ok(translate(&pb, &r));
+    }
+
+    borne_degre(pa.degre() * pb.degre())?;
+    choisit_facteur(&annulateur_somme(&pa, &pb), e)
+}
+
+/// a · b (ou a / b).
+fn produit(a: &Expr, b: &Expr, divise: bool, e: &Expr) -> Result<Polynome, String> {
+    let pa = polynome_minimal(a)?;
+    let mut pb = polynome_minimal(b)?;
+    if divise {
+        pb = inverse(&pb)?;
+    }
+
+    // homothétie rationnelle : p(x/r) reste irréductible
+    if let Some(r) = racine_rationnelle(&pa) {
+        return Ok(homothetie(&pb, &r));
+    }
+    if let Some(r) = racine_rationnelle(&pb) {
+        return Ok(homothetie(&pa, &r));
+    }
+
+    borne_degre(pa.degre() * pb.degre())?;
+    choisit_facteur(&annulateur_produit(&pa, &pb), e)
+}
+
+/// Racine d’un polynôme de degré 1.
+fn racine_rationnelle(p: &Polynome) -> Option<BigRational> {
+    (p.degre() == 1).then(|| -p.coeff(0) / p.coeff(1))
+}
+
+/// Polynôme de α + r : p(x - r).
+fn translate(p: &Polynome, r: &BigRational) -> Polynome {
+    p.compose(&Polynome::nouveau(vec![-r.clone(), BigRational::one()]))
+        .primitif()
+}
+
+/// Polynôme de r·α : p(x/r) (r = 0 : x).
+fn homothetie(p: &Polynome, r: &BigRational) -> Polynome {
+    if r.is_zero() {
+        return Polynome::x();
+    }
+    let mut rk = BigRational::one();
+    let mut c = Vec::with_capacity(p.coeffs.len());
+    for a in &p.coeffs {
+        c.push(a / &rk);
+        rk *= r;
+    }
+    Polynome::nouveau(c).primitif()
+}
+
+/* ------------------------ Trigonométrie (qπ) ------------------------ */
+
+/// cos(qπ) (ou sin(qπ) = cos((1/2 - q)π)) : Ψ_n(2x) avec qπ = 2πk/n, pgcd(k, n) = 1.
+fn cosinus(x: &Expr, sinus: bool) -> Result<Polynome, String> {
+    let mut q = x
+        .as_coeff_pi_ext()
+        .ok_or("polynôme minimal : angle non rationnel·π (transcendant en général)")?;
+    if sinus {
+        q = BigRational::new(1.into(), 2.into()) - q;
+    }
+    let q = mod_rationnel(&q, 2);
+
+    // qπ = 2π·(q/2) ; n = dénominateur réduit de q/2
+    let n = (q / BigRational::from_integer(2.into()))
+        .denom()
+        .to_usize()
+        .filter(|n| n / 2 <= 4 * DEGRE_MAX)
+        .ok_or("polynôme minimal : dénominateur d’angle trop grand")?;
+    let psi = psi(n);
+    borne_degre(psi.degre())?;
+
+    // 2·cos θ racine de Ψ_n => cos θ racine de Ψ_n(2x)
+    Ok(psi
+        .compose(&Polynome::nouveau(vec![
+            BigRational::zero(),
+            BigRational::from_integer(2.into()),
+        ]))
+        .primitif())
+}
+
+/// Φ_n : x^n - 1 divisé par les Φ_d (d | n, d < n).
+fn cyclotomique(n: usize) -> Polynome {
+    let mut c = vec![BigRational::zero(); n + 1];
+    c[0] = -BigRational::one();
+    c[n] = BigRational::one();
+    let mut p = Polynome::nouveau(c);
+    for d in (1..n).filter(|d| n.is_multiple_of(*d)) {
+        p = p.divrem(&cyclotomique(d)).0;
+    }
+    p
+}
+
+/// Ψ_n : polynôme minimal de 2·cos(2π/n).
+/// Φ_n(z) = z^m·Ψ_n(z + 1/z) (n ≥ 3) ; z^j + z^-j = D_j(z + 1/z) (Dickson).
+fn psi(n: usize) -> Polynome {
+    match n {
+        1 => return Polynome::depuis_entiers(&[BigInt::from(-2), BigInt::one()]),
+        2 => return Polynome::depuis_entiers(&[BigInt::from(2), BigInt::one()]),
+        _ => {}
+    }
+    let phi = cyclotomique(n);
+    let m = phi.degre() / 2;
+
+    let mut res = Polynome::constante(phi.coeff(m));
+    let mut d_prec = Polynome::constante(BigRational::from_integer(2.into())); // D_0
+    let mut d = Polynome::x(); // D_1
+    for j in 1..=m {
+        res = res.add(&d.mul_scalaire(&phi.coeff(m + j)));
+        let suivant = Polynome::x().mul(&d).sub(&d_prec);
+        d_prec = d;
+        d = suivant;
+    }
+    res.primitif()
+}
+
+/* ------------------------ Choix du facteur ------------------------ */
+
+/// Factorise `candidat` et garde l’unique facteur irréductible qui s’annule en `e`.
+fn choisit_facteur(candidat: &Polynome, e: &Expr) -> Result<Polynome, String> {
+    let (_, facteurs) = factorise_polynome(candidat)?;
+    let mut restants: Vec<Polynome> = facteurs.into_iter().map(|(g, _)| g).collect();
+
+    for digits in PRECISIONS {
+        if restants.len() <= 1 {
+            break;
+        }
+        let Some(iv) = encadre(e, digits) else {
+            continue;
+        };
+        restants.retain(|g| horner(g, &iv).contient_zero());
+    }
+
+    match restants.len() {
+        1 => Ok(restants.remove(0)),
+        0 => Err("polynôme minimal : aucun facteur ne s’annule (encadrement incohérent)".into()),
+        _ => Err("polynôme minimal : conjugués indiscernables à la précision maximale".into()),
+    }
+}
+
+/* ------------------------ Intervalles certifiés (signe.rs) ------------------------ */
+
+/// Image d’un intervalle par un polynôme (Horner en intervalles, exact).
+fn horner(p: &Polynome, x: &Intervalle) -> Intervalle {
+    p.coeffs
+        .iter()
+        .rev()
+        .fold(Intervalle::point(BigRational::zero()), |acc, c| {
+            acc.mul(x).add(&Intervalle::point(c.clone()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noyau::eval::forme_exacte;
+
+    fn pm(s: &str) -> String {
+        polynome_minimal(&forme_exacte(s).unwrap().0)
+            .unwrap()
+            .format("x")
+    }
+
+    #[test]
+    fn radicaux_et_trig() {
+        assert_eq!(pm("cos(2*pi/7)"), "8x^3+4x^2-4x-1");
+        assert_eq!(pm("sqrt(2)+sqrt(3)"), "x^4-10x^2+1");
+        assert_eq!(pm("cbrt(2)+1"), "x^3-3x^2+3x-3");
+        assert_eq!(pm("3/4"), "4x-3");
+        assert_eq!(pm("sin(pi/5)"), "16x^4-20x^2+5");
+        assert_eq!(pm("(1+sqrt(5))/2"), "x^2-x-1");
+        assert_eq!(pm("tan(pi/7)"), "x^6-21x^4+35x^2-7");
+    }
+
+    #[test]
+    fn degre_borne() {
+        // au plafond (3·2·2·2 = 24)
+        let p = polynome_minimal(&forme_exacte("cbrt(2)+sqrt(2)+sqrt(3)+sqrt(5)").unwrap().0);
+        assert_eq!(p.unwrap().degre(), DEGRE_MAX);
+
+        // au-delà (degré 27) : Err, pas de calcul
+        let e = forme_exacte("cbrt(2)+cbrt(3)+cbrt(5)").unwrap().0;
+        assert!(polynome_minimal(&e).is_err());
+    }
+
+    // temps mur dépendant de la machine : hors suite par défaut (cargo test -- --ignored)
+    #[test]
+    #[ignore]
+    fn degre_borne_rapide() {
+        use std::time::{Duration, Instant};
+
+        let debut = Instant::now();
+        let p = polynome_minimal(&forme_exacte("cbrt(2)+sqrt(2)+sqrt(3)+sqrt(5)").unwrap().0);
+        assert!(p.is_ok());
+        let e = forme_exacte("cbrt(2)+cbrt(3)+cbrt(5)").unwrap().0;
+        assert!(polynome_minimal(&e).is_err());
+        assert!(debut.elapsed() < Duration::from_secs(20));
+    }
+
+    #[test]
+    fn hors_domaine() {
+        assert!(polynome_minimal(&forme_exacte("pi").unwrap().0).is_err());
+        assert!(polynome_minimal(&forme_exacte("x+1").unwrap().0).is_err());
+    }
+}
@@ -0,0 +1,309 @@
+// src/noyau/arith.rs
+//
+// Arithmétique entière exacte (BigInt, sans flottants)
+// ----------------------------------------------------
+// - pgcd / ppcm / mod : étendus aux rationnels (pgcd(a/b, c/d) = pgcd(a,c)/ppcm(b,d))
+// - primalité : Miller–Rabin sur les 13 premiers nombres premiers comme bases
+//   (déterministe pour n < 3,3·10^24, “probable premier” au-delà)
+// - factorisation : division par petits premiers, puis Pollard rho (Brent)
+//
+// IMPORTANT (SAFE):
+// - Pas d’horloge (wasm) : le “budget temps” de Pollard rho est un nombre
+//   d’itérations déterministe. Un cofacteur composé non cassé dans le budget est
+//   rendu tel quel dans `non_resolus` (jamais présenté comme premier).
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+/// Bases de Miller–Rabin (les 13 premiers nombres premiers).
+const BASES_MR: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Borne de validité déterministe des bases ci-dessus : 3 317 044 064 679 887 385 961 981.
+const BORNE_MR_DETERMINISTE: &str = "3317044064679887385961981";
+
+/// Division d’essai jusqu’à cette borne avant Pollard rho.
+const BORNE_ESSAIS: u32 = 1000;
+
+/// Budget total d’itérations de Pollard rho pour une factorisation.
+const BUDGET_RHO: u64 = 400_000;
+
+/* ------------------------ pgcd / ppcm / mod ------------------------ */
+
+/// pgcd rationnel (≥ 0) : pgcd(a/b, c/d) = pgcd(a,c) / ppcm(b,d).
+pub fn pgcd_rationnel(x: &BigRational, y: &BigRational) -> BigRational {
+    let n = x.numer().gcd(y.numer());
+    let d = x.denom().lcm(y.denom());
+    BigRational::new(n, d)
+}
+
+/// ppcm rationnel (≥ 0) : ppcm(a/b, c/d) = ppcm(a,c) / pgcd(b,d) ; 0 si l’un est nul.
+pub fn ppcm_rationnel(x: &BigRational, y: &BigRational) -> BigRational {
+    if x.is_zero() || y.is_zero() {
+        return BigRational::zero();
+    }
+    let n = x.numer().lcm(y.numer());
+    let d = x.denom().gcd(y.denom());
+    BigRational::new(n, d)
+}
+
+/// a mod m = a - m·⌊a/m⌋ (signe de m, comme la division euclidienne pour m > 0).
+/// None si m = 0.
+pub fn mod_rationnel_exact(a: &BigRational, m: &BigRational) -> Option<BigRational> {
+    if m.is_zero() {
+        return None;
+    }
+    let q = (a / m).floor();
+    Some(a - m * q)
+}
+
+/* ------------------------ Primalité ------------------------ */
+
+/// Résultat d’un test de primalité.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Primalite {
+    Compose,
+    /// Certifié (n < 3,3·10^24 : bases déterministes).
+    Premier,
+    /// Miller–Rabin passé sur toutes les bases, n au-delà de la borne déterministe.
+    ProbablePremier,
+}
+
+/// Miller–Rabin (n quelconque ; n ≤ 1 : composé par convention).
+pub fn primalite(n: &BigInt) -> Primalite {
+    if n <= &BigInt::one() {
+        return Primalite::Compose;
+    }
+    for p in BASES_MR {
+        let p = BigInt::from(p);
+        if n == &p {
+            return Primalite::Premier;
+        }
+        if (n % &p).is_zero() {
+            return Primalite::Compose;
+        }
+    }
+
+    // n - 1 = d·2^s, d impair
+    let n1: BigInt = n - 1;
+    let s = n1.trailing_zeros().unwrap_or(0);
+    let d = &n1 >> s;
+
+    'bases: for a in BASES_MR {
+        let mut x = BigInt::from(a).modpow(&d, n);
+        if x.is_one() || x == n1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = (&x * &x) % n;
+            if x == n1 {
+                continue 'bases;
+            }
+        }
+        return Primalite::Compose;
+    }
+
+    let borne = BigInt::parse_bytes(BORNE_MR_DETERMINISTE.as_bytes(), 10).unwrap();
+    if n < &borne {
+        Primalite::Premier
+    } else {
+        Primalite::ProbablePremier
+    }
+}
+
+/// Raccourci : premier (certifié ou probable).
+pub fn est_premier(n: &BigInt) -> bool {
+    primalite(n) != Primalite::Compose
+}
+
+/* ------------------------ Factorisation ------------------------ */
+
+/// n = ∏ p^e · ∏ non_resolus (n > 0).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Factorisation {
+    /// Facteurs premiers (ou probables premiers) triés, avec exposants.
+    pub premiers: Vec<(BigInt, u32)>,
+    /// Cofacteurs composés non cassés dans le budget (triés).
+    pub non_resolus: Vec<BigInt>,
+}
+
+/// Factorise |n| (n ≠ 0). Le signe est laissé à l’appelant.
+pub fn factorise(n: &BigInt) -> Factorisation {
+    let mut reste = n.abs();
+    let mut premiers: Vec<(BigInt, u32)> = Vec::new();
+
+    // 1) petits premiers
+    let mut p: u32 = 2;
+    while p <= BORNE_ESSAIS {
+        let bp = BigInt::from(p);
+        if &bp * &bp > reste {
+            break;
+        }
+        let mut e = 0;
+        while (&reste % &bp).is_zero() {
+            reste /= &bp;
+            e += 1;
+        }
+        if e > 0 {
+            premiers.push((bp, e));
+        }
+        p = if p == 2 { 3 } else { p + 2 };
+    }
+
+    // 2) cofacteurs : premier, ou cassé par Pollard rho
+    let mut non_resolus = Vec::new();
+    let mut budget = BUDGET_RHO;
+    let mut pile = vec![reste];
+    while let Some(m) = pile.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if est_premier(&m) {
+            ajoute_facteur(&mut premiers, m, 1);
+            continue;
+        }
+        match pollard_rho(&m, &mut budget) {
+            Some(f) => {
+                let g = &m / &f;
+                pile.push(f);
+                pile.push(g);
+            }
+            None => non_resolus.push(m),
+        }
+    }
+
+    premiers.sort();
+    non_resolus.sort();
+    Factorisation {
+        premiers,
+        non_resolus,
+    }
+}
+
+fn ajoute_facteur(premiers: &mut Vec<(BigInt, u32)>, p: BigInt, e: u32) {
+    match premiers.iter_mut().find(|(q, _)| *q == p) {
+        Some((_, k)) => *k += e,
+        None => premiers.push((p, e)),
+    }
+}
+
+/// Pollard rho (variante de Brent, pgcd par paquets). n composé impair > 1.
+/// Consomme `budget` ; None si épuisé sans facteur non trivial.
+fn pollard_rho(n: &BigInt, budget: &mut u64) -> Option<BigInt> {
+    if n.is_even() {
+        return Some(BigInt::from(2));
+    }
+    const PAQUET: u64 = 128;
+
+    let mut c = BigInt::one();
+    while *budget > 0 {
+        let f = |x: &BigInt| (x * x + &c) % n;
+        let mut y = BigInt::from(2);
+        let mut r: u64 = 1;
+        let mut q = BigInt::one();
+        let mut g = BigInt::one();
+        let mut x = y.clone();
+        let mut ys = y.clone();
+
+        while g.is_one() {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g.is_one() {
+                ys = y.clone();
+                let pas = PAQUET.min(r - k);
+                for _ in 0..pas {
+                    y = f(&y);
+                    q = (q * (&x - &y).abs()) % n;
+                }
+                g = q.gcd(n);
+                k += pas;
+                *budget = budget.saturating_sub(pas);
+            }
+            r *= 2;
+            if *budget == 0 && g.is_one() {
+                return None;
+            }
+        }
+
+        // paquet trop grossier (g = n) : on repasse pas à pas
+        if &g == n {
+            loop {
+                ys = f(&ys);
+                g = (&x - &ys).abs().gcd(n);
+                if !g.is_one() {
+                    break;
+                }
+            }
+        }
+
+        if &g != n {
+            return Some(g);
+        }
+        c += 1;
+    }
+    None
+}
+
+/* ------------------------ Affichage ------------------------ */
+
+/// 360 => "2^3·3^2·5" ; -12 => "-2^2·3" ; cofacteurs non cassés entre parenthèses.
+pub fn format_factorisation(n: &BigInt, f: &Factorisation) -> String {
+    if n.abs().is_one() {
+        return n.to_string();
+    }
+    let mut morceaux: Vec<String> = f
+        .premiers
+        .iter()
+        .map(|(p, e)| {
+            if *e == 1 {
+                p.to_string()
+            } else {
+                format!("{p}^{e}")
+            }
+        })
+        .collect();
+    morceaux.extend(f.non_resolus.iter().map(|m| format!("({m})")));
+
+    let signe = if n.is_negative() { "-" } else { "" };
+    format!("{signe}{}", morceaux.join("·"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        BigInt::parse_bytes(s.as_bytes(), 10).unwrap()
+    }
+
+    #[test]
+    fn primalite_petits_et_grands() {
+        assert_eq!(primalite(&BigInt::from(97)), Primalite::Premier);
+        assert_eq!(primalite(&BigInt::from(1)), Primalite::Compose);
+        // nombre de Carmichael
+        assert_eq!(primalite(&BigInt::from(561)), Primalite::Compose);
+        assert_eq!(primalite(&big("1000000007")), Primalite::Premier);
+        // 2^89 - 1 (Mersenne premier) : au-delà de la borne déterministe
+        assert_eq!(
+            primalite(&big("618970019642690137449562111")),
+            Primalite::ProbablePremier
+        );
+    }
+
+    #[test]
+    fn factorisation_rho() {
+        let n = big("1000000016000000063"); // 1000000007 · 1000000009
+        let f = factorise(&n);
+        assert!(f.non_resolus.is_empty());
+        assert_eq!(format_factorisation(&n, &f), "1000000007·1000000009");
+
+        let n = BigInt::from(360);
+        assert_eq!(format_factorisation(&n, &factorise(&n)), "2^3·3^2·5");
+        let n = BigInt::from(-12);
+        assert_eq!(format_factorisation(&n, &factorise(&n)), "-2^2·3");
+    }
+}
@@ -5,14 +5,29 @@
 // - suppression neutres (x+0, x*1, etc.)
 // - extraction / remontée du signe (Sub(0,x) comme “-x” canon)
 // - regroupement des rationnels
+// - regroupement des puissances d’une même base (x·x => x², √2/√2 => 1, √2·√3 => √6) ;
+//   x/x n’est annulé que par fractions_rationnelles.rs (point exclu x ≠ 0 tracé)
 // - tri déterministe des termes/facteurs (ordre total)
 // - reconstruction “jolie” : utilise Sub quand le terme suivant est négatif
-// - simplif √(n) -> a*√b (extraction des carrés parfaits) pour n entier ≥ 0
+// - simplif √(n) -> a*√b (extraction des carrés parfaits) pour n rationnel ≥ 0
+//   (généralisé : ᵏ√(n) -> a*ᵏ√b, racine impaire d’un négatif -> -ᵏ√|n|)
+// - (ᵏ√r)^n -> r^(n div k) * ᵏ√(r^(n mod k))
+// - racines de rationnels fusionnées sous le ppcm des indices (∛2·⁶√2 -> √2), indice
+//   réduit (∜4 -> √2), racines emboîtées aplaties (√(√2) -> ∜2)
+// - √(a + b√c) dénoué quand a² - b²c est un carré (√(3+2√2) -> 1+√2)
+// - polynômes en variables : forme développée si plus courte ((x+1)^2 - x^2 -> 2x+1)
 //
 // Note : on reste volontairement “local” (pas d’identités trig générales ici).
 
-use crate::noyau::expr::Expr;
+use crate::noyau::arith::factorise;
+use crate::noyau::expr::{
+    map_matrice, rational_pow_borne, rational_pow_int, Expr, RACINE_INDICE_MAX,
+};
+use crate::noyau::polynome_multi::PolyMulti;
+use crate::noyau::radicaux::{racine_canon, SommeRadicaux};
+use crate::noyau::signe::signe_certifie;
 use num_bigint::BigInt;
+use num_integer::Integer;
 use num_rational::BigRational;
 use num_traits::{One, Signed, Zero};
 use std::cmp::Ordering;
@@ -23,22 +38,86 @@ pub fn canon_expr(e: Expr) -> Expr {
     match e {
         Rat(_) | Pi | Indefini | Var(_) => e,
 
-        Sqrt(x) => canon_sqrt(canon_expr(*x)),
-        PowInt(x, n) => canon_pow(canon_expr(*x), n),
+        Sqrt(x) => canon_racine(canon_expr(*x), 2),
+        Root(x, k) => canon_racine(canon_expr(*x), k),
+        PowInt(x, n) => forme_polynomiale(canon_pow(canon_expr(*x), n)),
+        Pow(x, e) => canon_pow_sym(canon_expr(*x), canon_expr(*e)),
 
         Sin(x) => Sin(Box::new(canon_expr(*x))),
         Cos(x) => Cos(Box::new(canon_expr(*x))),
         Tan(x) => Tan(Box::new(canon_expr(*x))),
 
-        Add(a, b) => canon_addsub(Add(Box::new(canon_expr(*a)), Box::new(canon_expr(*b)))),
-        Sub(a, b) => canon_addsub(Sub(Box::new(canon_expr(*a)), Box::new(canon_expr(*b)))),
+        Abs(x) => Abs(Box::new(canon_expr(*x))),
+        Sign(x) => Sign(Box::new(canon_expr(*x))),
+        Floor(x) => Floor(Box::new(canon_expr(*x))),
+        Ceil(x) => Ceil(Box::new(canon_expr(*x))),
+        Round(x) => Round(Box::new(canon_expr(*x))),
+        Frac(x) => Frac(Box::new(canon_expr(*x))),
+
+        Fact(x) => Fact(Box::new(canon_expr(*x))),
+        FactDouble(x) => FactDouble(Box::new(canon_expr(*x))),
+        Binom(n, k) => Binom(Box::new(canon_expr(*n)), Box::new(canon_expr(*k))),
+        Arrang(n, k) => Arrang(Box::new(canon_expr(*n)), Box::new(canon_expr(*k))),
+
+        Pgcd(a, b) => Pgcd(Box::new(canon_expr(*a)), Box::new(canon_expr(*b))),
+        Ppcm(a, b) => Ppcm(Box::new(canon_expr(*a)), Box::new(canon_expr(*b))),
+        Modulo(a, m) => Modulo(Box::new(canon_expr(*a)), Box::new(canon_expr(*m))),
+        Egal(a, b) => Egal(Box::new(canon_expr(*a)), Box::new(canon_expr(*b))),
+        Matrice(l) => map_matrice(l, canon_expr),
+        Matriciel(op, x) => Matriciel(op, Box::new(canon_expr(*x))),
+
+        Add(a, b) => forme_polynomiale(canon_addsub(Add(
+            Box::new(canon_expr(*a)),
+            Box::new(canon_expr(*b)),
+        ))),
+        Sub(a, b) => forme_polynomiale(canon_addsub(Sub(
+            Box::new(canon_expr(*a)),
+            Box::new(canon_expr(*b)),
+        ))),
+
+        Mul(a, b) => forme_polynomiale(canon_mul(Mul(
+            Box::new(canon_expr(*a)),
+            Box::new(canon_expr(*b)),
+        ))),
+
+        Div(a, b) => forme_polynomiale(canon_div(Div(
+            Box::new(canon_expr(*a)),
+            Box::new(canon_expr(*b)),
+        ))),
+    }
+}
 
-        Mul(a, b) => canon_mul(Mul(Box::new(canon_expr(*a)), Box::new(canon_expr(*b)))),
+/* ------------------------ forme polynomiale ------------------------ */
 
-        Div(a, b) => canon_div(Div(Box::new(canon_expr(*a)), Box::new(canon_expr(*b)))),
+/// Feuilles rationnelles ou variables uniquement : forme développée (polynome_multi.rs)
+/// si elle est strictement plus courte ((x+1)^2 - x^2 => 2x+1), sinon on garde e
+/// ((x+1)^2 reste factorisé).
+fn forme_polynomiale(e: Expr) -> Expr {
+    if !contient_variable(&e) {
+        return e;
+    }
+    match PolyMulti::depuis_expr(&e) {
+        Ok(p) => {
+            let developpe = p.vers_expr();
+            if taille(&developpe) < taille(&e) {
+                developpe
+            } else {
+                e
+            }
+        }
+        Err(_) => e,
     }
 }
 
+fn contient_variable(e: &Expr) -> bool {
+    matches!(e, Expr::Var(_)) || e.enfants().into_iter().any(contient_variable)
+}
+
+/// Nombre de nœuds.
+fn taille(e: &Expr) -> usize {
+    1 + e.enfants().into_iter().map(taille).sum::<usize>()
+}
+
 /* ------------------------ utilitaires signe ------------------------ */
 
 fn is_zero(e: &Expr) -> bool {
@@ -74,12 +153,17 @@ fn rang(e: &Expr) -> u8 {
     match e {
         Rat(_) => 0,
         Var(_) => 1, // ← NOUVEAU
-        Sqrt(_) => 2,
+        Sqrt(_) | Root(_, _) => 2,
         Pi => 3,
-        PowInt(_, _) => 4,
+        PowInt(_, _) | Pow(_, _) => 4,
         Sin(_) | Cos(_) | Tan(_) => 5,
+        Abs(_) | Sign(_) | Floor(_) | Ceil(_) | Round(_) | Frac(_) => 5,
+        Fact(_) | FactDouble(_) | Binom(_, _) | Arrang(_, _) => 5,
+        Pgcd(_, _) | Ppcm(_, _) | Modulo(_, _) => 5,
         Mul(_, _) | Div(_, _) => 6,
         Add(_, _) | Sub(_, _) => 7,
+        Egal(_, _) => 8,
+        Matrice(_) | Matriciel(_, _) => 9,
         Indefini => 255,
     }
 }
@@ -97,16 +181,41 @@ fn key_string(e: &Expr) -> String {
         Indefini => "INDEF".to_string(),
 
         Sqrt(x) => format!("SQRT({})", key_string(x)),
+        Root(x, k) => format!("ROOT({},{k})", key_string(x)),
         PowInt(x, n) => format!("POW({},{n})", key_string(x)),
+        Pow(x, e) => format!("POWE({},{})", key_string(x), key_string(e)),
 
         Sin(x) => format!("SIN({})", key_string(x)),
         Cos(x) => format!("COS({})", key_string(x)),
         Tan(x) => format!("TAN({})", key_string(x)),
 
+        Abs(x) => format!("ABS({})", key_string(x)),
+        Sign(x) => format!("SIGN({})", key_string(x)),
+        Floor(x) => format!("FLOOR({})", key_string(x)),
+        Ceil(x) => format!("CEIL({})", key_string(x)),
+        Round(x) => format!("ROUND({})", key_string(x)),
+        Frac(x) => format!("FRAC({})", key_string(x)),
+        Fact(x) => format!("FACT({})", key_string(x)),
+        FactDouble(x) => format!("FACT2({})", key_string(x)),
+        Binom(n, k) => format!("BINOM({},{})", key_string(n), key_string(k)),
+        Arrang(n, k) => format!("ARRANG({},{})", key_string(n), key_string(k)),
+        Pgcd(a, b) => format!("GCD({},{})", key_string(a), key_string(b)),
+        Ppcm(a, b) => format!("LCM({},{})", key_string(a), key_string(b)),
+        Modulo(a, m) => format!("MOD({},{})", key_string(a), key_string(m)),
+
         Add(a, b) => format!("ADD({},{})", key_string(a), key_string(b)),
         Sub(a, b) => format!("SUB({},{})", key_string(a), key_string(b)),
         Mul(a, b) => format!("MUL({},{})", key_string(a), key_string(b)),
         Div(a, b) => format!("DIV({},{})", key_string(a), key_string(b)),
+        Egal(a, b) => format!("EQ({},{})", key_string(a), key_string(b)),
+        Matrice(l) => {
+            let lignes: Vec<String> = l
+                .iter()
+                .map(|ligne| ligne.iter().map(key_string).collect::<Vec<_>>().join(","))
+                .collect();
+            format!("MAT({})", lignes.join(";"))
+        }
+        Matriciel(op, x) => format!("{}({})", op.nom().to_uppercase(), key_string(x)),
     }
 }
 
@@ -118,38 +227,125 @@ fn cmp_expr(a: &Expr, b: &Expr) -> Ordering {
 
 /* ------------------------ Add/Sub : aplatissement + tri + reconstruction ------------------------ */
 
-fn collect_addsub(e: Expr, out: &mut Vec<Expr>) {
+/// Aplatit une somme en termes signés ; Sub(a, b) distribue le signe dans b
+/// (a - (b - c) => a - b + c), pour que les termes semblables se retrouvent.
+fn collect_addsub(e: Expr, negatif: bool, out: &mut Vec<(bool, Expr)>) {
     use Expr::*;
     match e {
         Add(a, b) => {
-            collect_addsub(*a, out);
-            collect_addsub(*b, out);
+            collect_addsub(*a, negatif, out);
+            collect_addsub(*b, negatif, out);
         }
         Sub(a, b) => {
-            collect_addsub(*a, out);
-            out.push(neg(*b));
+            collect_addsub(*a, negatif, out);
+            collect_addsub(*b, !negatif, out);
         }
-        other => out.push(other),
+        other => out.push((negatif, other)),
+    }
+}
+
+/// Terme => (coefficient rationnel, monôme) : 3√2 => (3, √2), √3/3 => (1/3, √3),
+/// 2/x => (2, 1/x). Le monôme ne porte plus de facteur rationnel.
+fn split_coeff(t: Expr) -> (BigRational, Expr) {
+    use Expr::*;
+    match t {
+        Rat(r) => (r, Rat(BigRational::one())),
+        Mul(_, _) => {
+            let mut facteurs = Vec::new();
+            collect_mul(t, &mut facteurs);
+            let mut c = BigRational::one();
+            let mut reste = Vec::with_capacity(facteurs.len());
+            for f in facteurs {
+                match f {
+                    Rat(r) => c *= r,
+                    other => reste.push(other),
+                }
+            }
+            let mono = reste
+                .into_iter()
+                .reduce(|acc, f| Mul(Box::new(acc), Box::new(f)))
+                .unwrap_or(Rat(BigRational::one()));
+            (c, mono)
+        }
+        Div(a, b) => match (*a, *b) {
+            (a, Rat(k)) if !k.is_zero() => {
+                let (c, mono) = split_coeff(a);
+                (c / k, mono)
+            }
+            (Rat(p), b) => (p, Div(Box::new(Rat(BigRational::one())), Box::new(b))),
+            (a, b) => (BigRational::one(), Div(Box::new(a), Box::new(b))),
+        },
+        other => (BigRational::one(), other),
+    }
+}
+
+/// a = c·b avec c rationnel, lu sur la structure des formes canon (monômes comparés,
+/// sans division symbolique) : sin(x) contre -2·sin(x) => -1/2 ; None sinon.
+pub(crate) fn rapport_rationnel(a: &Expr, b: &Expr) -> Option<BigRational> {
+    let (neg_a, a) = split_signe(canon_expr(a.clone()));
+    let (neg_b, b) = split_signe(canon_expr(b.clone()));
+    let (ca, ma) = split_coeff(a);
+    let (cb, mb) = split_coeff(b);
+    if cb.is_zero() || key_string(&ma) != key_string(&mb) {
+        return None;
+    }
+    let c = ca / cb;
+    Some(if neg_a != neg_b { -c } else { c })
+}
+
+/// c·monôme reconstruit en forme canon (c > 0 ; le signe est géré par l’appelant).
+fn rebuild_coeff(c: BigRational, mono: Expr) -> Expr {
+    use Expr::*;
+    if c.is_one() {
+        return mono;
+    }
+    match mono {
+        // c/x plutôt que c*(1/x)
+        Div(a, b) if is_one(&a) => canon_div(Div(Box::new(Rat(c)), b)),
+        other => canon_mul(Mul(Box::new(Rat(c)), Box::new(other))),
     }
 }
 
 fn canon_addsub(e: Expr) -> Expr {
     // On reçoit déjà des sous-termes canonisés (canon_expr).
-    let mut termes: Vec<Expr> = Vec::new();
-    collect_addsub(e, &mut termes);
+    let mut termes: Vec<(bool, Expr)> = Vec::new();
+    collect_addsub(e, false, &mut termes);
 
-    // Retirer les zéros + regrouper les rationnels.
+    // Regroupement des termes semblables : (coefficient, monôme) par clef canonique
+    // du monôme (2x + 3x => 5x, √2 + 3√2 => 4√2, A - A => 0).
+    // Les rationnels purs ont le monôme 1.
     let mut somme_rat = BigRational::zero();
-    let mut v: Vec<Expr> = Vec::with_capacity(termes.len());
-
-    for t in termes {
-        match t {
-            Expr::Rat(r) => somme_rat += r,
-            other => {
-                if !is_zero(&other) {
-                    v.push(other);
-                }
-            }
+    let mut groupes: Vec<(String, BigRational, Expr)> = Vec::new();
+
+    for (negatif, t) in termes {
+        let (neg_t, abs_t) = split_signe(t);
+        let (c, mono) = split_coeff(abs_t);
+        let c = if negatif != neg_t { -c } else { c };
+
+        if is_one(&mono) {
+            somme_rat += c;
+            continue;
+        }
+        if c.is_zero() || is_zero(&mono) {
+            continue;
+        }
+
+        let clef = key_string(&mono);
+        match groupes.iter_mut().find(|g| g.0 == clef) {
+            Some(g) => g.1 += c,
+            None => groupes.push((clef, c, mono)),
+        }
+    }
+
+    let mut v: Vec<Expr> = Vec::with_capacity(groupes.len() + 1);
+    for (_, c, mono) in groupes {
+        if c.is_zero() {
+            continue;
+        }
+        if c.is_negative() {
+            v.push(neg(rebuild_coeff(-c, mono)));
+        } else {
+            v.push(rebuild_coeff(c, mono));
         }
     }
 
@@ -190,82 +386,207 @@ fn collect_mul(e: Expr, out: &mut Vec<Expr>) {
     }
 }
 
-fn canon_mul(e: Expr) -> Expr {
-    use Expr::*;
-
-    let mut facteurs: Vec<Expr> = Vec::new();
-    collect_mul(e, &mut facteurs);
+/// Facteurs d’un produit / quotient : rationnel global + (base, exposant) symboliques.
+/// Div(a, b) est vu comme a·b^(-1) dans la même passe.
+struct Facteurs {
+    signe_neg: bool,
+    prod_rat: BigRational,
+    bases: Vec<(String, Expr, i64)>,
+    zero: bool,
+    indefini: bool,
+}
 
-    // Si un facteur est indéfini → indéfini.
-    if facteurs.iter().any(|x| matches!(x, Indefini)) {
-        return Indefini;
+impl Facteurs {
+    fn new() -> Self {
+        Facteurs {
+            signe_neg: false,
+            prod_rat: BigRational::one(),
+            bases: Vec::new(),
+            zero: false,
+            indefini: false,
+        }
     }
 
-    // Extraire signe global, regrouper rationnels, retirer *1, court-circuit *0.
-    let mut signe_neg = false;
-    let mut prod_rat = BigRational::one();
-    let mut v: Vec<Expr> = Vec::with_capacity(facteurs.len());
-
-    for f in facteurs {
-        // 0 * ... = 0
-        if is_zero(&f) {
-            return Rat(BigRational::zero());
+    /// Ajoute base^n. Exposants de signes opposés (x·x⁻¹) fusionnés seulement si la
+    /// base est prouvée non nulle (√2/√2 => 1) : x/x reste un quotient, simplifié
+    /// ensuite par fractions_rationnelles.rs avec le point exclu x ≠ 0.
+    fn ajoute_base(&mut self, base: Expr, n: i64) {
+        let clef = key_string(&base);
+        let meme_signe = self
+            .bases
+            .iter()
+            .position(|b| b.0 == clef && (b.2 > 0) == (n > 0));
+        let existant = meme_signe.or_else(|| {
+            self.bases
+                .iter()
+                .position(|b| b.0 == clef)
+                .filter(|_| base_non_nulle(&base))
+        });
+        match existant {
+            Some(i) => self.bases[i].2 = self.bases[i].2.saturating_add(n),
+            None => self.bases.push((clef, base, n)),
         }
+    }
 
-        let (neg_f, abs_f) = split_signe(f);
-        if neg_f {
-            signe_neg = !signe_neg;
+    /// Ajoute e^(±1) ; `inverse` pour un facteur de dénominateur.
+    fn ajoute(&mut self, e: Expr, inverse: bool) {
+        use Expr::*;
+        let (neg_e, abs_e) = split_signe(e);
+        if neg_e {
+            self.signe_neg = !self.signe_neg;
         }
-
-        match abs_f {
-            Rat(r) => {
-                // r peut être 0 déjà traité ; ici r != 0
-                if r.is_one() {
-                    // ignore
+        match abs_e {
+            Indefini => self.indefini = true,
+            Rat(r) if r.is_zero() => {
+                if inverse {
+                    // a/0 : reste symbolique (opaque), comme avant
+                    self.ajoute_base(Div(Box::new(Rat(BigRational::one())), Box::new(Rat(r))), 1);
                 } else {
-                    prod_rat *= r;
+                    self.zero = true;
                 }
             }
-            other => {
-                if !is_one(&other) {
-                    v.push(other);
+            Rat(r) => {
+                if inverse {
+                    self.prod_rat /= r;
+                } else {
+                    self.prod_rat *= r;
                 }
             }
+            Mul(a, b) => {
+                self.ajoute(*a, inverse);
+                self.ajoute(*b, inverse);
+            }
+            Div(a, b) => {
+                self.ajoute(*a, inverse);
+                self.ajoute(*b, !inverse);
+            }
+            PowInt(x, n) => self.ajoute_base(*x, if inverse { -n } else { n }),
+            other => self.ajoute_base(other, if inverse { -1 } else { 1 }),
         }
     }
+}
+
+/// Base prouvée non nulle (signe certifié, jamais deviné).
+fn base_non_nulle(base: &Expr) -> bool {
+    matches!(
+        signe_certifie(base),
+        Some(Ordering::Less | Ordering::Greater)
+    )
+}
+
+/// base^n canon (n ≠ 0) ; base^1 = base.
+fn puissance(base: Expr, n: i64) -> Expr {
+    if n == 1 {
+        base
+    } else {
+        canon_pow(base, n)
+    }
+}
+
+fn canon_mul(e: Expr) -> Expr {
+    let mut f = Facteurs::new();
+    f.ajoute(e, false);
+    reconstruit_produit(f)
+}
+
+/// Regroupe les bases égales (x·x => x², π/π => 1), fusionne les racines
+/// rationnelles sous le ppcm de leurs indices (√2·√3 => √6, √3·√3·√3 => 3√3,
+/// ∛2·⁶√2 => √2), puis reconstruit num / den (facteurs triés, rationnel en tête
+/// du numérateur).
+fn reconstruit_produit(f: Facteurs) -> Expr {
+    use Expr::*;
 
-    // Appliquer prod_rat
-    if prod_rat.is_zero() {
+    if f.indefini {
+        return Indefini;
+    }
+    if f.zero || f.prod_rat.is_zero() {
         return Rat(BigRational::zero());
     }
 
-    // Pousser le signe dans prod_rat si possible
-    if signe_neg {
-        prod_rat = -prod_rat;
+    let mut prod_rat = f.prod_rat;
+    let mut num: Vec<Expr> = Vec::new();
+    let mut den: Vec<Expr> = Vec::new();
+    // radicande cumulé sous un indice commun (racines de rationnels > 0)
+    let mut racines: Vec<(u32, BigRational)> = Vec::new();
+
+    for (_, base, n) in f.bases {
+        if n == 0 {
+            continue;
+        }
+        match as_racine_rat(&base) {
+            Some((r, k)) if r.is_positive() => {
+                // (ᵏ√r)^n = r^(n div k) · ᵏ√(r^(n mod k))
+                let k64 = i64::from(k);
+                let Some(p) = rational_pow_borne(r.clone(), n.div_euclid(k64)) else {
+                    // trop grand : puissance laissée symbolique
+                    if n > 0 {
+                        num.push(PowInt(Box::new(base), n));
+                    } else {
+                        den.push(PowInt(Box::new(base), -n));
+                    }
+                    continue;
+                };
+                prod_rat *= p;
+                let reste = rational_pow_int(r, n.rem_euclid(k64));
+                if !reste.is_one() {
+                    fusionne_racine(&mut racines, k, reste);
+                }
+            }
+            _ if n > 0 => num.push(puissance(base, n)),
+            _ => den.push(puissance(base, -n)),
+        }
     }
 
-    // Si prod_rat == 1 et pas d’autres facteurs : 1
-    // Si prod_rat != 1 : on le garde comme facteur.
-    if !prod_rat.is_one() || v.is_empty() {
-        v.push(Rat(prod_rat));
+    for (k, r) in racines {
+        let (neg_rad, rad) = split_signe(canon_racine(Rat(r), k));
+        debug_assert!(!neg_rad);
+        match rad {
+            Rat(c) => prod_rat *= c,
+            Mul(a, b) => match (*a, *b) {
+                (Rat(c), x) | (x, Rat(c)) => {
+                    prod_rat *= c;
+                    num.push(x);
+                }
+                (a, b) => num.push(Mul(Box::new(a), Box::new(b))),
+            },
+            other => num.push(other),
+        }
     }
 
-    // Nettoyage (au cas où)
-    v.retain(|x| !is_one(x));
+    let negatif = f.signe_neg != prod_rat.is_negative();
+    let c = prod_rat.abs();
 
-    if v.is_empty() {
-        return Rat(BigRational::one());
+    if den.is_empty() {
+        // Forme historique : le signe reste porté par le rationnel en tête.
+        let c = if negatif { -c } else { c };
+        if !c.is_one() || num.is_empty() {
+            num.push(Rat(c));
+        }
+        return produit_trie(num);
     }
-    if v.len() == 1 {
-        return v.pop().unwrap();
+
+    // x/(2y) plutôt que (1/2·x)/y : le dénominateur du rationnel rejoint den.
+    num.push(Rat(BigRational::from_integer(c.numer().clone())));
+    den.push(Rat(BigRational::from_integer(c.denom().clone())));
+    let q = Div(Box::new(produit_trie(num)), Box::new(produit_trie(den)));
+    if negatif {
+        neg(q)
+    } else {
+        q
     }
+}
 
-    // Tri déterministe des facteurs
+/// Produit trié (ordre total), reconstruit à gauche ; 1 si vide.
+fn produit_trie(mut v: Vec<Expr>) -> Expr {
+    use Expr::*;
+    v.retain(|x| !is_one(x));
+    if v.is_empty() {
+        return Rat(BigRational::one());
+    }
     v.sort_by(cmp_expr);
-
-    // Reconstruction left-assoc
-    let mut acc = v[0].clone();
-    for f in v.into_iter().skip(1) {
+    let mut it = v.into_iter();
+    let mut acc = it.next().unwrap();
+    for f in it {
         acc = Mul(Box::new(acc), Box::new(f));
     }
     acc
@@ -301,11 +622,20 @@ fn canon_div(e: Expr) -> Expr {
         num = neg(num);
     }
 
-    // Tri léger : si num et den ont des canonisations internes, elles sont déjà faites.
-    Div(Box::new(num), Box::new(den))
+    // Dénominateur rationnel : forme historique (π/2, (1+√5)/2…).
+    if matches!(den, Rat(_)) {
+        return Div(Box::new(num), Box::new(den));
+    }
+
+    // Sinon, même passe que le produit : exposants négatifs (π·y/π => y ; x/x² reste
+    // un quotient, réduit par fractions_rationnelles.rs avec x ≠ 0).
+    let mut f = Facteurs::new();
+    f.ajoute(num, false);
+    f.ajoute(den, true);
+    reconstruit_produit(f)
 }
 
-/* ------------------------ PowInt / Sqrt ------------------------ */
+/* ------------------------ PowInt / Sqrt / Root ------------------------ */
 
 fn canon_pow(base: Expr, n: i64) -> Expr {
     use Expr::*;
@@ -315,42 +645,233 @@ fn canon_pow(base: Expr, n: i64) -> Expr {
     if n == 0 {
         return Rat(BigRational::one());
     }
+
+    // (ᵏ√r)^n, r rationnel non nul : r^(n div k) · ᵏ√(r^(n mod k)) (division euclidienne)
+    if let Some((r, k)) = as_racine_rat(&base) {
+        if !r.is_zero() && (k % 2 == 1 || r.is_positive()) {
+            let k = i64::from(k);
+            let Some(facteur) = rational_pow_borne(r.clone(), n.div_euclid(k)) else {
+                return PowInt(Box::new(base), n);
+            };
+            let facteur = Rat(facteur);
+            let reste = canon_racine(Rat(rational_pow_int(r, n.rem_euclid(k))), k as u32);
+            return canon_mul(Mul(Box::new(facteur), Box::new(reste)));
+        }
+    }
+
+    // (c·ᵏ√r·…)^n, c, r > 0 rationnels : facteur par facteur ((∛18/3)^-2 => ∛18/2)
+    if let Some(fs) = racines_rationnelles(&base) {
+        let mut f = Facteurs::new();
+        for (r, k) in fs {
+            if k == 1 {
+                let Some(p) = rational_pow_borne(r, n) else {
+                    return PowInt(Box::new(base), n);
+                };
+                f.prod_rat *= p;
+            } else {
+                f.ajoute_base(racine_brute(Rat(r), k), n);
+            }
+        }
+        return reconstruit_produit(f);
+    }
+
     PowInt(Box::new(base), n)
 }
 
-fn canon_sqrt(x: Expr) -> Expr {
+fn canon_pow_sym(base: Expr, e: Expr) -> Expr {
+    use Expr::*;
+    if matches!(base, Indefini) || matches!(e, Indefini) {
+        return Indefini;
+    }
+    // exposant devenu entier après canonisation => chemin rapide
+    if let Rat(r) = &e {
+        if r.denom().is_one() {
+            if let Ok(n) = r.numer().to_string().parse::<i64>() {
+                return canon_pow(base, n);
+            }
+        }
+    }
+    Pow(Box::new(base), Box::new(e))
+}
+
+/// Reconnaît √r ou ᵏ√r avec r rationnel ; renvoie (r, k).
+fn as_racine_rat(e: &Expr) -> Option<(BigRational, u32)> {
+    match e {
+        Expr::Sqrt(x) => match x.as_ref() {
+            Expr::Rat(r) => Some((r.clone(), 2)),
+            _ => None,
+        },
+        Expr::Root(x, k) => match x.as_ref() {
+            Expr::Rat(r) => Some((r.clone(), *k)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Taille max (bits du numérateur + dénominateur) d’un radicande fusionné.
+const RADICANDE_BITS_MAX: u64 = 4096;
+
+/// Ajoute ᵏ√r (r > 0) aux racines cumulées : ʲ√a · ᵏ√r = ᴸ√(a^(L/j) · r^(L/k)),
+/// L = ppcm(j, k). Nouveau groupe si L dépasse RACINE_INDICE_MAX ou si le
+/// radicande fusionné devient trop grand.
+fn fusionne_racine(racines: &mut Vec<(u32, BigRational)>, k: u32, r: BigRational) {
+    let bits = |x: &BigRational| x.numer().bits() + x.denom().bits();
+    for (j, acc) in racines.iter_mut() {
+        let l = j.lcm(&k);
+        if l > RACINE_INDICE_MAX {
+            continue;
+        }
+        let (pj, pk) = (l / *j, l / k);
+        if bits(acc) * u64::from(pj) + bits(&r) * u64::from(pk) > RADICANDE_BITS_MAX {
+            continue;
+        }
+        *acc = rational_pow_int(acc.clone(), i64::from(pj)) * rational_pow_int(r, i64::from(pk));
+        *j = l;
+        return;
+    }
+    racines.push((k, r));
+}
+
+/// Facteurs d’un produit de rationnels > 0 et de racines de rationnels > 0
+/// (2·√3/∛5 => [(2, 1), (3, 2), (1/5, 3)]) ; None dès qu’un facteur sort de ce cadre.
+fn racines_rationnelles(e: &Expr) -> Option<Vec<(BigRational, u32)>> {
+    use Expr::*;
+    match e {
+        Rat(r) if r.is_positive() => Some(vec![(r.clone(), 1)]),
+        Mul(a, b) => {
+            let mut v = racines_rationnelles(a)?;
+            v.extend(racines_rationnelles(b)?);
+            Some(v)
+        }
+        Div(a, b) => {
+            let mut v = racines_rationnelles(a)?;
+            v.extend(
+                racines_rationnelles(b)?
+                    .into_iter()
+                    .map(|(r, k)| (r.recip(), k)),
+            );
+            Some(v)
+        }
+        _ => as_racine_rat(e)
+            .filter(|(r, _)| r.is_positive())
+            .map(|rk| vec![rk]),
+    }
+}
+
+/// Construit ᵏ√x (Sqrt pour k = 2, Root sinon), sans simplification.
+fn racine_brute(x: Expr, k: u32) -> Expr {
+    if k == 2 {
+        Expr::Sqrt(Box::new(x))
+    } else {
+        Expr::Root(Box::new(x), k)
+    }
+}
+
+fn canon_racine(x: Expr, k: u32) -> Expr {
     use Expr::*;
 
     if matches!(x, Indefini) {
         return Indefini;
     }
+    if k == 1 {
+        return x;
+    }
 
-    // √(rat) : si entier >= 0, on extrait les carrés parfaits : √(s²*t) = s*√t
     if let Rat(r) = &x {
         if r.is_zero() {
             return Rat(BigRational::zero());
         }
-        if r.is_positive() && r.denom().is_one() {
-            let n = r.numer().clone();
-            let (s, t) = extrait_carre_parfait(&n);
+
+        // racine impaire d’un négatif : ᵏ√(-r) = -ᵏ√r
+        if r.is_negative() && k % 2 == 1 {
+            return neg(canon_racine(Rat(-r.clone()), k));
+        }
+
+        if r.is_positive() {
+            // ᵏ√(p/q) = ᵏ√(p·q^(k-1)) / q, puis extraction des puissances k-ièmes :
+            // ᵏ√(s^k·t) = s·ᵏ√t
+            let q = r.denom().clone();
+            let m = r.numer() * q.pow(k - 1);
+            let (s, t, k) = extrait_racine(&m, k);
+            let coeff = BigRational::new(s, q);
             if t.is_one() {
-                return Rat(BigRational::from_integer(s));
+                return Rat(coeff);
             }
-            if !s.is_one() {
-                return canon_mul(Mul(
-                    Box::new(Rat(BigRational::from_integer(s))),
-                    Box::new(Sqrt(Box::new(Rat(BigRational::from_integer(t))))),
-                ));
+            let rad = racine_brute(Rat(BigRational::from_integer(t)), k);
+            if coeff.is_one() {
+                return rad;
             }
-            return Sqrt(Box::new(Rat(BigRational::from_integer(t))));
+            return canon_mul(Mul(Box::new(Rat(coeff)), Box::new(rad)));
+        }
+    }
+
+    // racines emboîtées : ᵏ√(ʲ√a) = ᵏʲ√a (√(√2) => ∜2, ∛(√x) => ⁶√x)
+    if let Sqrt(_) | Root(..) = &x {
+        let (a, j) = match x {
+            Sqrt(a) => (a, 2),
+            Root(a, j) => (a, j),
+            _ => unreachable!(),
+        };
+        return match k.checked_mul(j).filter(|kj| *kj <= RACINE_INDICE_MAX) {
+            Some(kj) => canon_racine(*a, kj),
+            None => racine_brute(racine_brute(*a, j), k),
+        };
+    }
+
+    // ᵏ√(c·ʲ√r·…) = ᵏ√c·ᵏʲ√r·… (c, r > 0 rationnels), fusionnés en une racine
+    if let Some(fs) = racines_rationnelles(&x).filter(|fs| {
+        fs.iter()
+            .all(|(_, j)| k.checked_mul(*j).is_some_and(|kj| kj <= RACINE_INDICE_MAX))
+    }) {
+        let mut f = Facteurs::new();
+        for (r, j) in fs {
+            f.ajoute_base(racine_brute(Rat(r), k * j), 1);
         }
+        return reconstruit_produit(f);
     }
 
-    Sqrt(Box::new(x))
+    // √(a + b√c + …) : dénouement / sortie des carrés (corps Q(√d1, …))
+    if k == 2 {
+        if let Some(r) = SommeRadicaux::depuis_expr(&x)
+            .filter(|s| !s.est_rationnel())
+            .and_then(|s| racine_canon(&s))
+        {
+            return r;
+        }
+    }
+
+    racine_brute(x, k)
 }
 
-/// Décompose n >= 0 en n = s^2 * t, avec t “sans facteur carré” (approx. par essais).
-fn extrait_carre_parfait(n: &BigInt) -> (BigInt, BigInt) {
+/// ᵏ√m (m >= 1) = s·ʲ√t : sortie des puissances k-ièmes, puis indice réduit quand
+/// t est une puissance parfaite (⁴√4 => √2, ⁶√8 => √2) ; j divise k.
+fn extrait_racine(m: &BigInt, k: u32) -> (BigInt, BigInt, u32) {
+    if m.is_one() {
+        return (BigInt::one(), BigInt::one(), k);
+    }
+
+    let f = factorise(m);
+    let mut s = BigInt::one();
+    let mut g = if f.non_resolus.is_empty() { k } else { 1 };
+    for (p, e) in &f.premiers {
+        s *= p.pow(e / k);
+        if e % k != 0 {
+            g = g.gcd(&(e % k));
+        }
+    }
+
+    let mut t: BigInt = f.non_resolus.iter().product();
+    for (p, e) in &f.premiers {
+        t *= p.pow((e % k) / g);
+    }
+
+    (s, t, k / g)
+}
+
+/// Décompose n >= 0 en n = s^k * t, avec t “sans facteur puissance k-ième”
+/// (factorisation de arith.rs ; un cofacteur non cassé dans le budget reste dans t).
+pub(crate) fn extrait_puissance_parfaite(n: &BigInt, k: u32) -> (BigInt, BigInt) {
     if n.is_zero() {
         return (BigInt::zero(), BigInt::zero());
     }
@@ -358,24 +879,12 @@ fn extrait_carre_parfait(n: &BigInt) -> (BigInt, BigInt) {
         return (BigInt::one(), BigInt::one());
     }
 
-    let mut reste = n.clone();
+    let f = factorise(n);
     let mut s = BigInt::one();
-
-    // Essai par p = 2 puis impairs. Suffisant pour nos petits entiers (cas √2, √3, √12, √75, etc.)
-    let mut p = BigInt::from(2);
-    while &p * &p <= reste {
-        let p2 = &p * &p;
-
-        while (&reste % &p2).is_zero() {
-            reste /= &p2;
-            s *= &p;
-        }
-
-        if p == BigInt::from(2) {
-            p = BigInt::from(3);
-        } else {
-            p += 2;
-        }
+    let mut reste: BigInt = f.non_resolus.iter().product();
+    for (p, e) in &f.premiers {
+        s *= p.pow(e / k);
+        reste *= p.pow(e % k);
     }
 
     (s, reste)
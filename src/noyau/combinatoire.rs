@@ -0,0 +1,168 @@
+// src/noyau/combinatoire.rs
+//
+// Combinatoire exacte (sans flottants)
+// ------------------------------------
+// - n!      : entier n ≥ 0 (n entier < 0 : pôle de Γ => indéfini)
+// - n!!     : entier n ≥ -1 (convention (-1)!! = 0!! = 1)
+// - C(n,k)  : k entier ≥ 0, n rationnel quelconque (binôme généralisé)
+// - P(n,k)  : n(n-1)…(n-k+1) (factorielle descendante), même domaine
+// - n!/m!   : produit fini si n - m est un entier structurellement visible
+//
+// IMPORTANT (SAFE):
+// - Garde de taille : au-delà de LIMITE_COMBINATOIRE facteurs, on reste symbolique
+//   (pas de calcul monstrueux déclenché par une saisie comme 100000!).
+// - Argument non entier / symbolique : on garde le nœud (jamais de valeur inventée).
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+use super::expr::Expr;
+
+/// Nombre maximal de facteurs multipliés pour une valeur exacte.
+pub const LIMITE_COMBINATOIRE: u64 = 5000;
+
+/// Écart maximal développé dans n!/m! (au-delà : on garde le quotient).
+const LIMITE_RAPPORT: u64 = 32;
+
+/// Entier k ≥ 0 et ≤ LIMITE_COMBINATOIRE, sinon None.
+pub fn entier_borne(r: &BigRational) -> Option<u64> {
+    if !r.is_integer() || r.is_negative() {
+        return None;
+    }
+    r.to_integer()
+        .to_u64()
+        .filter(|k| *k <= LIMITE_COMBINATOIRE)
+}
+
+/// n! exact (n ≤ LIMITE_COMBINATOIRE).
+pub fn factorielle(n: u64) -> BigInt {
+    (2..=n).fold(BigInt::one(), |acc, i| acc * i)
+}
+
+/// n!! exact : n(n-2)(n-4)… (0!! = 1!! = 1).
+pub fn double_factorielle(n: u64) -> BigInt {
+    (1..=n)
+        .rev()
+        .step_by(2)
+        .fold(BigInt::one(), |acc, i| acc * i)
+}
+
+/// Factorielle descendante n(n-1)…(n-k+1), n rationnel (k = 0 : 1).
+pub fn arrangement(n: &BigRational, k: u64) -> BigRational {
+    let mut acc = BigRational::one();
+    let mut f = n.clone();
+    for _ in 0..k {
+        if f.is_zero() {
+            return BigRational::zero();
+        }
+        acc *= &f;
+        f -= BigRational::one();
+    }
+    acc
+}
+
+/// C(n,k) = n(n-1)…(n-k+1) / k!, n rationnel (binôme généralisé).
+pub fn binomial(n: &BigRational, k: u64) -> BigRational {
+    arrangement(n, k) / BigRational::from_integer(factorielle(k))
+}
+
+/// C(n,k) pour n entier ≥ 0 : on utilise la symétrie k <-> n-k
+/// quand elle fait passer sous la garde (ex: C(10^6, 10^6 - 2)).
+pub fn binomial_symetrique(n: &BigRational, k: &BigRational) -> Option<BigRational> {
+    if !n.is_integer() || n.is_negative() || !k.is_integer() {
+        return None;
+    }
+    if k.is_negative() || k > n {
+        return Some(BigRational::zero());
+    }
+    let k = entier_borne(k).or_else(|| entier_borne(&(n - k)))?;
+    Some(binomial(n, k))
+}
+
+/* ------------------------ Rapport de factorielles ------------------------ */
+
+/// Aplatit une somme en termes signés + constante rationnelle.
+fn termes_signes(e: &Expr, positif: bool, termes: &mut Vec<(bool, Expr)>, cst: &mut BigRational) {
+    match e {
+        Expr::Rat(r) => {
+            if positif {
+                *cst += r;
+            } else {
+                *cst -= r;
+            }
+        }
+        Expr::Add(a, b) => {
+            termes_signes(a, positif, termes, cst);
+            termes_signes(b, positif, termes, cst);
+        }
+        Expr::Sub(a, b) => {
+            termes_signes(a, positif, termes, cst);
+            termes_signes(b, !positif, termes, cst);
+        }
+        _ => termes.push((positif, e.clone())),
+    }
+}
+
+/// a - b si c’est un entier lisible sans calcul (mêmes termes, constantes différentes).
+///
+/// Exemple : (n+1) - (n-1) = 2 ; n - (n-1) = 1 ; n - m => None.
+pub fn difference_entiere(a: &Expr, b: &Expr) -> Option<i64> {
+    let mut ta = Vec::new();
+    let mut tb = Vec::new();
+    let mut ca = BigRational::zero();
+    let mut cb = BigRational::zero();
+    termes_signes(a, true, &mut ta, &mut ca);
+    termes_signes(b, true, &mut tb, &mut cb);
+
+    for t in ta {
+        let i = tb.iter().position(|u| *u == t)?;
+        tb.swap_remove(i);
+    }
+    if !tb.is_empty() {
+        return None;
+    }
+
+    let d = ca - cb;
+    if !d.is_integer() {
+        return None;
+    }
+    d.to_integer().to_i64()
+}
+
+/// a!/b! => produit explicite si a - b = d entier, |d| ≤ LIMITE_RAPPORT.
+///
+/// - d ≥ 0 : a(a-1)…(a-d+1)
+/// - d < 0 : 1 / (b(b-1)…(b-|d|+1))
+///
+/// Valide pour tout a hors pôles (Γ(a+1)/Γ(a+1-d)), donc en particulier pour a entier.
+pub fn rapport_factorielles(a: &Expr, b: &Expr) -> Option<Expr> {
+    let d = difference_entiere(a, b)?;
+    if d.unsigned_abs() > LIMITE_RAPPORT {
+        return None;
+    }
+
+    let (haut, m) = if d >= 0 { (a, d) } else { (b, -d) };
+    let produit = (0..m)
+        .map(|j| {
+            if j == 0 {
+                haut.clone()
+            } else {
+                Expr::Sub(
+                    Box::new(haut.clone()),
+                    Box::new(Expr::Rat(BigRational::from_integer(BigInt::from(j)))),
+                )
+            }
+        })
+        .reduce(|acc, f| Expr::Mul(Box::new(acc), Box::new(f)))
+        .unwrap_or(Expr::Rat(BigRational::one()));
+
+    if d >= 0 {
+        Some(produit)
+    } else {
+        Some(Expr::Div(
+            Box::new(Expr::Rat(BigRational::one())),
+            Box::new(produit),
+        ))
+    }
+}
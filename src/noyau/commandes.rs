@@ -0,0 +1,801 @@
+// src/noyau/commandes.rs
+//
+// Commandes “hors expression”
+// ---------------------------
+// Certaines demandes ne produisent pas une valeur (Expr) mais un résultat mis en forme :
+// - factor(n)  : 360 => 2^3·3^2·5
+// - factor(P)  : factorisation sur Q d’un polynôme (x^2-1 => (x-1)·(x+1))
+// - isprime(n) : vrai / faux (+ preuve Miller–Rabin dans la démarche)
+// - cf(x[, n])  : fraction continue [a0; a1, …] (période exacte pour a + b√d)
+// - convergents(x[, n]) : réduites p_k/q_k
+// - approx(x, qmax) : meilleure approximation rationnelle de dénominateur ≤ qmax
+// - iszero(x) : vrai / faux / indécidé (zéro prouvé exactement, jamais deviné)
+// - minpoly(x) : polynôme minimal dans Z[x] (cos(2π/7) => 8x^3+4x^2-4x-1)
+// - expand(P) : forme développée d’un polynôme ((x+y)^2 => x^2+2x*y+y^2)
+// - apart(F) : éléments simples ((3x+5)/((x+1)(x+2)) => 2/(x+1)+1/(x+2))
+// - solve(eq[, x]) : ensemble solution dans ℝ (x^2 = 2 => x ∈ {-√2, √2})
+// - csolve(eq[, x]) : idem dans ℂ (x^2 + 1 = 0 => x ∈ {-i, i})
+//   (degrés 3 et 4 : Cardano / Ferrari, sinon encadrements certifiés des racines réelles)
+// - realroots(P[, x]) : racines réelles isolées par Sturm (x^3-3x+1 => 3 encadrements)
+// - charpoly(A[, λ]) : polynôme caractéristique det(λI - A) ([[1,2],[3,4]] => λ^2-5λ-2)
+// - eigenvals(A) : valeurs propres exactes avec multiplicité (radicaux, complexes)
+// - eigenvects(A) : bases des espaces propres des valeurs propres réelles exactes
+// - diff(f[, x]) : dérivée symbolique, règles appliquées tracées (sin(x)^2 => 2·cos(x)·sin(x))
+// - integrate(f[, x]) : primitive (+ C) ; integrate(f, x, a, b) : intégrale définie exacte
+//   (integrate(sin(x), x, 0, pi) => 2, continuité prouvée sur [a, b])
+//
+// Forme reconnue : l’entrée ENTIÈRE est un appel nom(args) ; sinon, pipeline normal.
+// Les arguments passent par le pipeline exact (factor(10!) marche).
+//
+// IMPORTANT (SAFE):
+// - Pas de ΣLocal pour ces résultats (ce ne sont pas des nombres à lire), sauf les racines
+//   isolées : lecture à `digits` chiffres, chacun garanti par bissection exacte.
+// - Un cofacteur non cassé dans le budget est affiché entre parenthèses et signalé.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use super::algebrique::polynome_minimal;
+use super::arith::{factorise, format_factorisation, primalite, Primalite};
+use super::derivation::{derive, variables};
+use super::elements_simples::{decompose, format_decomposition};
+use super::equations::{
+    format_racines_multiples, format_solutions, lecture_certifiee, lit_inconnue, racines_isolees,
+    resous, Domaine,
+};
+use super::eval::{forme_exacte, forme_normale, lecture_brute, DemarcheNoyau};
+use super::expr::Expr;
+use super::format::{format_exact_final, format_expr_pretty};
+use super::fractions_continues::{
+    developpe, meilleure_approximation, reduites, Source, TERMES_DEFAUT, TERMES_MAX,
+};
+use super::fractions_rationnelles::lit_fraction;
+use super::integration::{integrale_definie, primitive};
+use super::lecture::{lecture_scaled, scaled_to_decimal};
+use super::matrices::evalue_matrices;
+use super::nullite::{nullite, nullite_exacte, Nullite};
+use super::polynome_multi::{
+    factorise_multi, format_factorisation_multi, message_refus, vers_univarie, PolyMulti,
+};
+use super::signe::signe_encadrement;
+use super::valeurs_propres::{
+    espaces_propres, format_espaces_propres, matrice_rationnelle, polynome_caracteristique,
+    valeurs_propres,
+};
+
+type ResultatCommande = Result<(String, Option<String>, DemarcheNoyau), String>;
+
+/// Aiguillage : Some(résultat) si `s` est une commande connue, None sinon.
+pub(crate) fn commande(s: &str, digits: usize) -> Option<ResultatCommande> {
+    let (nom, inner) = decoupe_appel(s)?;
+    let args = decoupe_arguments(inner);
+
+    match nom.as_str() {
+        "factor" | "factorise" => Some(cmd_factor(&args)),
+        "isprime" | "premier" => Some(cmd_isprime(&args)),
+        "cf" | "fc" => Some(cmd_cf(&args)),
+        "convergents" | "reduites" => Some(cmd_convergents(&args)),
+        "approx" => Some(cmd_approx(&args)),
+        "iszero" | "estnul" => Some(cmd_iszero(&args)),
+        "minpoly" | "polmin" => Some(cmd_minpoly(&args)),
+        "expand" | "developpe" => Some(cmd_expand(&args)),
+        "apart" | "decompose" => Some(cmd_apart(&args)),
+        "solve" | "resous" => Some(cmd_solve(&args, Domaine::Reel, digits)),
+        "csolve" | "cresous" => Some(cmd_solve(&args, Domaine::Complexe, digits)),
+        "realroots" | "racinesreelles" => Some(cmd_realroots(&args, digits)),
+        "charpoly" | "polcar" => Some(cmd_charpoly(&args)),
+        "eigenvals" | "valeurspropres" => Some(cmd_eigenvals(&args)),
+        "eigenvects" | "vecteurspropres" => Some(cmd_eigenvects(&args, digits)),
+        "diff" | "derive" => Some(cmd_diff(&args)),
+        "integrate" | "integre" => Some(cmd_integrate(&args, digits)),
+        _ => None,
+    }
+}
+
+/* ------------------------ Découpage ------------------------ */
+
+/// "factor(360)" => ("factor", "360") si la parenthèse ouvrante ferme à la fin.
+fn decoupe_appel(s: &str) -> Option<(String, &str)> {
+    let s = s.trim();
+    let fin_nom = s.find(|c: char| !c.is_ascii_alphabetic())?;
+    if fin_nom == 0 {
+        return None;
+    }
+    let reste = s[fin_nom..].trim_start();
+    if !reste.starts_with('(') || !reste.ends_with(')') {
+        return None;
+    }
+
+    // la '(' ouvrante doit se refermer sur le dernier caractère
+    let mut profondeur = 0i32;
+    for (i, c) in reste.char_indices() {
+        match c {
+            '(' => profondeur += 1,
+            ')' => {
+                profondeur -= 1;
+                if profondeur == 0 && i + 1 != reste.len() {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some((s[..fin_nom].to_lowercase(), &reste[1..reste.len() - 1]))
+}
+
+/// Sépare les arguments aux virgules de niveau 0 ("a, gcd(b,c)" => ["a", "gcd(b,c)"]).
+pub(crate) fn decoupe_arguments(inner: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut profondeur = 0i32;
+    let mut debut = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' | '[' | '{' => profondeur += 1,
+            ')' | ']' | '}' => profondeur -= 1,
+            ',' if profondeur == 0 => {
+                args.push(inner[debut..i].trim());
+                debut = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(inner[debut..].trim());
+    args
+}
+
+/// Évalue un argument (pipeline exact) et exige un entier.
+fn argument_entier(nom: &str, arg: &str) -> Result<(BigInt, DemarcheNoyau), String> {
+    if arg.is_empty() {
+        return Err(format!("{nom} : argument manquant"));
+    }
+    let (e, d) = forme_exacte(arg)?;
+    match e {
+        Expr::Rat(r) if r.is_integer() => Ok((r.to_integer(), d)),
+        autre => Err(format!(
+            "{nom} : argument entier attendu (reçu {})",
+            format_expr_pretty(&autre)
+        )),
+    }
+}
+
+/// Évalue un argument réel (pipeline exact) : indéfini refusé.
+fn argument_reel(nom: &str, arg: &str) -> Result<(Expr, DemarcheNoyau), String> {
+    if arg.is_empty() {
+        return Err(format!("{nom} : argument manquant"));
+    }
+    let (e, d) = forme_exacte(arg)?;
+    if matches!(e, Expr::Indefini) {
+        return Err(format!("{nom} : valeur indéfinie"));
+    }
+    Ok((e, d))
+}
+
+/// Nombre de termes demandé (2e argument optionnel), borné par TERMES_MAX.
+fn argument_nb_termes(nom: &str, args: &[&str]) -> Result<usize, String> {
+    match args {
+        [_] => Ok(TERMES_DEFAUT),
+        [_, n] => argument_entier(nom, n)?
+            .0
+            .to_usize()
+            .filter(|n| (1..=TERMES_MAX).contains(n))
+            .ok_or_else(|| format!("{nom} : nombre de termes entre 1 et {TERMES_MAX}")),
+        _ => Err(format!("{nom} : 1 ou 2 arguments attendus")),
+    }
+}
+
+/// Lecture polynomiale (au moins une variable) d’un argument déjà évalué.
+fn polynome_argument(nom: &str, e: &Expr) -> Result<PolyMulti, String> {
+    let attendu = || {
+        format!(
+            "{nom} : entier ou polynôme attendu (reçu {})",
+            format_expr_pretty(e)
+        )
+    };
+    let p = PolyMulti::depuis_expr(e).map_err(|err| message_refus(nom, err, attendu))?;
+    if p.constante_seule().is_some() {
+        return Err(attendu());
+    }
+    Ok(p)
+}
+
+fn un_argument<'a>(nom: &str, args: &[&'a str]) -> Result<&'a str, String> {
+    match args {
+        [a] => Ok(a),
+        _ => Err(format!("{nom} : un seul argument attendu")),
+    }
+}
+
+/* ------------------------ factor / isprime ------------------------ */
+
+fn cmd_factor(args: &[&str]) -> ResultatCommande {
+    let arg = un_argument("factor", args)?;
+    let (e, d_arg) = argument_reel("factor", arg)?;
+    let n = match e {
+        Expr::Rat(r) if r.is_integer() => r.to_integer(),
+        autre => return factor_polynome(&autre, d_arg),
+    };
+    if n.is_zero() {
+        return Err("factor : 0 n’a pas de factorisation".into());
+    }
+
+    let f = factorise(&n);
+    let exact = format_factorisation(&n, &f);
+
+    let mut preuve = String::new();
+    for (p, _) in &f.premiers {
+        if primalite(p) == Primalite::ProbablePremier {
+            preuve.push_str(&format!(
+                "{p} : probable premier (Miller–Rabin, au-delà de la borne déterministe)\n"
+            ));
+        }
+    }
+    for m in &f.non_resolus {
+        preuve.push_str(&format!(
+            "({m}) : composé, non décomposé dans le budget de Pollard rho\n"
+        ));
+    }
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("factor({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande factor : division par les premiers ≤ 1000, puis Pollard rho (Brent) ; primalité des facteurs par Miller–Rabin.".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/// factor(P) pour un polynôme (une ou plusieurs variables) sur Q.
+fn factor_polynome(e: &Expr, d_arg: DemarcheNoyau) -> ResultatCommande {
+    let p = polynome_argument("factor", e)?;
+    let (contenu, facteurs) = factorise_multi(&p)?;
+    let exact = format_factorisation_multi(&contenu, &facteurs);
+
+    let preuve = facteurs
+        .iter()
+        .map(|(g, _)| format!("{} : irréductible sur Q", g.format()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("factor({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande factor (polynôme) : contenu, monôme commun, substitution de Kronecker, Zassenhaus dans Z[t], recombinaison par division exacte.".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+fn cmd_isprime(args: &[&str]) -> ResultatCommande {
+    let arg = un_argument("isprime", args)?;
+    let (n, d_arg) = argument_entier("isprime", arg)?;
+
+    let p = primalite(&n);
+    let exact = match p {
+        Primalite::Compose => "faux",
+        Primalite::Premier | Primalite::ProbablePremier => "vrai",
+    }
+    .to_string();
+
+    let preuve = match p {
+        Primalite::Premier => {
+            format!("{n} : Miller–Rabin (bases 2…41) => premier (certifié, n < 3,3·10^24)")
+        }
+        Primalite::ProbablePremier => {
+            format!("{n} : Miller–Rabin (bases 2…41) => probable premier (n ≥ 3,3·10^24)")
+        }
+        Primalite::Compose if n.abs() <= BigInt::from(1) => {
+            format!("{n} : ni premier ni composé (convention : faux)")
+        }
+        Primalite::Compose if n.is_negative() => format!("{n} < 0 : pas premier"),
+        Primalite::Compose => {
+            // témoin lisible si la factorisation le trouve dans le budget
+            let f = factorise(&n);
+            match f.premiers.first() {
+                Some((q, _)) => format!("{n} = {q}·{}", &n / q),
+                None => format!("{n} : témoin de Miller–Rabin => composé"),
+            }
+        }
+    };
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("isprime({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande isprime : Miller–Rabin sur les 13 premiers nombres premiers (déterministe sous 3,3·10^24).".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ Fractions continues ------------------------ */
+
+/// Texte de la source pour la note (exact ou encadrement).
+fn nature_source(src: &Source) -> &'static str {
+    match src {
+        Source::Rationnel(_) => "rationnel : algorithme d’Euclide (développement fini)",
+        Source::Quadratique { .. } => {
+            "irrationnel quadratique : développement périodique exact ((P + √D)/Q)"
+        }
+        Source::Encadrement(_) => {
+            "encadrement ΣLocal : termes certifiés (les deux bornes s’accordent), développement tronqué"
+        }
+    }
+}
+
+fn cmd_cf(args: &[&str]) -> ResultatCommande {
+    let n = argument_nb_termes("cf", args)?;
+    let (e, d_arg) = argument_reel("cf", args[0])?;
+    let src = Source::depuis_expr(&e);
+    let fc = developpe(&src, n)?;
+    let exact = fc.format();
+
+    let nb = fc.termes.len().min(n);
+    let preuve = reduites(&fc, nb)
+        .iter()
+        .enumerate()
+        .map(|(k, r)| format!("p{k}/q{k} = {r}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("cf({})", d_arg.apres),
+        apres: exact.clone(),
+        note: format!("Commande cf : {}.", nature_source(&src)),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+fn cmd_convergents(args: &[&str]) -> ResultatCommande {
+    let n = argument_nb_termes("convergents", args)?;
+    let (e, d_arg) = argument_reel("convergents", args[0])?;
+    let src = Source::depuis_expr(&e);
+    let fc = developpe(&src, n)?;
+
+    let liste = reduites(&fc, n);
+    let mut exact = liste
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if !fc.fini && liste.len() == n {
+        exact.push_str(", …");
+    }
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("convergents({})", d_arg.apres),
+        apres: exact.clone(),
+        note: format!(
+            "Commande convergents : p_k = a_k·p_(k-1) + p_(k-2), q_k = a_k·q_(k-1) + q_(k-2) ; {}.",
+            nature_source(&src)
+        ),
+        preuve: format!("x = {}", fc.format()),
+    };
+    Ok((exact, None, d))
+}
+
+fn cmd_approx(args: &[&str]) -> ResultatCommande {
+    let [x, q] = args else {
+        return Err("approx : 2 arguments attendus (x, dénominateur max)".into());
+    };
+    let (e, d_arg) = argument_reel("approx", x)?;
+    let (q_max, _) = argument_entier("approx", q)?;
+    let src = Source::depuis_expr(&e);
+
+    let (r, pourquoi) = meilleure_approximation(&src, &q_max)?;
+    let exact = format_expr_pretty(&Expr::Rat(r));
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("approx({}, {q_max})", d_arg.apres),
+        apres: exact.clone(),
+        note: format!(
+            "Commande approx : meilleure approximation (réduite ou réduite intermédiaire) ; {}.",
+            nature_source(&src)
+        ),
+        preuve: format!("{exact} : {pourquoi}"),
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ iszero ------------------------ */
+
+fn cmd_iszero(args: &[&str]) -> ResultatCommande {
+    let arg = un_argument("iszero", args)?;
+    let (e, d_arg) = argument_reel("iszero", arg)?;
+
+    let n = nullite(&e);
+    let exact = match n {
+        Nullite::Nul => "vrai",
+        Nullite::NonNul => "faux",
+        Nullite::Inconnu => "indécidé",
+    }
+    .to_string();
+
+    let forme = format_expr_pretty(&e);
+    let exacte = nullite_exacte(&e);
+    let preuve = match n {
+        Nullite::Nul if exacte == Some(true) => {
+            format!("{forme} = 0 (arithmétique exacte dans Q(√d1, …, √dk))")
+        }
+        Nullite::Nul => format!("{forme} = 0 (polynôme minimal : x)"),
+        Nullite::NonNul if exacte == Some(false) => {
+            format!("{forme} ≠ 0 (forme unique non vide dans Q(√d1, …, √dk))")
+        }
+        Nullite::NonNul if exacte.is_none() && signe_encadrement(&e).is_none() => {
+            let p = polynome_minimal(&e)
+                .map(|p| p.format("x"))
+                .unwrap_or_default();
+            format!("{forme} ≠ 0 (polynôme minimal {p}, sans racine nulle)")
+        }
+        Nullite::NonNul => format!("{forme} ≠ 0 (encadrement ΣLocal certifié excluant 0)"),
+        Nullite::Inconnu => {
+            format!("{forme} : encadrement contenant 0, ni quadratique ni algébrique reconnu")
+        }
+    };
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("iszero({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande iszero : pré-filtre par encadrement certifié, puis décision exacte dans Q(√d1, …, √dk), sinon par polynôme minimal.".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ minpoly ------------------------ */
+
+fn cmd_minpoly(args: &[&str]) -> ResultatCommande {
+    let arg = un_argument("minpoly", args)?;
+    let (e, d_arg) = argument_reel("minpoly", arg)?;
+
+    let p = polynome_minimal(&e)?;
+    let exact = p.format("x");
+    let preuve = format!(
+        "{}({}) = 0, {} irréductible dans Z[x] (degré {})",
+        exact,
+        format_expr_pretty(&e),
+        exact,
+        p.degre()
+    );
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("minpoly({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande minpoly : résultants, factorisation de Zassenhaus, facteur choisi par encadrement certifié.".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ expand ------------------------ */
+
+fn cmd_expand(args: &[&str]) -> ResultatCommande {
+    let arg = un_argument("expand", args)?;
+    let (e, d_arg) = argument_reel("expand", arg)?;
+    let p = PolyMulti::depuis_expr(&e).map_err(|err| {
+        message_refus("expand", err, || {
+            format!(
+                "expand : expression polynomiale attendue (reçu {})",
+                format_expr_pretty(&e)
+            )
+        })
+    })?;
+    let exact = p.format();
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("expand({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande expand : forme normale développée (monômes par degré décroissant).".into(),
+        preuve: String::new(),
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ apart ------------------------ */
+
+fn cmd_apart(args: &[&str]) -> ResultatCommande {
+    let arg = un_argument("apart", args)?;
+    let (e, d_arg) = argument_reel("apart", arg)?;
+
+    let attendu = || {
+        format!(
+            "apart : fraction rationnelle en une variable attendue (reçu {})",
+            format_expr_pretty(&e)
+        )
+    };
+    let (num, den) = lit_fraction(&e).map_err(|err| message_refus("apart", err, attendu))?;
+    let mut vars = num.variables();
+    vars.extend(den.variables());
+    vars.sort();
+    vars.dedup();
+    let [var] = vars.as_slice() else {
+        return Err(attendu());
+    };
+    let (p, q) = vers_univarie(&num, var)
+        .zip(vers_univarie(&den, var))
+        .ok_or_else(attendu)?;
+
+    let mut preuve = String::new();
+    let decomposition = decompose(&p, &q, var, &mut preuve)?;
+    let exact = format_decomposition(&decomposition, var);
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("apart({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande apart : réduction, division euclidienne, factorisation du dénominateur sur Q, puis A ≡ R·H⁻¹ (mod g^e) et développement g-adique.".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ solve / csolve ------------------------ */
+
+fn cmd_solve(args: &[&str], domaine: Domaine, digits: usize) -> ResultatCommande {
+    let nom = match domaine {
+        Domaine::Reel => "solve",
+        Domaine::Complexe => "csolve",
+    };
+    let (eq, var) = equation_et_variable(nom, args)?;
+    let (_, d_arg) = argument_reel(nom, eq)?;
+    let d = DemarcheNoyau {
+        avant: format!("{nom}({})", d_arg.avant),
+        ..d_arg
+    };
+    resous_equation(eq, var, domaine, digits, d)
+}
+
+/// (équation, nom de variable optionnel) : “x^2 = 2”, “t^2 + 4 = 0, t”.
+fn equation_et_variable<'a>(
+    nom: &str,
+    args: &[&'a str],
+) -> Result<(&'a str, Option<&'a str>), String> {
+    match args {
+        [eq] => Ok((eq, None)),
+        [eq, v] if est_nom_variable(v) => Ok((eq, Some(v))),
+        [_, v] => Err(format!("{nom} : nom de variable attendu (reçu {v})")),
+        _ => Err(format!("{nom} : 1 ou 2 arguments attendus")),
+    }
+}
+
+/// Nom de variable comme Tok::Ident : [a-zA-Z_][a-zA-Z0-9_]* (diff(x, 2) refusé).
+fn est_nom_variable(v: &str) -> bool {
+    let mut c = v.chars();
+    c.next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && c.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Résolution de l’équation `s` (déjà passée par le pipeline : `d`) ; sert aussi
+/// à eval_expression quand l’entrée est une équation.
+pub(crate) fn resous_equation(
+    s: &str,
+    var: Option<&str>,
+    domaine: Domaine,
+    digits: usize,
+    d: DemarcheNoyau,
+) -> ResultatCommande {
+    // lecture AVANT simplification des fractions : les dénominateurs restent visibles
+    let e = lecture_brute(s)?;
+    let mut preuve = String::new();
+    let (var, solutions) = resous(&e, var, domaine, &mut preuve)?;
+    let exact = format_solutions(&var, &solutions, domaine);
+
+    let d = DemarcheNoyau {
+        apres: exact.clone(),
+        note: "Résolution : lhs - rhs = P/Q, zéros communs exclus, degré 1 direct, degré 2 par le discriminant, au-delà factorisation sur Q.".into(),
+        preuve: if d.preuve.is_empty() {
+            preuve
+        } else {
+            format!("{}\n{preuve}", d.preuve)
+        },
+        ..d
+    };
+    Ok((exact, lecture_certifiee(&var, &solutions, digits), d))
+}
+
+/* ------------------------ realroots ------------------------ */
+
+fn cmd_realroots(args: &[&str], digits: usize) -> ResultatCommande {
+    let (arg, var) = equation_et_variable("realroots", args)?;
+    let (_, d_arg) = argument_reel("realroots", arg)?;
+    let (var, p, q) = lit_inconnue(&lecture_brute(arg)?, var, "realroots")?;
+    if q.degre() > 0 || p.est_zero() {
+        return Err("realroots : polynôme non nul en une variable attendu".into());
+    }
+
+    let mut preuve = String::new();
+    let solutions = racines_isolees(&p, &var, &mut preuve);
+    let exact = format_solutions(&var, &solutions, Domaine::Reel);
+    let lecture = lecture_certifiee(&var, &solutions, digits);
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("realroots({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande realroots : partie sans carré, suite de Sturm sur ]-B, B[ (borne de Cauchy), bissection exacte jusqu’à une racine par intervalle, puis affinage jusqu’à ce que chaque chiffre lu soit garanti.".into(),
+        preuve,
+    };
+    Ok((exact, lecture, d))
+}
+
+/* ------------------------ charpoly / eigenvals / eigenvects ------------------------ */
+
+/// (matrice rationnelle évaluée, nom de variable) : “[[1, 2], [3, 4]]”, “…, t” (λ par défaut).
+fn matrice_et_variable(
+    nom: &str,
+    args: &[&str],
+) -> Result<(Vec<Vec<BigRational>>, String, DemarcheNoyau), String> {
+    let (arg, var) = equation_et_variable(nom, args)?;
+    let (e, d_arg) = argument_reel(nom, arg)?;
+    let a = matrice_rationnelle(&e, nom)?;
+    Ok((a, var.unwrap_or("λ").to_string(), d_arg))
+}
+
+fn cmd_charpoly(args: &[&str]) -> ResultatCommande {
+    let (a, var, d_arg) = matrice_et_variable("charpoly", args)?;
+    let mut preuve = String::new();
+    let exact = polynome_caracteristique(&a, &var, &mut preuve).format(&var);
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("charpoly({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande charpoly : det(λI - A) par Faddeev–LeVerrier (traces de A·M_k, arithmétique exacte sur Q).".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+fn cmd_eigenvals(args: &[&str]) -> ResultatCommande {
+    let (a, var, d_arg) = matrice_et_variable("eigenvals", args)?;
+    let mut preuve = String::new();
+    let valeurs = valeurs_propres(&a, &var, &mut preuve)?;
+    let exact = format_racines_multiples(&var, &valeurs);
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("eigenvals({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande eigenvals : racines de det(λI - A) dans ℂ avec multiplicité (partie sans carré, discriminant, Cardano, Ferrari, sinon encadrements certifiés).".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+fn cmd_eigenvects(args: &[&str], digits: usize) -> ResultatCommande {
+    let (a, var, d_arg) = matrice_et_variable("eigenvects", args)?;
+    let mut preuve = String::new();
+    let espaces = espaces_propres(&a, &var, digits, &mut preuve)?;
+    let exact = format_espaces_propres(&var, &espaces, digits);
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("eigenvects({})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande eigenvects : valeurs propres exactes, puis base de ker(A - μI) par Gauss–Jordan (pivots prouvés non nuls).".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ diff ------------------------ */
+
+/// Variable de dérivation : celle donnée, sinon l’unique variable de f (x si aucune).
+fn variable_de(nom: &str, e: &Expr, var: Option<&str>) -> Result<String, String> {
+    let vars = variables(e);
+    match (var, vars.as_slice()) {
+        (Some(v), _) => Ok(v.to_string()),
+        (None, []) => Ok("x".into()),
+        (None, [v]) => Ok(v.clone()),
+        (None, _) => Err(format!(
+            "{nom} : préciser la variable (reçu {})",
+            vars.join(", ")
+        )),
+    }
+}
+
+fn cmd_diff(args: &[&str]) -> ResultatCommande {
+    let (arg, var) = equation_et_variable("diff", args)?;
+    let (_, d_arg) = argument_reel("diff", arg)?;
+    // dérivation sur la lecture brute (règles lisibles), matrices déjà réduites
+    let mut preuve = String::new();
+    let e = evalue_matrices(lecture_brute(arg)?, &mut preuve)?;
+    let var = variable_de("diff", &e, var)?;
+
+    let brute = derive(&e, &var, &mut preuve)?;
+    let r = forme_normale(brute, &mut preuve);
+    let exact = format_exact_final(&r);
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant: format!("diff({}, {var})", d_arg.avant),
+        apres: exact.clone(),
+        note: "Commande diff : linéarité, produit, quotient et dérivées composées sur l’arbre, puis simplification exacte (pipeline).".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ integrate ------------------------ */
+
+fn cmd_integrate(args: &[&str], digits: usize) -> ResultatCommande {
+    let (arg, var, bornes) = match args {
+        [f] => (*f, None, None),
+        [f, v] => (*f, Some(*v), None),
+        [f, v, a, b] => (*f, Some(*v), Some((*a, *b))),
+        _ => return Err("integrate : 1, 2 ou 4 arguments attendus".into()),
+    };
+    if let Some(v) = var {
+        equation_et_variable("integrate", &[arg, v])?;
+    }
+    let (_, d_arg) = argument_reel("integrate", arg)?;
+    let mut preuve = String::new();
+    let f = lecture_brute(arg)?;
+    let var = variable_de("integrate", &f, var)?;
+
+    let brute = primitive(&f, &var, &mut preuve)?;
+    let primitive = forme_normale(brute, &mut preuve);
+
+    let (exact, lecture, avant) = match bornes {
+        None => (
+            format!("{} + C", format_exact_final(&primitive)),
+            None,
+            format!("integrate({}, {var})", d_arg.avant),
+        ),
+        Some((a, b)) => {
+            let (ea, _) = argument_reel("integrate", a)?;
+            let (eb, _) = argument_reel("integrate", b)?;
+            if !variables(&ea).is_empty() || !variables(&eb).is_empty() {
+                return Err("integrate : bornes numériques attendues".into());
+            }
+            let r = integrale_definie(&f, &primitive, &var, &ea, &eb, &mut preuve)?;
+            let lecture = lecture_scaled(&r, digits)
+                .ok()
+                .filter(|_| variables(&r).is_empty())
+                .map(|s| scaled_to_decimal(s, digits));
+            let avant = format!(
+                "integrate({}, {var}, {}, {})",
+                d_arg.avant,
+                format_expr_pretty(&ea),
+                format_expr_pretty(&eb)
+            );
+            (format_exact_final(&r), lecture, avant)
+        }
+    };
+
+    let d = DemarcheNoyau {
+        jetons: d_arg.jetons,
+        rpn: d_arg.rpn,
+        avant,
+        apres: exact.clone(),
+        note: "Commande integrate : linéarité, puissances et sin/cos d’argument linéaire, substitution t = g(x), polynômes terme à terme ; définie : continuité prouvée puis F(b) - F(a) exact.".into(),
+        preuve,
+    };
+    Ok((exact, lecture, d))
+}
@@ -0,0 +1,332 @@
+// src/noyau/derivation.rs
+//
+// Dérivation symbolique : diff(sin(x)^2, x) => 2·cos(x)·sin(x)
+// ------------------------------------------------------------
+// Récursion sur l’arbre (toutes les variantes d’Expr), une règle par nœud :
+// - linéarité : (u ± v)' = u' ± v', (k·u)' = k·u', (u/k)' = u'/k
+// - produit : (u·v)' = u'·v + u·v' ; quotient : (u/v)' = (u'·v - u·v')/v²
+// - puissances : (u^n)' = n·u^(n-1)·u', (√u)' = u'/(2√u), (ⁿ√u)' = u'/(n·ⁿ√u^(n-1)),
+//   (ᵏ√u)^n = u^(n/k) : (n/k)·(ᵏ√u)^(n-k)·u' (une seule puissance de la racine),
+//   (u^a)' = a·u^(a-1)·u' (exposant a indépendant de x)
+// - composées : sin' = cos, cos' = -sin, tan' = 1 + tan²
+// - |u|' = sign(u)·u' (u ≠ 0) ; sign, floor, ceil, round : 0 hors des sauts ; frac(u)' = u'
+// Le résultat brut repasse ensuite par le pipeline (simplify, canon, radicaux, fractions).
+//
+// IMPORTANT (SAFE):
+// - Chaque règle appliquée est tracée (preuve), dans l’ordre du parcours.
+// - Exposant dépendant de x (2^x, x^x) : erreur explicite (pas de logarithme dans le noyau).
+// - Fonctions d’arguments entiers (n!, C(n,k), pgcd…) dépendant de x : erreur, pas de dérivée.
+// - Dérivées “hors des sauts” (|u|, floor, frac…) : domaine de validité noté dans la preuve.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+use super::expr::Expr;
+use super::format::format_expr_pretty;
+
+/// Variables présentes dans e (ordre alphabétique, sans doublon).
+pub fn variables(e: &Expr) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut pile = vec![e];
+    while let Some(e) = pile.pop() {
+        if let Expr::Var(v) = e {
+            out.push(v.clone());
+        }
+        pile.extend(e.enfants());
+    }
+    out.sort();
+    out.dedup();
+    out
+}
+
+/// Vrai si e contient la variable x.
+pub(crate) fn depend(e: &Expr, x: &str) -> bool {
+    match e {
+        Expr::Var(v) => v == x,
+        _ => e.enfants().into_iter().any(|f| depend(f, x)),
+    }
+}
+
+pub(crate) fn contient_indefini(e: &Expr) -> bool {
+    matches!(e, Expr::Indefini) || e.enfants().into_iter().any(contient_indefini)
+}
+
+/// Parenthèses extérieures retirées si elles entourent tout le texte.
+pub(crate) fn nu(t: String) -> String {
+    if entoure(&t) {
+        t[1..t.len() - 1].to_string()
+    } else {
+        t
+    }
+}
+
+/// Texte groupé entre parenthèses, sauf s’il l’est déjà ou s’il est atomique (x, sin(x)).
+pub(crate) fn groupe(t: String) -> String {
+    let mut profondeur = 0;
+    let operateur = t.chars().any(|c| {
+        match c {
+            '(' => profondeur += 1,
+            ')' => profondeur -= 1,
+            _ => {}
+        }
+        profondeur == 0 && matches!(c, '+' | '-' | '*' | '/' | '^')
+    });
+    if entoure(&t) || !operateur {
+        t
+    } else {
+        format!("({t})")
+    }
+}
+
+/// Vrai si t = “(…)” avec une seule paire extérieure.
+fn entoure(t: &str) -> bool {
+    if !t.starts_with('(') || !t.ends_with(')') {
+        return false;
+    }
+    let mut profondeur = 0;
+    for (i, c) in t.char_indices() {
+        match c {
+            '(' => profondeur += 1,
+            ')' => profondeur -= 1,
+            _ => {}
+        }
+        if profondeur == 0 && i + 1 < t.len() {
+            return false;
+        }
+    }
+    true
+}
+
+fn b(e: Expr) -> Box<Expr> {
+    Box::new(e)
+}
+
+fn entier(n: i64) -> Expr {
+    Expr::Rat(BigRational::from_integer(BigInt::from(n)))
+}
+
+/// Dérivée brute de e par rapport à x (non simplifiée) ; règles tracées dans `preuve`.
+pub fn derive(e: &Expr, x: &str, preuve: &mut String) -> Result<Expr, String> {
+    use Expr::*;
+
+    if contient_indefini(e) {
+        return Err("diff : valeur indéfinie".into());
+    }
+    if let Egal(_, _) = e {
+        return Err("diff : expression attendue (reçu une équation)".into());
+    }
+    if let Matrice(l) = e {
+        let l = l
+            .iter()
+            .map(|ligne| ligne.iter().map(|c| derive(c, x, preuve)).collect())
+            .collect::<Result<_, String>>()?;
+        return Ok(Matrice(l));
+    }
+    if !depend(e, x) {
+        return Ok(Rat(BigRational::zero()));
+    }
+
+    // u sans parenthèses extérieures (argument), ou groupé (facteur, base d’une puissance)
+    let arg = |u: &Expr| nu(format_expr_pretty(u));
+    let g = |u: &Expr| groupe(format_expr_pretty(u));
+    let d = |u: &Expr| format!("d/d{x}({})", arg(u));
+    // facteur u' d’une composée (omis si u = x)
+    let chaine = |u: &Expr| match u {
+        Var(v) if v == x => String::new(),
+        _ => format!("·{}", d(u)),
+    };
+    let f = format_expr_pretty(e);
+    let mut regle = |s: String| preuve.push_str(&format!("{s}\n"));
+
+    Ok(match e {
+        Var(_) => Rat(BigRational::one()),
+
+        Add(u, v) | Sub(u, v) => {
+            let signe = if matches!(e, Add(_, _)) { '+' } else { '-' };
+            regle(format!("linéarité : {} = {} {signe} {}", d(e), d(u), d(v)));
+            let (du, dv) = (derive(u, x, preuve)?, derive(v, x, preuve)?);
+            match e {
+                Add(_, _) => Add(b(du), b(dv)),
+                _ => Sub(b(du), b(dv)),
+            }
+        }
+
+        Mul(u, v) if !depend(u, x) => {
+            regle(format!("facteur constant : {} = {}·{}", d(e), g(u), d(v)));
+            Mul(u.clone(), b(derive(v, x, preuve)?))
+        }
+        Mul(u, v) if !depend(v, x) => {
+            regle(format!("facteur constant : {} = {}·{}", d(e), g(v), d(u)));
+            Mul(b(derive(u, x, preuve)?), v.clone())
+        }
+        Mul(u, v) => {
+            regle(format!(
+                "produit : {} = {}·{} + {}·{}",
+                d(e),
+                d(u),
+                g(v),
+                g(u),
+                d(v)
+            ));
+            let (du, dv) = (derive(u, x, preuve)?, derive(v, x, preuve)?);
+            Add(b(Mul(b(du), v.clone())), b(Mul(u.clone(), b(dv))))
+        }
+
+        Div(u, v) if !depend(v, x) => {
+            regle(format!("facteur constant : {} = {}/{}", d(e), d(u), g(v)));
+            Div(b(derive(u, x, preuve)?), v.clone())
+        }
+        Div(u, v) => {
+            regle(format!(
+                "quotient : {} = ({}·{} - {}·{})/{}^2",
+                d(e),
+                d(u),
+                g(v),
+                g(u),
+                d(v),
+                g(v)
+            ));
+            let (du, dv) = (derive(u, x, preuve)?, derive(v, x, preuve)?);
+            let num = Sub(b(Mul(b(du), v.clone())), b(Mul(u.clone(), b(dv))));
+            Div(b(num), b(PowInt(v.clone(), 2)))
+        }
+
+        // (ᵏ√a)^n = a^(n/k) : exposant rationnel dérivé d’un bloc, (n/k)·(ᵏ√a)^(n-k)·a'
+        // (sinon n·(ᵏ√a)^(n-1)/(k·(ᵏ√a)^(k-1)) laisse la racine au numérateur et au dénominateur)
+        PowInt(u, n) if matches!(**u, Sqrt(_) | Root(_, _)) => {
+            let (a, k) = match &**u {
+                Sqrt(a) => (a, 2),
+                Root(a, k) => (a, *k as i64),
+                _ => unreachable!(),
+            };
+            let coef = Rat(BigRational::new(BigInt::from(*n), BigInt::from(k)));
+            regle(format!(
+                "puissance : {} = {}·{}^{}{}",
+                d(e),
+                g(&coef),
+                g(u),
+                groupe((n - k).to_string()),
+                chaine(a)
+            ));
+            let da = derive(a, x, preuve)?;
+            Mul(b(Mul(b(coef), b(PowInt(u.clone(), n - k)))), b(da))
+        }
+        PowInt(u, n) => {
+            let puissance = match n - 1 {
+                1 => g(u),
+                k => format!("{}^{k}", g(u)),
+            };
+            regle(format!(
+                "puissance : {} = {n}·{puissance}{}",
+                d(e),
+                chaine(u)
+            ));
+            let du = derive(u, x, preuve)?;
+            Mul(b(Mul(b(entier(*n)), b(PowInt(u.clone(), n - 1)))), b(du))
+        }
+        Sqrt(u) => {
+            regle(format!("racine : {} = 1/(2·{f}){}", d(e), chaine(u)));
+            let du = derive(u, x, preuve)?;
+            Div(b(du), b(Mul(b(entier(2)), b(e.clone()))))
+        }
+        Root(u, k) => {
+            regle(format!(
+                "racine : {} = 1/({k}·{f}^{}){}",
+                d(e),
+                k - 1,
+                chaine(u)
+            ));
+            let du = derive(u, x, preuve)?;
+            let den = Mul(b(entier(*k as i64)), b(PowInt(b(e.clone()), *k as i64 - 1)));
+            Div(b(du), b(den))
+        }
+        Pow(_, a) if depend(a, x) => {
+            return Err(format!(
+                "diff : exposant dépendant de {x} dans {f} (logarithme non disponible)"
+            ))
+        }
+        Pow(u, a) => {
+            let a1 = Sub(a.clone(), b(Rat(BigRational::one())));
+            regle(format!(
+                "puissance : {} = {}·{}^{}{}",
+                d(e),
+                g(a),
+                g(u),
+                groupe(format_expr_pretty(&a1)),
+                chaine(u)
+            ));
+            let du = derive(u, x, preuve)?;
+            Mul(b(Mul(a.clone(), b(Pow(u.clone(), b(a1))))), b(du))
+        }
+
+        Sin(u) => {
+            regle(format!(
+                "composée : {} = cos({}){}",
+                d(e),
+                arg(u),
+                chaine(u)
+            ));
+            Mul(b(Cos(u.clone())), b(derive(u, x, preuve)?))
+        }
+        Cos(u) => {
+            regle(format!(
+                "composée : {} = -sin({}){}",
+                d(e),
+                arg(u),
+                chaine(u)
+            ));
+            let du = derive(u, x, preuve)?;
+            Mul(b(entier(-1)), b(Mul(b(Sin(u.clone())), b(du))))
+        }
+        Tan(u) => {
+            regle(format!("composée : {} = (1 + {f}^2){}", d(e), chaine(u)));
+            let du = derive(u, x, preuve)?;
+            Mul(b(Add(b(entier(1)), b(PowInt(b(e.clone()), 2)))), b(du))
+        }
+
+        Abs(u) => {
+            let ut = arg(u);
+            regle(format!(
+                "valeur absolue : {} = sign({ut}){} pour {ut} ≠ 0",
+                d(e),
+                chaine(u)
+            ));
+            Mul(b(Sign(u.clone())), b(derive(u, x, preuve)?))
+        }
+        Sign(_) | Floor(_) | Ceil(_) | Round(_) => {
+            regle(format!(
+                "constante par morceaux : {} = 0 hors des sauts de {f}",
+                d(e)
+            ));
+            Rat(BigRational::zero())
+        }
+        Frac(u) => {
+            regle(format!(
+                "partie fractionnaire : {} = {} hors des sauts",
+                d(e),
+                d(u)
+            ));
+            derive(u, x, preuve)?
+        }
+        Modulo(u, m) if !depend(m, x) => {
+            regle(format!("modulo : {} = {} hors des sauts", d(e), d(u)));
+            derive(u, x, preuve)?
+        }
+
+        Fact(_)
+        | FactDouble(_)
+        | Binom(_, _)
+        | Arrang(_, _)
+        | Pgcd(_, _)
+        | Ppcm(_, _)
+        | Modulo(_, _) => {
+            return Err(format!(
+                "diff : {f} n’est pas dérivable en {x} (fonction d’arguments entiers)"
+            ))
+        }
+        Matriciel(_, _) => return Err("diff : opération matricielle non réduite".into()),
+
+        Rat(_) | Pi | Indefini | Egal(_, _) | Matrice(_) => unreachable!(),
+    })
+}
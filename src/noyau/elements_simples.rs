@@ -0,0 +1,242 @@
+// src/noyau/elements_simples.rs
+//
+// Décomposition en éléments simples sur Q (apart(…))
+// --------------------------------------------------
+// P/Q, une variable, coefficients rationnels :
+// 1) P/Q réduite (pgcd), puis division euclidienne P = E·Q + R (partie entière E)
+// 2) Q = c·∏ g_i^e_i (facteurs irréductibles sur Q, facteurs_polynome.rs)
+// 3) pour chaque G = g^e, avec Q = G·H (pgcd(G, H) = 1) :
+//      R/(G·H) = A/G + B/H,  A ≡ R·H⁻¹ (mod G),  B = (R - A·H)/G
+//    puis A = a_0 + a_1·g + … (développement g-adique) : A/g^e = Σ a_j/g^(e-j)
+//
+// Facteurs linéaires répétés : constantes sur (x-a)^k ; facteurs quadratiques
+// irréductibles : numérateurs ax+b (en général : deg a_j < deg g).
+//
+// Exemple : (3x+5)/((x+1)(x+2)) = 2/(x+1)+1/(x+2)
+//
+// IMPORTANT (SAFE):
+// - Arithmétique exacte sur Q uniquement (pas de racines : x²+1 reste irréductible).
+// - Chaque étape (réduction, division, factorisation, inverse modulaire) est tracée.
+
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+use super::facteurs_polynome::factorise_polynome;
+use super::polynome::Polynome;
+
+/// a / g^puissance
+#[derive(Clone, Debug)]
+pub struct ElementSimple {
+    pub numerateur: Polynome,
+    pub facteur: Polynome,
+    pub puissance: u32,
+}
+
+/// P/Q = partie_entiere + Σ éléments simples.
+#[derive(Clone, Debug)]
+pub struct Decomposition {
+    pub partie_entiere: Polynome,
+    pub elements: Vec<ElementSimple>,
+}
+
+/// Décompose num/den (variable `var` pour la preuve).
+pub fn decompose(
+    num: &Polynome,
+    den: &Polynome,
+    var: &str,
+    preuve: &mut String,
+) -> Result<Decomposition, String> {
+    if den.est_zero() {
+        return Err("apart : dénominateur nul".into());
+    }
+
+    // 1) réduction + partie entière
+    let g = num.pgcd(den);
+    let (num, den) = if g.degre() > 0 && !num.est_zero() {
+        preuve.push_str(&format!(
+            "pgcd({}, {}) = {} : fraction réduite (pour {} ≠ 0)\n",
+            num.format(var),
+            den.format(var),
+            g.format(var),
+            g.format(var)
+        ));
+        (num.divrem(&g).0, den.divrem(&g).0)
+    } else {
+        (num.clone(), den.clone())
+    };
+    let (partie_entiere, reste) = num.divrem(&den);
+    if !partie_entiere.est_zero() {
+        preuve.push_str(&format!(
+            "division euclidienne : {} = ({})·({}) + {}\n",
+            num.format(var),
+            partie_entiere.format(var),
+            den.format(var),
+            reste.format(var)
+        ));
+    }
+    if reste.est_zero() {
+        return Ok(Decomposition {
+            partie_entiere,
+            elements: vec![],
+        });
+    }
+
+    // 2) factorisation du dénominateur
+    let (contenu, facteurs) = factorise_polynome(&den)?;
+    preuve.push_str(&format!(
+        "{} = {}\n",
+        den.format(var),
+        format_produit(&contenu, &facteurs, var)
+    ));
+
+    // 3) facteur par facteur (Bezout), puis développement g-adique
+    let mut r = reste.mul_scalaire(&contenu.recip());
+    let mut produit = facteurs
+        .iter()
+        .fold(Polynome::constante(BigRational::one()), |acc, (g, e)| {
+            acc.mul(&g.puissance(*e))
+        });
+    let mut elements = Vec::new();
+    for (g, e) in &facteurs {
+        let gros = g.puissance(*e);
+        let (h, _) = produit.divrem(&gros);
+        let inv = h
+            .inverse_modulo(&gros)
+            .ok_or("apart : facteurs non premiers entre eux (incohérent)")?;
+        let a = r.mul(&inv).rem(&gros);
+        let b = r.sub(&a.mul(&h)).divrem(&gros).0;
+        let gf = format_facteur(g, *e, var);
+        preuve.push_str(&format!(
+            "{gf} : A ≡ R·H⁻¹ (mod {gf}) = {}\n",
+            a.format(var)
+        ));
+
+        let mut morceaux = Vec::new();
+        let mut reste_a = a;
+        for j in 0..*e {
+            let (qq, aj) = reste_a.divrem(g);
+            if !aj.est_zero() {
+                morceaux.push(ElementSimple {
+                    numerateur: aj,
+                    facteur: g.clone(),
+                    puissance: e - j,
+                });
+            }
+            reste_a = qq;
+        }
+        morceaux.reverse();
+        elements.extend(morceaux);
+
+        r = b;
+        produit = h;
+    }
+
+    Ok(Decomposition {
+        partie_entiere,
+        elements,
+    })
+}
+
+/* ------------------------ Affichage ------------------------ */
+
+/// “x^2+1-x/(x^2+1)+2/(x+1)^2”
+pub fn format_decomposition(d: &Decomposition, var: &str) -> String {
+    let mut s = String::new();
+    if !d.partie_entiere.est_zero() {
+        s.push_str(&d.partie_entiere.format(var));
+    }
+    for el in &d.elements {
+        let (c, n) = el.numerateur.partie_primitive();
+        let n = Polynome::depuis_entiers(&n);
+        let (p, q) = (c.numer().abs(), c.denom().clone());
+
+        if c.is_negative() {
+            s.push('-');
+        } else if !s.is_empty() {
+            s.push('+');
+        }
+
+        let numerateur = if n.degre() == 0 {
+            p.to_string()
+        } else {
+            let nf = entre_parentheses_si_somme(&n, var);
+            if p.is_one() {
+                nf
+            } else {
+                format!("{p}{nf}")
+            }
+        };
+        let facteur = format_facteur(&el.facteur, el.puissance, var);
+        let denominateur = if q.is_one() {
+            facteur
+        } else {
+            format!("({q}{facteur})")
+        };
+        s.push_str(&format!("{numerateur}/{denominateur}"));
+    }
+    if s.is_empty() {
+        s.push('0');
+    }
+    s
+}
+
+fn entre_parentheses_si_somme(g: &Polynome, var: &str) -> String {
+    let f = g.format(var);
+    if g.coeffs.iter().filter(|c| !c.is_zero()).count() > 1 {
+        format!("({f})")
+    } else {
+        f
+    }
+}
+
+/// g^e : “x^2”, “(x+1)”, “(x+1)^3”
+fn format_facteur(g: &Polynome, e: u32, var: &str) -> String {
+    let f = entre_parentheses_si_somme(g, var);
+    if e == 1 {
+        f
+    } else if f.starts_with('(') || f == var {
+        format!("{f}^{e}")
+    } else {
+        // monôme 2x : parenthèses pour l’exposant
+        format!("({f})^{e}")
+    }
+}
+
+fn format_produit(contenu: &BigRational, facteurs: &[(Polynome, u32)], var: &str) -> String {
+    let mut morceaux: Vec<String> = Vec::new();
+    if !contenu.is_one() {
+        morceaux.push(contenu.to_string());
+    }
+    morceaux.extend(facteurs.iter().map(|(g, e)| format_facteur(g, *e, var)));
+    morceaux.join("·")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    fn p(c: &[i64]) -> Polynome {
+        Polynome::depuis_entiers(&c.iter().map(|&n| BigInt::from(n)).collect::<Vec<_>>())
+    }
+
+    fn apart(num: &[i64], den: &[i64]) -> String {
+        let mut preuve = String::new();
+        let d = decompose(&p(num), &p(den), "x", &mut preuve).unwrap();
+        format_decomposition(&d, "x")
+    }
+
+    #[test]
+    fn lineaires_repetes_et_quadratiques() {
+        // (3x+5)/((x+1)(x+2))
+        assert_eq!(apart(&[5, 3], &[2, 3, 1]), "2/(x+1)+1/(x+2)");
+        // 1/(x^2(x+1))
+        assert_eq!(apart(&[1], &[0, 0, 1, 1]), "-1/x+1/x^2+1/(x+1)");
+        // 1/(x^3+x)
+        assert_eq!(apart(&[1], &[0, 1, 0, 1]), "1/x-x/(x^2+1)");
+        // x^3/(x^2-1) = x + (1/2)/(x-1) + (1/2)/(x+1)
+        assert_eq!(apart(&[0, 0, 0, 1], &[-1, 0, 1]), "x+1/(2(x-1))+1/(2(x+1))");
+        // 1/(2x+2)
+        assert_eq!(apart(&[1], &[2, 2]), "1/(2(x+1))");
+    }
+}
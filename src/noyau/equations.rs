@@ -0,0 +1,890 @@
+// src/noyau/equations.rs
+//
+// Équations à une inconnue (solve(…))
+// -----------------------------------
+// lhs = rhs  =>  lhs - rhs = P/Q (fractions_rationnelles.rs : lit_fraction)
+// 1) P/Q réduite : on retire de P tout facteur commun avec Q (les zéros de Q sont exclus)
+// 2) P constant : nul => toutes les valeurs (hors zéros de Q), sinon aucune solution
+// 3) degré 1 : a·x + b = 0 => x = -b/a
+// 4) degré 2 : Δ = b² - 4ac ; Δ > 0 deux racines (-b ± √Δ)/(2a), Δ = 0 racine double,
+//    Δ < 0 racines complexes (-b ± i√(-Δ))/(2a)
+// 5) degré ≥ 3 : racines rationnelles ±p/q (p | a0, q | an) et division par (x - r),
+//    puis factorisation sur Q du quotient (facteurs_polynome.rs) ; par facteur :
+//    3) / 4), Cardano / Ferrari (resolution_radicaux.rs) si les radicaux sont réels,
+//    sinon racines réelles isolées (isolation.rs) : “x₁ ∈ ]a, b[”
+//
+// Domaine :
+// - Reel : Δ < 0 => aucune racine réelle ; les racines complexes sont données dans la preuve
+// - Complexe (csolve) : les racines complexes font partie de l’ensemble solution
+//
+// IMPORTANT (SAFE):
+// - Coefficients rationnels uniquement (la lecture refuse √2·x, sin(x)…).
+// - Racines écrites avec Sqrt puis canon (√8 => 2√2) : jamais de valeur approchée.
+// - Un encadrement affiché contient exactement une racine (Sturm, bornes arrondies vers
+//   l’extérieur) ; csolve refuse (Err) des racines non réelles sans forme par radicaux.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::cmp::Ordering;
+
+use super::arith::factorise;
+use super::expr::Expr;
+use super::facteurs_polynome::factorise_polynome;
+use super::format::{entre_parentheses, format_exact_final};
+use super::fractions_rationnelles::lit_fraction;
+use super::isolation::{
+    affine, decimales_certifiees, encadrement_decimal, isole_racines, trace_sturm, Encadrement,
+};
+use super::lecture::eval_scaled;
+use super::polynome::Polynome;
+use super::polynome_multi::{message_refus, vers_univarie};
+use super::resolution_radicaux::{cubique, quartique};
+
+/// Précision de tri des racines (racines distinctes de petits degrés).
+const DIGITS_TRI: usize = 40;
+
+/// Chiffres des encadrements affichés (racines sans radicaux réels).
+const DIGITS_ENCADREMENT: usize = 6;
+
+/// Au-delà, pas de recherche ±p/q (la factorisation sur Q prend le relais).
+const CANDIDATS_MAX: usize = 2000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Domaine {
+    Reel,
+    Complexe,
+}
+
+#[derive(Clone, Debug)]
+pub enum Racine {
+    /// re + i·im (im = 0 pour une racine réelle).
+    Exacte { re: Expr, im: Expr },
+    /// Seule racine réelle de `polynome` dans l’encadrement (pas de radicaux réels).
+    Isolee {
+        polynome: Polynome,
+        encadrement: Encadrement,
+    },
+}
+
+impl Racine {
+    pub fn est_reelle(&self) -> bool {
+        match self {
+            Racine::Exacte { im, .. } => matches!(im, Expr::Rat(r) if r.is_zero()),
+            Racine::Isolee { .. } => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Solutions {
+    /// Aucune solution.
+    Vide,
+    /// Toutes les valeurs, sauf les points exclus (zéros du dénominateur).
+    Tout(Vec<Racine>),
+    /// Ensemble fini, trié (partie réelle puis imaginaire).
+    Racines(Vec<Racine>),
+}
+
+/// Résout `e` (Egal(lhs, rhs), ou e = 0) pour l’inconnue `var` (déduite si None).
+/// Retourne l’inconnue et l’ensemble solution ; la démarche va dans `preuve`.
+pub fn resous(
+    e: &Expr,
+    var: Option<&str>,
+    domaine: Domaine,
+    preuve: &mut String,
+) -> Result<(String, Solutions), String> {
+    let (var, mut p, q) = lit_inconnue(e, var, "solve")?;
+
+    preuve.push_str(&format!("équation : {} = 0", p.format(&var)));
+    if q.degre() > 0 {
+        preuve.push_str(&format!(", avec {} ≠ 0", q.format(&var)));
+    }
+    preuve.push('\n');
+
+    // 1) zéros communs à P et Q : exclus (toute la puissance de g retirée, tracée une fois)
+    let g = p.pgcd(&q);
+    if g.degre() > 0 && !p.est_zero() {
+        preuve.push_str(&format!(
+            "facteur commun {} : ses zéros annulent le dénominateur (exclus)\n",
+            g.format(&var)
+        ));
+        loop {
+            let h = p.pgcd(&g);
+            if h.degre() == 0 {
+                break;
+            }
+            p = p.divrem(&h).0;
+        }
+    }
+
+    // 2) P constant
+    if p.degre() == 0 {
+        if !p.est_zero() {
+            preuve.push_str(&format!(
+                "{} ≠ 0 : aucune valeur ne convient\n",
+                p.format(&var)
+            ));
+            return Ok((var, Solutions::Vide));
+        }
+        let exclus = if q.degre() > 0 {
+            preuve.push_str("0 = 0 : toute valeur convient, hors zéros du dénominateur\n");
+            let mut sans_preuve = String::new();
+            racines(&q, &var, Domaine::Reel, &mut sans_preuve)?
+        } else {
+            preuve.push_str("0 = 0 : toute valeur convient\n");
+            vec![]
+        };
+        return Ok((var, Solutions::Tout(exclus)));
+    }
+
+    // 3) – 5)
+    let r = racines(&p, &var, domaine, preuve)?;
+    if r.is_empty() {
+        Ok((var, Solutions::Vide))
+    } else {
+        Ok((var, Solutions::Racines(r)))
+    }
+}
+
+/// lhs - rhs = P/Q en l’inconnue (donnée ou seule variable présente, “x” par défaut).
+pub fn lit_inconnue(
+    e: &Expr,
+    var: Option<&str>,
+    nom: &str,
+) -> Result<(String, Polynome, Polynome), String> {
+    let f = match e {
+        Expr::Egal(a, b) => Expr::Sub(a.clone(), b.clone()),
+        autre => autre.clone(),
+    };
+    let (num, den) = lit_fraction(&f).map_err(|err| {
+        message_refus(nom, err, || {
+            format!("{nom} : équation rationnelle à coefficients rationnels attendue")
+        })
+    })?;
+
+    let mut vars = num.variables();
+    vars.extend(den.variables());
+    vars.sort();
+    vars.dedup();
+    let var = match (var, vars.as_slice()) {
+        (Some(v), []) => v.to_string(),
+        (Some(v), [w]) if w == v => v.to_string(),
+        (Some(v), _) => {
+            return Err(format!(
+                "{nom} : {v} doit être la seule variable (reçu {})",
+                vars.join(", ")
+            ))
+        }
+        (None, []) => "x".to_string(),
+        (None, [w]) => w.clone(),
+        (None, _) => {
+            return Err(format!(
+                "{nom} : une seule inconnue attendue (reçu {})",
+                vars.join(", ")
+            ))
+        }
+    };
+    let (p, q) = vers_univarie(&num, &var)
+        .zip(vers_univarie(&den, &var))
+        .ok_or_else(|| format!("{nom} : lecture polynomiale impossible"))?;
+    Ok((var, p, q))
+}
+
+/// Toutes les racines réelles de p par isolation (realroots(…)), sans forme close :
+/// partie sans carré p / pgcd(p, p'), puis suite de Sturm.
+pub fn racines_isolees(p: &Polynome, var: &str, preuve: &mut String) -> Solutions {
+    let sans_carre = p.divrem(&p.pgcd(&p.derivee())).0;
+    if sans_carre.degre() < p.degre() {
+        preuve.push_str(&format!(
+            "partie sans carré : {} / pgcd(P, P') = {}\n",
+            p.format(var),
+            sans_carre.format(var)
+        ));
+    }
+    if sans_carre.degre() == 0 {
+        return Solutions::Vide;
+    }
+    preuve.push_str(&trace_sturm(&sans_carre, var));
+    // racines rationnelles (théorème des racines rationnelles) : affichées exactement
+    let rationnelles = racines_rationnelles_seules(&sans_carre);
+    let r: Vec<Racine> = isole_racines(&sans_carre)
+        .into_iter()
+        .map(|e| {
+            let exacte = rationnelles.iter().find(|&x| e.bas < *x && *x <= e.haut);
+            let encadrement = match exacte {
+                Some(x) => Encadrement {
+                    bas: x.clone(),
+                    haut: x.clone(),
+                },
+                None => e,
+            };
+            Racine::Isolee {
+                polynome: sans_carre.clone(),
+                encadrement,
+            }
+        })
+        .collect();
+    if r.is_empty() {
+        Solutions::Vide
+    } else {
+        Solutions::Racines(r)
+    }
+}
+
+/* ------------------------ Racines par degré ------------------------ */
+
+/// Racines de p (degré ≥ 1), triées, sans répétition.
+fn racines(
+    p: &Polynome,
+    var: &str,
+    domaine: Domaine,
+    preuve: &mut String,
+) -> Result<Vec<Racine>, String> {
+    let mut out = Vec::new();
+    let reste = if p.degre() <= 2 {
+        p.clone()
+    } else {
+        deflation_rationnelle(p, var, preuve, &mut out)
+    };
+
+    match reste.degre() {
+        0 => {}
+        1 | 2 => racines_petit_degre(&reste, var, domaine, preuve, &mut out),
+        3 => racines_irreductible(&reste, var, domaine, preuve, &mut out)?,
+        _ => {
+            let (_, facteurs) = factorise_polynome(&reste)?;
+            let txt: Vec<String> = facteurs
+                .iter()
+                .map(|(g, e)| match e {
+                    1 => format!("({})", g.format(var)),
+                    _ => format!("({})^{e}", g.format(var)),
+                })
+                .collect();
+            preuve.push_str(&format!(
+                "factorisation sur Q : {} ∝ {}\n",
+                reste.format(var),
+                txt.join("·")
+            ));
+            for (g, _) in &facteurs {
+                match g.degre() {
+                    1 | 2 => racines_petit_degre(g, var, domaine, preuve, &mut out),
+                    _ => racines_irreductible(g, var, domaine, preuve, &mut out)?,
+                }
+            }
+        }
+    }
+    trie_racines(&mut out);
+    Ok(out)
+}
+
+/// Candidats ±p/q (p | a0, q | an) de f ∈ Z[x] primitif, a0 ≠ 0 ; None si trop nombreux
+/// ou si un coefficient extrême n’est pas factorisé dans le budget.
+fn candidats_rationnels(a0: &BigInt, an: &BigInt) -> Option<Vec<BigRational>> {
+    let (dp, dq) = (diviseurs(a0)?, diviseurs(an)?);
+    if dp.len() * dq.len() > CANDIDATS_MAX {
+        return None;
+    }
+    let mut c: Vec<BigRational> = dp
+        .iter()
+        .flat_map(|p| {
+            dq.iter()
+                .map(move |q| BigRational::new(p.clone(), q.clone()))
+        })
+        .flat_map(|r| [-r.clone(), r])
+        .collect();
+    c.sort();
+    c.dedup();
+    Some(c)
+}
+
+/// Diviseurs positifs de |n| (n ≠ 0), via la factorisation (arith.rs).
+fn diviseurs(n: &BigInt) -> Option<Vec<BigInt>> {
+    let f = factorise(n);
+    if !f.non_resolus.is_empty() {
+        return None;
+    }
+    let mut d = vec![BigInt::one()];
+    for (p, e) in &f.premiers {
+        let mut suivants = Vec::new();
+        for x in &d {
+            let mut pk = x.clone();
+            for _ in 0..=*e {
+                suivants.push(pk.clone());
+                pk *= p;
+            }
+        }
+        d = suivants;
+        if d.len() > CANDIDATS_MAX {
+            return None;
+        }
+    }
+    Some(d)
+}
+
+/// Racines rationnelles de p (distinctes, triées), sans preuve : sert à la résolvante
+/// de Ferrari (resolution_radicaux.rs).
+pub(crate) fn racines_rationnelles_seules(p: &Polynome) -> Vec<BigRational> {
+    let mut out = Vec::new();
+    deflation_rationnelle(p, "m", &mut String::new(), &mut out);
+    out.into_iter()
+        .filter_map(|r| match r {
+            Racine::Exacte {
+                re: Expr::Rat(x), ..
+            } => Some(x),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Théorème des racines rationnelles + division par (x - r) à chaque racine trouvée ;
+/// retourne le quotient (sans racine rationnelle).
+fn deflation_rationnelle(
+    p: &Polynome,
+    var: &str,
+    preuve: &mut String,
+    out: &mut Vec<Racine>,
+) -> Polynome {
+    let mut reste = p.clone();
+    let mut racine = |r: BigRational, reste: &mut Polynome, preuve: &mut String| {
+        let facteur = Polynome::nouveau(vec![-r.clone(), BigRational::one()]);
+        let mut k = 0;
+        while !reste.est_zero() && reste.degre() > 0 && reste.evalue(&r).is_zero() {
+            *reste = reste.divrem(&facteur).0;
+            k += 1;
+        }
+        let multiplicite = if k > 1 {
+            format!(" (multiplicité {k})")
+        } else {
+            String::new()
+        };
+        preuve.push_str(&format!(
+            "{var} = {r} racine{multiplicite} : division par ({})\n",
+            facteur.format(var)
+        ));
+        out.push(Racine::Exacte {
+            re: Expr::Rat(r),
+            im: Expr::Rat(BigRational::zero()),
+        });
+    };
+
+    if reste.coeff(0).is_zero() {
+        racine(BigRational::zero(), &mut reste, preuve);
+    }
+    if reste.degre() == 0 {
+        return reste;
+    }
+
+    let (_, entiers) = reste.partie_primitive();
+    let (a0, an) = (&entiers[0], &entiers[entiers.len() - 1]);
+    let Some(candidats) = candidats_rationnels(a0, an) else {
+        preuve.push_str("racines rationnelles : trop de candidats ±p/q, factorisation sur Q\n");
+        return reste;
+    };
+    preuve.push_str(&format!(
+        "racines rationnelles possibles ±p/q (p | {a0}, q | {an}) : {} candidats\n",
+        candidats.len()
+    ));
+    for r in candidats {
+        if reste.degre() == 0 {
+            break;
+        }
+        if reste.evalue(&r).is_zero() {
+            racine(r, &mut reste, preuve);
+        }
+    }
+    reste
+}
+
+/// Facteur irréductible de degré ≥ 3 : Cardano / Ferrari, sinon encadrements certifiés.
+fn racines_irreductible(
+    g: &Polynome,
+    var: &str,
+    domaine: Domaine,
+    preuve: &mut String,
+    out: &mut Vec<Racine>,
+) -> Result<(), String> {
+    let formule = match g.degre() {
+        3 => cubique(g, var, preuve),
+        4 => quartique(g, var, preuve),
+        _ => None,
+    };
+    if let Some(r) = formule {
+        let (reelles, complexes): (Vec<Racine>, Vec<Racine>) =
+            r.into_iter().partition(Racine::est_reelle);
+        out.extend(reelles);
+        match domaine {
+            Domaine::Complexe => out.extend(complexes),
+            Domaine::Reel if !complexes.is_empty() => preuve.push_str(&format!(
+                "{} racine(s) non réelle(s) écartée(s) (ℝ)\n",
+                complexes.len()
+            )),
+            Domaine::Reel => {}
+        }
+        return Ok(());
+    }
+
+    // pas de radicaux réels : racines réelles isolées (suite de Sturm)
+    let encadrements = isole_racines(g);
+    let non_reelles = g.degre() - encadrements.len();
+    preuve.push_str(&format!(
+        "{} : {} racine(s) réelle(s) isolée(s) par la suite de Sturm\n",
+        g.format(var),
+        encadrements.len()
+    ));
+    if domaine == Domaine::Complexe && non_reelles > 0 {
+        return Err(format!(
+            "csolve : {non_reelles} racine(s) non réelle(s) de {} sans forme par radicaux",
+            g.format(var)
+        ));
+    }
+    out.extend(encadrements.into_iter().map(|e| Racine::Isolee {
+        polynome: g.clone(),
+        encadrement: e,
+    }));
+    Ok(())
+}
+
+/// Degré 1 ou 2 : racine directe ou discriminant.
+fn racines_petit_degre(
+    p: &Polynome,
+    var: &str,
+    domaine: Domaine,
+    preuve: &mut String,
+    out: &mut Vec<Racine>,
+) {
+    let zero = || Expr::Rat(BigRational::zero());
+    if p.degre() == 1 {
+        let x = -p.coeff(0) / p.coeff(1);
+        preuve.push_str(&format!("{} = 0 : {var} = {x}\n", p.format(var)));
+        out.push(Racine::Exacte {
+            re: Expr::Rat(x),
+            im: zero(),
+        });
+        return;
+    }
+
+    let (a, b, c) = (p.coeff(2), p.coeff(1), p.coeff(0));
+    let delta = &b * &b - BigRational::from_integer(4.into()) * &a * &c;
+    let deux_a = &a + &a;
+    let sommet = -&b / &deux_a;
+    preuve.push_str(&format!(
+        "{} = 0 : Δ = b² - 4ac = {}² - 4·{}·{} = {delta}",
+        p.format(var),
+        entre_parentheses_si_negatif(&b),
+        entre_parentheses_si_negatif(&a),
+        entre_parentheses_si_negatif(&c),
+    ));
+
+    match delta.cmp(&BigRational::zero()) {
+        Ordering::Equal => {
+            preuve.push_str(&format!(
+                " = 0 : racine double {var} = -b/(2a) = {sommet}\n"
+            ));
+            out.push(Racine::Exacte {
+                re: Expr::Rat(sommet),
+                im: zero(),
+            });
+        }
+        Ordering::Greater => {
+            preuve.push_str(&format!(
+                " > 0 : deux racines réelles {var} = (-b ± √Δ)/(2a)\n"
+            ));
+            for signe in [-1, 1] {
+                let k = BigRational::from_integer(signe.into()) / &deux_a;
+                out.push(Racine::Exacte {
+                    re: radical(&sommet, &k, &delta),
+                    im: zero(),
+                });
+            }
+        }
+        Ordering::Less => {
+            let k = BigRational::one() / deux_a.abs();
+            let im = radical(&BigRational::zero(), &k, &-&delta);
+            let txt = format!(
+                "{} ± i·{}",
+                format_exact_final(&Expr::Rat(sommet.clone())),
+                format_exact_final(&im)
+            );
+            match domaine {
+                Domaine::Reel => preuve.push_str(&format!(
+                    " < 0 : aucune racine réelle (racines complexes {var} = {txt})\n"
+                )),
+                Domaine::Complexe => {
+                    preuve.push_str(&format!(
+                        " < 0 : deux racines complexes conjuguées {var} = (-b ± i√(-Δ))/(2a) = {txt}\n"
+                    ));
+                    let moins_im = Expr::Sub(Box::new(zero()), Box::new(im.clone()))
+                        .simplify()
+                        .canon();
+                    for im in [moins_im, im] {
+                        out.push(Racine::Exacte {
+                            re: Expr::Rat(sommet.clone()),
+                            im,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// r + k·√d, simplifié et canonisé (√8 => 2√2).
+fn radical(r: &BigRational, k: &BigRational, d: &BigRational) -> Expr {
+    let racine = Expr::Mul(
+        Box::new(Expr::Rat(k.clone())),
+        Box::new(Expr::Sqrt(Box::new(Expr::Rat(d.clone())))),
+    );
+    Expr::Add(Box::new(Expr::Rat(r.clone())), Box::new(racine))
+        .simplify()
+        .canon()
+}
+
+fn entre_parentheses_si_negatif(r: &BigRational) -> String {
+    if r.is_negative() {
+        format!("({r})")
+    } else {
+        r.to_string()
+    }
+}
+
+/// Tri par valeur (partie réelle, puis imaginaire) ; lecture ΣLocal, racines distinctes.
+fn trie_racines(r: &mut [Racine]) {
+    r.sort_by_cached_key(cle_tri);
+}
+
+/// Clef de tri : (partie réelle, partie imaginaire) lues à DIGITS_TRI chiffres.
+fn cle_tri(x: &Racine) -> (BigInt, BigInt) {
+    let echelle = BigRational::from_integer(BigInt::from(10).pow(DIGITS_TRI as u32));
+    match x {
+        Racine::Exacte { re, im } => (
+            eval_scaled(re, DIGITS_TRI).unwrap_or_default(),
+            eval_scaled(im, DIGITS_TRI).unwrap_or_default(),
+        ),
+        Racine::Isolee {
+            polynome,
+            encadrement,
+        } => {
+            let fin = affine(polynome, encadrement, &echelle.recip());
+            ((fin.bas * &echelle).floor().to_integer(), BigInt::zero())
+        }
+    }
+}
+
+/// Racines de p avec leur multiplicité (décomposition sans carré, puis chaque facteur) ;
+/// dans ℂ, un facteur aux racines non réelles sans radicaux est écarté (noté).
+pub fn racines_multiples(
+    p: &Polynome,
+    var: &str,
+    preuve: &mut String,
+) -> Result<Vec<(Racine, u32)>, String> {
+    let mut out = Vec::new();
+    for (f, k) in p.sans_carre() {
+        if k > 1 {
+            preuve.push_str(&format!(
+                "facteur ({})^{k} : racines de multiplicité {k}\n",
+                f.format(var)
+            ));
+        }
+        let r = match racines(&f, var, Domaine::Complexe, preuve) {
+            Ok(r) => r,
+            Err(_) => {
+                preuve.push_str(&format!(
+                    "{} : racines non réelles sans forme par radicaux, seules les réelles sont données\n",
+                    f.format(var)
+                ));
+                racines(&f, var, Domaine::Reel, preuve)?
+            }
+        };
+        out.extend(r.into_iter().map(|x| (x, k)));
+    }
+    out.sort_by_cached_key(|(x, _)| cle_tri(x));
+    Ok(out)
+}
+
+/* ------------------------ Affichage ------------------------ */
+
+/// “x ∈ {-√2, √2}”, “x ∈ ∅”, “x ∈ ℝ \ {1}”, “x ∈ {-1-i√2, -1+i√2}”,
+/// “x ∈ {x₁, x₂, x₃} avec x₁ ∈ ]-1.879386, -1.879385[, …” (racines isolées).
+pub fn format_solutions(var: &str, s: &Solutions, domaine: Domaine) -> String {
+    let tout = match domaine {
+        Domaine::Reel => "ℝ",
+        Domaine::Complexe => "ℂ",
+    };
+    let mut encadrements: Vec<String> = Vec::new();
+    let mut ensemble = |r: &[Racine]| {
+        let v: Vec<String> = r
+            .iter()
+            .map(|x| match x {
+                Racine::Exacte { re, im } => format_racine(re, im),
+                // racine rationnelle tombée sur un point de bissection : exacte
+                Racine::Isolee { encadrement, .. } if encadrement.bas == encadrement.haut => {
+                    format_exact_final(&Expr::Rat(encadrement.bas.clone()))
+                }
+                Racine::Isolee {
+                    polynome,
+                    encadrement,
+                } => {
+                    let nom = format!("{var}{}", indice(encadrements.len() + 1));
+                    let (bas, haut) =
+                        encadrement_decimal(polynome, encadrement, DIGITS_ENCADREMENT);
+                    encadrements.push(format!("{nom} ∈ ]{bas}, {haut}["));
+                    nom
+                }
+            })
+            .collect();
+        format!("{{{}}}", v.join(", "))
+    };
+    let texte = match s {
+        Solutions::Vide => format!("{var} ∈ ∅"),
+        Solutions::Tout(exclus) if exclus.is_empty() => format!("{var} ∈ {tout}"),
+        Solutions::Tout(exclus) => format!("{var} ∈ {tout} \\ {}", ensemble(exclus)),
+        Solutions::Racines(r) => format!("{var} ∈ {}", ensemble(r)),
+    };
+    if encadrements.is_empty() {
+        texte
+    } else {
+        format!("{texte} avec {}", encadrements.join(", "))
+    }
+}
+
+/// “λ ∈ {2, 5}” ; multiplicités > 1 ajoutées : “λ ∈ {2, 5} ; 2 double”, “0 triple”, “(×k)”.
+pub fn format_racines_multiples(var: &str, r: &[(Racine, u32)]) -> String {
+    let racines: Vec<Racine> = r.iter().map(|(x, _)| x.clone()).collect();
+    let s = format_solutions(var, &Solutions::Racines(racines), Domaine::Complexe);
+    let multiples: Vec<String> = r
+        .iter()
+        .filter(|(_, k)| *k > 1)
+        .map(|(x, k)| {
+            let nom = match x {
+                Racine::Exacte { re, im } => format_racine(re, im),
+                Racine::Isolee { .. } => "racine isolée".into(),
+            };
+            match k {
+                2 => format!("{nom} double"),
+                3 => format!("{nom} triple"),
+                _ => format!("{nom} (×{k})"),
+            }
+        })
+        .collect();
+    if multiples.is_empty() || r.is_empty() {
+        s
+    } else {
+        format!("{s} ; {}", multiples.join(", "))
+    }
+}
+
+/// Lecture ΣLocal des racines isolées, chiffres garantis : “x₁ ≈ -1.8793852415, …”
+/// (mêmes noms que format_solutions) ; None s’il n’y en a pas.
+pub fn lecture_certifiee(var: &str, s: &Solutions, digits: usize) -> Option<String> {
+    let Solutions::Racines(r) = s else {
+        return None;
+    };
+    let lectures: Vec<String> = r
+        .iter()
+        .filter_map(|x| match x {
+            Racine::Isolee {
+                polynome,
+                encadrement,
+            } if encadrement.bas != encadrement.haut => {
+                Some(decimales_certifiees(polynome, encadrement, digits))
+            }
+            _ => None,
+        })
+        .enumerate()
+        .map(|(i, d)| format!("{var}{} ≈ {d}", indice(i + 1)))
+        .collect();
+    (!lectures.is_empty()).then(|| lectures.join(", "))
+}
+
+/// Indice “₁₂…” (nom des racines isolées).
+fn indice(n: usize) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| char::from_u32(0x2080 + c.to_digit(10).unwrap()).unwrap())
+        .collect()
+}
+
+/// re + i·im, sans parenthèses extérieures : “-1+√2”, “1-i√2/2”, “-2i”.
+pub fn format_racine(re: &Expr, im: &Expr) -> String {
+    let re = match re {
+        // -1·(a+b) => -(a+b)
+        Expr::Mul(a, b) if matches!(a.as_ref(), Expr::Rat(r) if *r == -BigRational::one()) => {
+            let t = sans_parentheses_externes(format_exact_final(b));
+            if matches!(b.as_ref(), Expr::Add(_, _) | Expr::Sub(_, _)) {
+                format!("-{}", entre_parentheses(t))
+            } else {
+                format!("-{t}")
+            }
+        }
+        _ => sans_parentheses_externes(format_exact_final(re)),
+    };
+    let negatif = eval_scaled(im, DIGITS_TRI).is_ok_and(|v| v.is_negative());
+    let module = if negatif {
+        Expr::Sub(
+            Box::new(Expr::Rat(BigRational::zero())),
+            Box::new(im.clone()),
+        )
+        .simplify()
+        .canon()
+    } else {
+        im.clone()
+    };
+    let m = sans_parentheses_externes(format_exact_final(&module));
+    if m == "0" {
+        return re;
+    }
+    // i placé devant le radical : “2i”, “i√2”, “3i√2/2”, “i/2”
+    let i = match m.find(['√', '/']) {
+        _ if m == "1" => "i".to_string(),
+        Some(k) if m[..k].chars().all(|c| c.is_ascii_digit()) => {
+            let pre = if &m[..k] == "1" { "" } else { &m[..k] };
+            format!("{pre}i{}", &m[k..])
+        }
+        None if m.chars().all(|c| c.is_ascii_digit()) => format!("{m}i"),
+        _ => format!("i·{m}"),
+    };
+    let signe = if negatif { "-" } else { "+" };
+    match (re.as_str(), negatif) {
+        ("0", false) => i,
+        ("0", true) => format!("-{i}"),
+        _ => format!("{re}{signe}{i}"),
+    }
+}
+
+/// “(a+b)” => “a+b” si la première parenthèse ferme à la fin.
+fn sans_parentheses_externes(s: String) -> String {
+    if !(s.starts_with('(') && s.ends_with(')')) {
+        return s;
+    }
+    let mut profondeur = 0i32;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => profondeur += 1,
+            ')' => {
+                profondeur -= 1;
+                if profondeur == 0 && i + 1 != s.len() {
+                    return s;
+                }
+            }
+            _ => {}
+        }
+    }
+    s[1..s.len() - 1].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noyau::eval::lecture_brute;
+
+    fn resout(s: &str, domaine: Domaine) -> (String, String) {
+        let e = lecture_brute(s).unwrap();
+        let mut preuve = String::new();
+        let (var, sol) = resous(&e, None, domaine, &mut preuve).unwrap();
+        (format_solutions(&var, &sol, domaine), preuve)
+    }
+
+    #[test]
+    fn lineaires_et_cas_degeneres() {
+        assert_eq!(resout("2x + 3 = 7", Domaine::Reel).0, "x ∈ {2}");
+        assert_eq!(resout("3y = 1", Domaine::Reel).0, "y ∈ {1/3}");
+        assert_eq!(resout("x + 1 = x + 2", Domaine::Reel).0, "x ∈ ∅");
+        assert_eq!(resout("2(x+1) = 2x + 2", Domaine::Reel).0, "x ∈ ℝ");
+        // domaine : x ≠ 1 exclu, (x^2-1)/(x-1) = 2 n’a pas de solution
+        let (s, preuve) = resout("(x^2-1)/(x-1) = 2", Domaine::Reel);
+        assert_eq!(s, "x ∈ ∅");
+        assert_eq!(preuve.matches("facteur commun").count(), 1, "{preuve}");
+        assert_eq!(resout("x/x = 1", Domaine::Reel).0, "x ∈ ℝ \\ {0}");
+    }
+
+    #[test]
+    fn quadratiques_discriminant() {
+        let (s, preuve) = resout("x^2 - 2 = 0", Domaine::Reel);
+        assert_eq!(s, "x ∈ {-√2, √2}");
+        assert!(
+            preuve.contains("Δ = b² - 4ac = 0² - 4·1·(-2) = 8 > 0"),
+            "{preuve}"
+        );
+        assert_eq!(
+            resout("x^2 - 2x - 1 = 0", Domaine::Reel).0,
+            "x ∈ {1-√2, 1+√2}"
+        );
+        assert_eq!(resout("x^2 = 2x - 1", Domaine::Reel).0, "x ∈ {1}");
+        assert_eq!(resout("x^2 - 5x + 6 = 0", Domaine::Reel).0, "x ∈ {2, 3}");
+
+        let (s, preuve) = resout("x^2 + 2x + 3 = 0", Domaine::Reel);
+        assert_eq!(s, "x ∈ ∅");
+        assert!(preuve.contains("aucune racine réelle"), "{preuve}");
+        let (s, _) = resout("x^2 + 2x + 3 = 0", Domaine::Complexe);
+        assert_eq!(s, "x ∈ {-1-i√2, -1+i√2}");
+        assert_eq!(resout("x^2 + 1 = 0", Domaine::Complexe).0, "x ∈ {-i, i}");
+        assert_eq!(
+            resout("2x^2 - 2x + 5 = 0", Domaine::Complexe).0,
+            "x ∈ {1/2-3i/2, 1/2+3i/2}"
+        );
+    }
+
+    #[test]
+    fn degre_superieur_par_facteurs() {
+        assert_eq!(resout("x^3 - 2x = 0", Domaine::Reel).0, "x ∈ {-√2, 0, √2}");
+        let (s, preuve) = resout("2x^3 - 3x^2 - 3x + 2 = 0", Domaine::Reel);
+        assert_eq!(s, "x ∈ {-1, 1/2, 2}");
+        assert!(preuve.contains("(p | 2, q | 2) : 6 candidats"), "{preuve}");
+        assert_eq!(resout("(x-1)^3 = 0", Domaine::Reel).0, "x ∈ {1}");
+    }
+
+    #[test]
+    fn cardano_et_casus_irreducibilis() {
+        let (s, preuve) = resout("x^3 = 2", Domaine::Reel);
+        assert_eq!(s, "x ∈ {∛2}");
+        assert!(preuve.contains("D > 0 : Cardano"), "{preuve}");
+        assert_eq!(
+            resout("x^3 + x + 1 = 0", Domaine::Reel).0,
+            "x ∈ {∛((-9+√93)/18)+∛((-9-√93)/18)}"
+        );
+        assert_eq!(
+            resout("x^3 = 2", Domaine::Complexe).0.matches(", ").count(),
+            2
+        );
+
+        // trois racines réelles : encadrements certifiés (Sturm)
+        let (s, preuve) = resout("x^3 - 3x + 1 = 0", Domaine::Reel);
+        assert_eq!(
+            s,
+            "x ∈ {x₁, x₂, x₃} avec x₁ ∈ ]-1.879386, -1.879385[, x₂ ∈ ]0.347296, 0.347297[, x₃ ∈ ]1.532088, 1.532089["
+        );
+        assert!(preuve.contains("casus irreducibilis"), "{preuve}");
+    }
+
+    #[test]
+    fn ferrari_et_bicarrees() {
+        assert_eq!(
+            resout("x^4 - 10x^2 + 1 = 0", Domaine::Reel).0,
+            "x ∈ {-√2-√3, √2-√3, √3-√2, √2+√3}"
+        );
+        // racines passées par la forme normale : dénouées, fusionnées
+        assert_eq!(
+            resout("x^4 - 4x^2 + 2 = 0", Domaine::Reel).0,
+            "x ∈ {-√(2+√2), -√(2-√2), √(2-√2), √(2+√2)}"
+        );
+        assert_eq!(
+            resout("x^4 + 4x^2 + 2 = 0", Domaine::Complexe).0,
+            "x ∈ {-i√(2+√2), -i√(2-√2), i√(2-√2), i√(2+√2)}"
+        );
+        assert_eq!(resout("x^4 - 2 = 0", Domaine::Reel).0, "x ∈ {-∜2, ∜2}");
+        assert_eq!(
+            resout("x^4 + 1 = 0", Domaine::Complexe).0,
+            "x ∈ {-√2/2-i√2/2, -√2/2+i√2/2, √2/2-i√2/2, √2/2+i√2/2}"
+        );
+        let (s, preuve) = resout("x^4 + 4x - 1 = 0", Domaine::Reel);
+        assert_eq!(s, "x ∈ {-√(-2+4√2)/2-√2/2, √(-2+4√2)/2-√2/2}");
+        assert!(preuve.contains("m = 1"), "{preuve}");
+
+        // sans radicaux réels : réelles isolées, csolve refuse plutôt que d’omettre
+        let (s, _) = resout("x^5 - x - 1 = 0", Domaine::Reel);
+        assert_eq!(s, "x ∈ {x₁} avec x₁ ∈ ]1.167303, 1.167304[");
+        let e = lecture_brute("x^4 + x^3 - 2x + 5 = 0").unwrap();
+        assert!(resous(&e, None, Domaine::Complexe, &mut String::new()).is_err());
+    }
+}
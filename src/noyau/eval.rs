@@ -5,13 +5,24 @@
 //!
 //! Remarque : trig spéciale est appliquée ici (pas encore dans Expr::simplify),
 //! pour garder la “preuve” hors de l’AST.
-
-use super::expr::Expr;
-use super::format::{format_exact_final, format_expr_pretty};
+//!
+//! Les commandes (factor(…), isprime(…)) et le contexte "mod n: …" court-circuitent
+//! ce pipeline : voir commandes.rs et modulaire.rs. Une équation (lhs = rhs) est
+//! résolue dans ℝ (equations.rs).
+
+use super::commandes::{commande, resous_equation};
+use super::equations::Domaine;
+use super::expr::{map_matrice, Expr};
+use super::format::{format_exact_final, format_expr_pretty, format_latex};
+use super::fractions_rationnelles::simplifie_fractions;
 use super::identites_trig::trig_identites;
 use super::jetons::{format_tokens, tokenize};
-use super::lecture::{eval_scaled, scaled_to_decimal};
+use super::lecture::{lecture_scaled, scaled_to_decimal};
+use super::matrices::evalue_matrices;
+use super::modulaire::evalue_modulaire;
+use super::radicaux::{normalise_radicaux, rationalise_denominateurs, trace_denouements};
 use super::rpn::{from_rpn, to_rpn};
+use super::systemes::evalue_systeme;
 // trig_special + preuve
 use super::trig::{trig_special, TrigFn, TrigOutcome};
 
@@ -27,8 +38,11 @@ pub struct DemarcheNoyau {
 
 /// API publique : évalue une expression et retourne:
 /// - EXACT (forme finie)
-/// - ΣLocal (lecture décimale tronquée) : None si indéfini OU si variable
+/// - ΣLocal (lecture décimale tronquée) : None si indéfini, variable ou exposant symbolique
 /// - Démarche (jetons, rpn, avant/après, preuve)
+///
+/// Les commandes “hors expression” (factor(…), isprime(…)) sont aiguillées vers commandes.rs,
+/// le contexte "mod n: …" vers modulaire.rs, les systèmes "{…; …}" vers systemes.rs.
 pub fn eval_expression(
     expr_str: &str,
     digits: usize,
@@ -38,6 +52,51 @@ pub fn eval_expression(
         return Err("Entrée vide".into());
     }
 
+    if let Some(res) = evalue_modulaire(s) {
+        return res;
+    }
+    if let Some(res) = evalue_systeme(s) {
+        return res;
+    }
+    if let Some(res) = commande(s, digits) {
+        return res;
+    }
+
+    let (expr_c, d) = forme_exacte(s)?;
+
+    // 5h) Équation à la racine : résolution réelle (comme solve(…))
+    if matches!(expr_c, Expr::Egal(_, _)) {
+        return resous_equation(s, None, Domaine::Reel, digits, d);
+    }
+
+    // 6) EXACT final (sur la forme canon) ; matrice : grille, et LaTeX dans la démarche
+    let exact = format_exact_final(&expr_c);
+    let mut d = d;
+    if matches!(expr_c, Expr::Matrice(_)) {
+        if !d.preuve.is_empty() && !d.preuve.ends_with('\n') {
+            d.preuve.push('\n');
+        }
+        d.preuve
+            .push_str(&format!("LaTeX : {}", format_latex(&expr_c)));
+    }
+
+    // 7) ΣLocal (bloquée si indéfini, variable, exposant symbolique ou n! non évalué)
+    //    (sur la forme canon)
+    let lecture = match &expr_c {
+        Expr::Indefini => None,
+        _ if socal_bloquee(&expr_c) => None,
+        _ => {
+            let scaled = lecture_scaled(&expr_c, digits)?;
+            Some(scaled_to_decimal(scaled, digits))
+        }
+    };
+
+    Ok((exact, lecture, d))
+}
+
+/// Étapes 1 à 5g du pipeline : forme canon + démarche (sans ΣLocal).
+/// Sert aussi aux commandes pour évaluer leurs arguments.
+pub(crate) fn forme_exacte(s: &str) -> Result<(Expr, DemarcheNoyau), String> {
     // 1) Jetons
     let jetons = tokenize(s)?;
     let jetons_txt = format_tokens(&jetons);
@@ -49,13 +108,35 @@ pub fn eval_expression(
     // 3) AST (Expr)
     let expr0 = from_rpn(&rpn)?;
 
+    // 3b) Matrices : opérations réduites (coefficients en forme normale), matrices.rs
+    let mut preuve = String::new();
+    let expr_m = evalue_matrices(expr0.clone(), &mut preuve)?;
+
+    // 4) à 5g)
+    let expr_c = forme_normale(expr_m, &mut preuve);
+
+    // 8) Démarche
+    let d = DemarcheNoyau {
+        jetons: jetons_txt,
+        rpn: rpn_txt,
+        avant: format_expr_pretty(&expr0),
+        apres: format_expr_pretty(&expr_c), // reflète la forme finale (identités + canon)
+        note: "Pipeline: jetons → RPN → Expr → matrices → simplify → trig spéciale → re-simplify → identités trig → re-simplify → canon → rationalisation → Q(√d) → fractions rationnelles → EXACT → ΣLocal.".into(),
+        preuve,
+    };
+
+    Ok((expr_c, d))
+}
+
+/// Étapes 4 à 5g : forme normale d’une Expr déjà construite (sert aussi aux
+/// coefficients des matrices).
+pub(crate) fn forme_normale(expr0: Expr, preuve: &mut String) -> Expr {
     // 4) Simplification de base
-    let expr_s0 = expr0.clone().simplify();
+    let expr_s0 = expr0.simplify();
 
     // 5) Trig spéciale (récursive) : remplace sin/cos/tan dès que possible + accumule preuve
     //    OPTI: preuve mut (zéro concat lourde, pas de String retournée en cascade)
-    let mut preuve = String::new();
-    let expr_s1 = applique_trig_speciale(&expr_s0, &mut preuve);
+    let expr_s1 = applique_trig_speciale(&expr_s0, preuve);
 
     // 5b) Re-simplify (important : après remplacements trig)
     let expr_s = expr_s1.simplify();
@@ -63,38 +144,35 @@ pub fn eval_expression(
     // 5c) Identités trig (SAFE) puis re-simplify (important : nettoie Sub(0,·), etc.)
     let expr_b = trig_identites(expr_s).simplify();
 
-    // 5d) Canon
+    // 5d) Canon (les √(a + b√c) dénoués sont tracés dans la preuve)
+    trace_denouements(&expr_b, preuve);
     let expr_c = expr_b.canon();
 
-    // 6) EXACT final (sur la forme canon)
-    let exact = format_exact_final(&expr_c);
+    // 5e) Rationalisation des dénominateurs (conjugués) + preuve, puis re-canon
+    let expr_c = rationalise_denominateurs(expr_c, preuve).canon();
 
-    // 7) ΣLocal (bloquée si indéfini OU si variable) (sur la forme canon)
-    let lecture = match &expr_c {
-        Expr::Indefini => None,
-        _ if contient_var(&expr_c) => None,
-        _ => {
-            let scaled = eval_scaled(&expr_c, digits)?;
-            Some(scaled_to_decimal(scaled, digits))
-        }
-    };
+    // 5f) Forme unique dans Q(√d1, …, √dk) : (1+√2)^5 => 41+29√2
+    let expr_c = normalise_radicaux(expr_c, preuve).canon();
 
-    // 8) Démarche
-    let d = DemarcheNoyau {
-        jetons: jetons_txt,
-        rpn: rpn_txt,
-        avant: format_expr_pretty(&expr0),
-        apres: format_expr_pretty(&expr_c), // reflète la forme finale (identités + canon)
-        note: "Pipeline: jetons → RPN → Expr → simplify → trig spéciale → re-simplify → identités trig → re-simplify → canon → EXACT → ΣLocal.".into(),
-        preuve,
-    };
+    // 5g) Fractions rationnelles : simplification par le pgcd, points exclus dans la preuve
+    simplifie_fractions(expr_c, preuve).canon()
+}
 
-    Ok((exact, lecture, d))
+/// Lecture brute (étapes 1 à 4) : arbre simplifié localement, AVANT canon et
+/// simplification des fractions. solve(…) en a besoin : (x^2-1)/(x-1) = 2 doit
+/// encore voir le dénominateur x-1 (point exclu).
+pub(crate) fn lecture_brute(s: &str) -> Result<Expr, String> {
+    let jetons = tokenize(s)?;
+    let rpn = to_rpn(&jetons)?;
+    Ok(from_rpn(&rpn)?.simplify())
 }
 
-/// Détecte si une expression contient au moins une variable.
+/// Détecte si ΣLocal doit être bloquée : au moins une variable, ou une puissance
+/// à exposant symbolique (2^√2 : pas de lecture sans exp/ln), ou une factorielle /
+/// un binomial restés symboliques (argument non entier ou hors garde de taille), ou un
+/// signe / arrondi non décidé (sign(π/10^70) reste symbolique, sans lecture).
 /// Itératif + garde-fous : si l'arbre est trop gros, on retourne true (SAFE => bloque ΣLocal).
-fn contient_var(expr: &Expr) -> bool {
+fn socal_bloquee(expr: &Expr) -> bool {
     use Expr::*;
 
     const MAX_PILE: usize = 8192;
@@ -113,13 +191,21 @@ fn contient_var(expr: &Expr) -> bool {
         }
 
         match e {
-            Var(_) => return true,
+            Var(_) | Pow(_, _) => return true,
+            Fact(_) | FactDouble(_) | Binom(_, _) | Arrang(_, _) => return true,
+            Pgcd(_, _) | Ppcm(_, _) | Modulo(_, _) => return true,
+            Egal(_, _) | Matrice(_) | Matriciel(_, _) => return true,
 
             Rat(_) | Pi | Indefini => {}
 
             Sqrt(x) | Sin(x) | Cos(x) | Tan(x) => pile.push(x.as_ref()),
 
-            PowInt(x, _) => pile.push(x.as_ref()),
+            // après simplify, un Sign / Floor / … restant n’est pas décidé
+            Sign(_) | Floor(_) | Ceil(_) | Round(_) | Frac(_) => return true,
+
+            Abs(x) => pile.push(x.as_ref()),
+
+            PowInt(x, _) | Root(x, _) => pile.push(x.as_ref()),
 
             Add(a, b) | Sub(a, b) | Mul(a, b) | Div(a, b) => {
                 pile.push(a.as_ref());
@@ -224,6 +310,52 @@ fn applique_trig_speciale(expr: &Expr, preuve: &mut String) -> Expr {
             let xx = applique_trig_speciale(x, preuve);
             PowInt(Box::new(xx), *n)
         }
+        Root(x, k) => Root(Box::new(applique_trig_speciale(x, preuve)), *k),
+        Pow(x, e) => {
+            let xx = applique_trig_speciale(x, preuve);
+            let ee = applique_trig_speciale(e, preuve);
+            Pow(Box::new(xx), Box::new(ee))
+        }
+        Abs(x) => Abs(Box::new(applique_trig_speciale(x, preuve))),
+        Sign(x) => Sign(Box::new(applique_trig_speciale(x, preuve))),
+        Floor(x) => Floor(Box::new(applique_trig_speciale(x, preuve))),
+        Ceil(x) => Ceil(Box::new(applique_trig_speciale(x, preuve))),
+        Round(x) => Round(Box::new(applique_trig_speciale(x, preuve))),
+        Frac(x) => Frac(Box::new(applique_trig_speciale(x, preuve))),
+        Fact(x) => Fact(Box::new(applique_trig_speciale(x, preuve))),
+        FactDouble(x) => FactDouble(Box::new(applique_trig_speciale(x, preuve))),
+        Binom(n, k) => {
+            let nn = applique_trig_speciale(n, preuve);
+            let kk = applique_trig_speciale(k, preuve);
+            Binom(Box::new(nn), Box::new(kk))
+        }
+        Arrang(n, k) => {
+            let nn = applique_trig_speciale(n, preuve);
+            let kk = applique_trig_speciale(k, preuve);
+            Arrang(Box::new(nn), Box::new(kk))
+        }
+        Pgcd(a, b) => {
+            let aa = applique_trig_speciale(a, preuve);
+            let bb = applique_trig_speciale(b, preuve);
+            Pgcd(Box::new(aa), Box::new(bb))
+        }
+        Ppcm(a, b) => {
+            let aa = applique_trig_speciale(a, preuve);
+            let bb = applique_trig_speciale(b, preuve);
+            Ppcm(Box::new(aa), Box::new(bb))
+        }
+        Modulo(a, m) => {
+            let aa = applique_trig_speciale(a, preuve);
+            let mm = applique_trig_speciale(m, preuve);
+            Modulo(Box::new(aa), Box::new(mm))
+        }
+        Egal(a, b) => {
+            let aa = applique_trig_speciale(a, preuve);
+            let bb = applique_trig_speciale(b, preuve);
+            Egal(Box::new(aa), Box::new(bb))
+        }
+        Matrice(l) => map_matrice(l.clone(), |x| applique_trig_speciale(&x, preuve)),
+        Matriciel(op, x) => Matriciel(*op, Box::new(applique_trig_speciale(x, preuve))),
 
         // --- feuilles ---
         Rat(_) | Pi | Indefini | Var(_) => expr.clone(),
@@ -390,6 +522,705 @@ mod tests {
         assert_contains(&exact.replace(' ', ""), "1");
     }
 
+    // --- Valeur absolue / arrondis ---
+
+    #[test]
+    fn abs_rationnel_et_radical() {
+        assert_eq_trim(&ok_exact_only("abs(-3/4)"), "3/4");
+        // 1 - √2 < 0 (décidé par encadrement) => |1 - √2| = √2 - 1
+        let exact = ok_exact_only("abs(1 - sqrt(2))");
+        assert_eq_trim(&exact, "(-1+√2)");
+        assert_contains(&ok_dec("abs(1 - sqrt(2))", 10), "0.4142135623");
+    }
+
+    #[test]
+    fn abs_variable_reste_symbolique() {
+        let (exact, lecture) = ok_exact("abs(x)", 20);
+        assert_eq_trim(&exact, "|x|");
+        assert!(lecture.is_none());
+    }
+
+    #[test]
+    fn floor_ceil_round_frac() {
+        assert_eq_trim(&ok_exact_only("floor(-7/2)"), "-4");
+        assert_eq_trim(&ok_exact_only("ceil(-7/2)"), "-3");
+        assert_eq_trim(&ok_exact_only("round(5/2)"), "3");
+        assert_eq_trim(&ok_exact_only("frac(7/3)"), "1/3");
+
+        // π et √ via encadrement certifié
+        assert_eq_trim(&ok_exact_only("floor(pi)"), "3");
+        assert_eq_trim(&ok_exact_only("ceil(10*sqrt(2))"), "15");
+        assert_eq_trim(&ok_exact_only("frac(pi)"), "(-3+π)");
+        assert_eq_trim(&ok_exact_only("sign(pi - 22/7)"), "-1");
+    }
+
+    #[test]
+    fn floor_symbolique_rendu() {
+        let (exact, lecture) = ok_exact("floor(x) + ceil(y)", 20);
+        assert_contains(&exact, "⌊x⌋");
+        assert_contains(&exact, "⌈y⌉");
+        assert!(lecture.is_none());
+    }
+
+    #[test]
+    fn signe_non_decide_reste_symbolique() {
+        // π/10^70 : décidé à précision plus fine
+        assert_eq_trim(&ok_exact_only("sign(pi/(10^70))"), "1");
+        assert_eq_trim(&ok_exact_only("floor(pi/(10^70))"), "0");
+
+        // π/10^400 : trop proche de 0 pour l’encadrement => symbolique, ΣLocal bloquée
+        let (exact, lecture) = ok_exact("sign(pi/(10^400))", 20);
+        assert_contains(&exact, "sign(");
+        assert!(lecture.is_none());
+        assert_eq_trim(&ok_exact_only("floor(pi/(10^400))"), "0");
+        let (exact, lecture) = ok_exact("ceil(pi/(10^400))", 20);
+        assert_contains(&exact, "⌈");
+        assert!(lecture.is_none());
+    }
+
+    #[test]
+    fn signe_grands_operandes() {
+        // 10^40·√2 - a/10^30 ∈ (0, 10^-30) : décidé exactement dans Q(√2)
+        let v = "10^40*sqrt(2) - 14142135623730950488016887242096980785696718753769480731766797379907324/(10^30)";
+        assert_eq_trim(&ok_exact_only(&format!("sign({v})")), "1");
+        assert_eq_trim(&ok_exact_only(&format!("floor({v})")), "0");
+    }
+
+    // --- Racines n-ièmes / exposants rationnels ---
+
+    #[test]
+    fn exposant_rationnel_puissances_parfaites() {
+        assert_eq_trim(&ok_exact_only("8^(1/3)"), "2");
+        assert_eq_trim(&ok_exact_only("16^(-1/4)"), "1/2");
+        assert_eq_trim(&ok_exact_only("4^(3/2)"), "8");
+        assert_eq_trim(&ok_exact_only("2^(-1)"), "1/2");
+        assert_eq_trim(&ok_exact_only("(-8)^(1/3)"), "-2");
+    }
+
+    #[test]
+    fn fraction_apres_exposant_est_une_division() {
+        // x^2/4 = (x^2)/4, jamais x^(2/4)
+        assert_eq_trim(&ok_exact_only("2^3/4"), "2");
+        assert_eq_trim(&ok_exact_only("2^-3/4"), "1/32");
+        assert_eq_trim(&ok_exact_only("x^2/4"), "x^2/4");
+        assert_eq_trim(&ok_exact_only("x^2/4-1"), "(-1+(1/4*x^2))");
+        assert_eq_trim(&ok_exact_only("factor(x^2/4 - 1)"), "1/4·(x-2)·(x+2)");
+        // sortie de integrate relue telle quelle
+        assert_eq_trim(&ok_exact_only("x^3/3"), "x^3/3");
+        assert_eq_trim(&ok_exact_only("8^(2/3)"), "4");
+    }
+
+    #[test]
+    fn zero_puissance_negative_indefini() {
+        for e in ["0^(-1)", "0^(-1/2)", "0^(-2/3)", "(sqrt(2)-sqrt(2))^(-1)"] {
+            let (exact, lecture) = ok_exact(e, 20);
+            assert_eq_trim(&exact, "indéfini");
+            assert!(lecture.is_none(), "expr={e:?}");
+        }
+    }
+
+    #[test]
+    fn racine_cubique_extraction() {
+        assert_eq_trim(&ok_exact_only("cbrt(54)"), "3∛2");
+        assert_eq_trim(&ok_exact_only("∛(-54)"), "-3∛2");
+        assert_eq_trim(&ok_exact_only("2^(2/3)"), "∛4");
+        assert_eq_trim(&ok_exact_only("2^(4/3)"), "2∛2");
+        assert_eq_trim(&ok_exact_only("(1/2)^(1/3)"), "∛4/2");
+        assert_eq_trim(&ok_exact_only("sqrt(1/2)"), "√2/2");
+        assert_eq_trim(&ok_exact_only("32^(1/5)*3^(1/5)"), "2⁵√3");
+    }
+
+    #[test]
+    fn racines_forme_unique() {
+        // indices différents fusionnés sous leur ppcm, racines emboîtées aplaties
+        assert_eq_trim(&ok_exact_only("sqrt(sqrt(2)) - 2^(1/4)"), "0");
+        assert_eq_trim(&ok_exact_only("2^(1/3)*2^(1/6)"), "√2");
+        assert_eq_trim(&ok_exact_only("sqrt(2)/cbrt(2)"), "⁶√2");
+        assert_eq_trim(&ok_exact_only("cbrt(sqrt(2))"), "⁶√2");
+        assert_eq_trim(&ok_exact_only("sqrt(sqrt(8))"), "∜8");
+        assert_eq_trim(&ok_exact_only("root4(4)"), "√2");
+        assert_eq_trim(&ok_exact_only("sqrt(sqrt(sqrt(2)))*2^(7/8)"), "2");
+        assert_eq_trim(&ok_exact_only("sqrt(sqrt(x))"), "∜(x)");
+        // exposant rationnel négatif : une seule racine, rationalisée
+        assert_eq_trim(&ok_exact_only("(2/3)^(-2/3)"), "∛18/2");
+        assert_eq_trim(&ok_exact_only("(1/2)^(2/3)"), "∛2/2");
+    }
+
+    #[test]
+    fn puissance_de_racine_bornee() {
+        // r^(n div k) trop grand : puissance symbolique, ΣLocal refusée (pas de blocage)
+        for e in [
+            "sqrt(2)^100000001",
+            "sqrt(2)^100000002",
+            "cbrt(3)^(-100000001)",
+        ] {
+            let err = eval_expression(e, 20).unwrap_err();
+            assert_contains(&err, "trop grand");
+        }
+        assert_eq_trim(&ok_exact_only("x*sqrt(2)^100000001"), "(x*√2^100000001)");
+        assert_eq_trim(&ok_exact_only("sqrt(2)^100000001/sqrt(2)^100000000"), "√2");
+        assert_eq_trim(&ok_exact_only("sqrt(2)^101"), "1125899906842624√2");
+    }
+
+    #[test]
+    fn racine_cubique_produits() {
+        assert_eq_trim(&ok_exact_only("cbrt(2)*cbrt(4)"), "2");
+        assert_eq_trim(&ok_exact_only("(cbrt(2))^3"), "2");
+        assert_eq_trim(&ok_exact_only("(cbrt(3))^5"), "3∛9");
+    }
+
+    #[test]
+    fn racine_cubique_lecture() {
+        assert_contains(&ok_dec("cbrt(2)", 19), "1.2599210498948731647");
+        assert_contains(&ok_dec("cbrt(-2)", 10), "-1.2599210498");
+        assert_contains(&ok_dec("2^(1/5)", 12), "1.148698354997");
+        // argument non rationnel : lu avec chiffres de garde, puis tronqué
+        for e in ["root4(pi/1000)", "cbrt(pi)", "sqrt(1+sqrt(2)+sqrt(3))"] {
+            let court = ok_dec(e, 30);
+            assert!(ok_dec(e, 60).starts_with(&court), "expr={e:?} : {court}");
+        }
+        assert_contains(
+            &ok_dec("root4(pi/1000)", 30),
+            "0.236748626530291167797324622477",
+        );
+    }
+
+    #[test]
+    fn indice_de_racine_borne() {
+        assert_eq_trim(&ok_exact_only("2^(1/100)"), "¹⁰⁰√2");
+        let debut = std::time::Instant::now();
+        assert!(eval_expression("2^(1/1000)", 20).is_err());
+        assert!(eval_expression("2^(1/10000)", 20).is_err());
+        assert!(debut.elapsed() < std::time::Duration::from_secs(2));
+    }
+
+    // --- Exposants symboliques ---
+
+    #[test]
+    fn puissance_symbolique_parse_et_affiche() {
+        let (exact, lecture) = ok_exact("2^x", 20);
+        assert_eq_trim(&exact, "2^x");
+        assert!(lecture.is_none());
+
+        let (exact, lecture) = ok_exact("2^sqrt(2)", 20);
+        assert_eq_trim(&exact, "2^√2");
+        assert!(lecture.is_none(), "exposant symbolique => ΣLocal bloquée");
+    }
+
+    #[test]
+    fn puissance_symbolique_regles() {
+        // x^a · x^b = x^(a+b) pour une base > 0
+        assert_eq_trim(&ok_exact_only("2^x * 2^y"), "2^(x+y)");
+        assert_eq_trim(&ok_exact_only("3^x * 3"), "3^(1+x)");
+        // (x^a)^b = x^(ab) pour une base > 0
+        assert_eq_trim(&ok_exact_only("(2^x)^y"), "2^(x*y)");
+        // base de signe inconnu : pas de fusion
+        assert_contains(&ok_exact_only("a^x * a^y"), "a^x");
+        // exposant rationnel après simplification => chemin rapide
+        assert_eq_trim(&ok_exact_only("4^(x - x + 1/2)"), "2");
+        assert_eq_trim(&ok_exact_only("1^x"), "1");
+    }
+
+    #[test]
+    fn puissance_pi_lecture() {
+        let (exact, lecture) = ok_exact("pi^2", 20);
+        assert_eq_trim(&exact, "π^2");
+        assert_contains(&lecture.unwrap(), "9.86960440108935861883");
+    }
+
+    // --- Combinatoire ---
+
+    #[test]
+    fn factorielles_exactes() {
+        assert_eq_trim(&ok_exact_only("5!"), "120");
+        assert_eq_trim(&ok_exact_only("0!"), "1");
+        assert_eq_trim(&ok_exact_only("7!!"), "105");
+        assert_eq_trim(&ok_exact_only("8!!"), "384");
+        assert_eq_trim(&ok_exact_only("2^3!"), "64");
+        assert_eq_trim(&ok_exact_only("-3!"), "-6");
+        assert_eq_trim(&ok_exact_only("(-2)!"), "indéfini");
+        assert_contains(&ok_exact_only("30!"), "265252859812191058636308480000000");
+    }
+
+    #[test]
+    fn binomiaux_et_arrangements() {
+        assert_eq_trim(&ok_exact_only("C(5,2)"), "10");
+        assert_eq_trim(&ok_exact_only("binom(10, 3)"), "120");
+        assert_eq_trim(&ok_exact_only("P(5,2)"), "20");
+        assert_eq_trim(&ok_exact_only("C(3,5)"), "0");
+        assert_eq_trim(&ok_exact_only("C(1/2,2)"), "-1/8");
+        assert_eq_trim(&ok_exact_only("C(1000000,999999)"), "1000000");
+        assert_eq_trim(&ok_exact_only("C(n,1)"), "n");
+        // "c" seul reste une variable
+        assert_eq_trim(&ok_exact_only("c+1"), "(1+c)");
+    }
+
+    #[test]
+    fn factorielle_symbolique_et_rapports() {
+        assert_eq_trim(&ok_exact_only("n!/(n-1)!"), "n");
+        assert_eq_trim(&ok_exact_only("(n-1)!/n!"), "1/n");
+        assert_contains(&ok_exact_only("(n+1)!/(n-1)!"), "n");
+        assert_eq_trim(&ok_exact_only("(1/2)!"), "(1/2)!");
+        let (exact, lecture) = ok_exact("100000!", 10);
+        assert_eq_trim(&exact, "100000!");
+        assert!(lecture.is_none());
+    }
+
+    // --- Arithmétique entière ---
+
+    #[test]
+    fn pgcd_ppcm_mod() {
+        assert_eq_trim(&ok_exact_only("gcd(84,36)"), "12");
+        assert_eq_trim(&ok_exact_only("lcm(4, 6)"), "12");
+        assert_eq_trim(&ok_exact_only("pgcd(1/2, 3/4)"), "1/4");
+        assert_eq_trim(&ok_exact_only("mod(17,5)"), "2");
+        assert_eq_trim(&ok_exact_only("mod(-17,5)"), "3");
+        assert_eq_trim(&ok_exact_only("mod(7,0)"), "indéfini");
+        assert_eq_trim(&ok_exact_only("mod(pi,1)"), "(-3+π)");
+        assert_contains(&ok_exact_only("gcd(x,6)"), "gcd(x, 6)");
+    }
+
+    #[test]
+    fn commandes_factor_isprime() {
+        assert_eq_trim(&ok_exact_only("factor(360)"), "2^3·3^2·5");
+        assert_eq_trim(&ok_exact_only("factor(10!)"), "2^8·3^4·5^2·7");
+        assert_eq_trim(&ok_exact_only("factor(-1)"), "-1");
+        assert_eq_trim(&ok_exact_only("isprime(97)"), "vrai");
+        assert_eq_trim(&ok_exact_only("isprime(91)"), "faux");
+        let (_, lecture, d) = eval_expression("isprime(91)", 10).unwrap();
+        assert!(lecture.is_none());
+        assert_contains(&d.preuve, "91 = 7·13");
+        assert!(eval_expression("factor(1/2)", 10).is_err());
+        assert!(eval_expression("factor(0)", 10).is_err());
+    }
+
+    #[test]
+    fn racine_grand_premier_sans_boucle() {
+        // extraction par factorisation (pas de division d’essai jusqu’à √n)
+        assert_eq_trim(&ok_exact_only("sqrt(4000000028000000049)"), "2000000007");
+        assert_contains(&ok_exact_only("sqrt(8000000056000000098)"), "2000000007√2");
+    }
+
+    #[test]
+    fn moins_unaire_apres_operateur() {
+        assert_eq_trim(&ok_exact_only("2*-3"), "-6");
+        assert_eq_trim(&ok_exact_only("2^-1"), "1/2");
+        assert_eq_trim(&ok_exact_only("2--3"), "5");
+        assert_eq_trim(&ok_exact_only("2*-3^2"), "-18");
+        assert_eq_trim(&ok_exact_only("-2^2"), "-4");
+    }
+
+    // --- Mode modulaire ---
+
+    #[test]
+    fn modulaire_inverse_et_puissance() {
+        assert_eq_trim(&ok_exact_only("mod 7: 3^-1"), "5 (mod 7)");
+        assert_eq_trim(&ok_exact_only("mod 19: 5^117"), "1 (mod 19)");
+        assert_eq_trim(&ok_exact_only("mod 7: 1/3 + 2"), "0 (mod 7)");
+        assert_eq_trim(&ok_exact_only("mod 101: 100!"), "100 (mod 101)");
+        assert_eq_trim(&ok_exact_only("mod 1000: 2^(10^30)"), "376 (mod 1000)");
+        let err = eval_expression("mod 8: 1/2", 10).unwrap_err();
+        assert_contains(&err, "pas inversible");
+        // la fonction mod(a, m) reste disponible
+        assert_eq_trim(&ok_exact_only("mod(17,5)"), "2");
+    }
+
+    #[test]
+    fn modulaire_congruences() {
+        assert_eq_trim(&ok_exact_only("mod 7: 3*x = 4"), "x ≡ 6 (mod 7)");
+        assert_eq_trim(
+            &ok_exact_only("mod 10: 4*x + 1 = 3"),
+            "x ≡ 3 (mod 5), soit x ∈ {3, 8} (mod 10)",
+        );
+        assert_contains(&ok_exact_only("mod 10: 2*x = 3"), "aucune solution");
+        let (_, _, d) = eval_expression("mod 7: 3^-1", 10).unwrap();
+        assert_contains(&d.preuve, "3⁻¹ ≡ 5 (mod 7)");
+    }
+
+    // --- Fractions continues ---
+
+    #[test]
+    fn fractions_continues_exactes() {
+        assert_eq_trim(&ok_exact_only("cf(sqrt(2))"), "[1; (2)]");
+        assert_eq_trim(&ok_exact_only("cf((1+sqrt(5))/2)"), "[1; (1)]");
+        assert_eq_trim(&ok_exact_only("cf(sqrt(7))"), "[2; (1, 1, 1, 4)]");
+        assert_eq_trim(&ok_exact_only("cf(415/93)"), "[4; 2, 6, 7]");
+        assert_eq_trim(&ok_exact_only("cf(3)"), "[3]");
+    }
+
+    #[test]
+    fn fractions_continues_encadrement() {
+        assert_eq_trim(&ok_exact_only("cf(pi, 5)"), "[3; 7, 15, 1, 292, …]");
+        assert_eq_trim(
+            &ok_exact_only("convergents(pi, 4)"),
+            "3, 22/7, 333/106, 355/113, …",
+        );
+        assert_eq_trim(
+            &ok_exact_only("convergents(sqrt(2), 4)"),
+            "1, 3/2, 7/5, 17/12, …",
+        );
+    }
+
+    #[test]
+    fn meilleure_approximation_rationnelle() {
+        assert_eq_trim(&ok_exact_only("approx(pi, 100)"), "311/99");
+        assert_eq_trim(&ok_exact_only("approx(pi, 1000)"), "355/113");
+        assert_eq_trim(&ok_exact_only("approx(sqrt(2), 10)"), "7/5");
+        assert_eq_trim(&ok_exact_only("approx(1/3, 10)"), "1/3");
+    }
+
+    #[test]
+    fn termes_semblables() {
+        assert_eq_trim(&ok_exact_only("sqrt(2) + 3*sqrt(2)"), "4√2");
+        assert_eq_trim(&ok_exact_only("1 + sqrt(2) - sqrt(2)"), "1");
+        assert_eq_trim(&ok_exact_only("pi + pi/2"), "3π/2");
+        assert_eq_trim(&ok_exact_only("2/x + 3/x"), "5/x");
+        assert_eq_trim(&ok_exact_only("2*x + 3*x"), "(5*x)");
+        assert_eq_trim(&ok_exact_only("x - (y - x)"), "((2*x)-y)");
+        assert_eq_trim(&ok_exact_only("x*y - y*x"), "0");
+    }
+
+    #[test]
+    fn puissances_regroupees() {
+        assert_eq_trim(&ok_exact_only("x*x*x"), "x^3");
+        assert_eq_trim(&ok_exact_only("x^2*x^-5"), "1/x^3");
+        assert_eq_trim(&ok_exact_only("x*y/(y*z)"), "x/z");
+        assert_eq_trim(&ok_exact_only("x/(2*y)"), "x/(2*y)");
+        assert_eq_trim(&ok_exact_only("sqrt(3)*sqrt(3)*sqrt(3)"), "3√3");
+        assert_eq_trim(&ok_exact_only("sqrt(2)*sqrt(6)"), "2√3");
+        assert_eq_trim(&ok_exact_only("cbrt(2)*cbrt(4)"), "2");
+        assert_eq_trim(&ok_exact_only("pi/pi"), "1");
+    }
+
+    #[test]
+    fn bases_variables_annulees_avec_point_exclu() {
+        // x/x : pas d’annulation silencieuse dans canon, le pgcd trace x ≠ 0
+        let (_, _, d) = eval_expression("x/x", 10).unwrap();
+        assert_contains(&d.preuve, "x ≠ 0");
+        let (_, _, d) = eval_expression("(x-1)/(x-1)", 10).unwrap();
+        assert_contains(&d.preuve, "x ≠ 1");
+        let (_, _, d) = eval_expression("x^2*x^-5", 10).unwrap();
+        assert_contains(&d.preuve, "x ≠ 0");
+
+        // √x/√x : non polynomial, laissé tel quel
+        let (exact, lecture) = ok_exact("sqrt(x)/sqrt(x)", 10);
+        assert_ne!(exact.trim(), "1");
+        assert!(lecture.is_none());
+    }
+
+    #[test]
+    fn rationalisation_conjugues() {
+        assert_eq_trim(&ok_exact_only("1/(1+sqrt(2))"), "(-1+√2)");
+        assert_eq_trim(&ok_exact_only("1/(sqrt(2)+sqrt(3))"), "(√3-√2)");
+        assert_eq_trim(&ok_exact_only("(1+sqrt(2))^-2"), "(3-2√2)");
+        assert_eq_trim(
+            &ok_exact_only("2/((1+sqrt(2))*(sqrt(3)-1))"),
+            "(((-1+√2)+√6)-√3)",
+        );
+
+        let (_, _, d) = eval_expression("1/(1+sqrt(2))", 10).unwrap();
+        assert_contains(&d.preuve, "(1+√2)·(1-√2) = -1");
+    }
+
+    #[test]
+    fn corps_quadratique_forme_unique() {
+        assert_eq_trim(&ok_exact_only("(1+sqrt(2))^5"), "(41+29√2)");
+        assert_eq_trim(&ok_exact_only("(sqrt(2)+sqrt(3))^2 - 2*sqrt(6)"), "5");
+        assert_eq_trim(&ok_exact_only("(2+sqrt(3))/(2-sqrt(3))"), "(7+4√3)");
+        assert_eq_trim(&ok_exact_only("sqrt(1/2)+sqrt(2)"), "3√2/2");
+        assert_eq_trim(&ok_exact_only("1/(1+sqrt(2)+sqrt(3))"), "((2+√2)-√6)/4");
+        assert_eq_trim(&ok_exact_only("(1+sqrt(5))/2"), "(1+√5)/2");
+    }
+
+    #[test]
+    fn corps_quadratique_grandes_puissances() {
+        // borne sur la taille des coefficients, pas sur l’exposant
+        assert_eq_trim(
+            &ok_exact_only("(1+sqrt(2))^65"),
+            "(3796553736732654909229441+2684568892382786771291329√2)",
+        );
+        assert_eq_trim(&ok_exact_only("(1+sqrt(2))^300 - (1+sqrt(2))^300"), "0");
+        // ΣLocal : c·√2 lu avec les chiffres de c en plus (pas d’erreur amplifiée)
+        assert_contains(
+            &ok_dec("(1+sqrt(2))^65", 20),
+            "7593107473465309818458882.000000",
+        );
+        assert_contains(
+            &ok_dec("(1+sqrt(2))^(-64)", 30),
+            "0.000000000000000000000000317948",
+        );
+
+        // borne atteinte : puissance symbolique, tracée dans la démarche
+        let (exact, _, d) = eval_expression("x*(1+sqrt(2))^20000", 10).unwrap();
+        assert_eq_trim(&exact, "(x*(1+√2)^20000)");
+        assert_contains(&d.preuve, "(1+√2)^20000 non développé");
+    }
+
+    #[test]
+    fn racines_denouees() {
+        assert_eq_trim(&ok_exact_only("sqrt(3+2*sqrt(2))"), "(1+√2)");
+        assert_eq_trim(&ok_exact_only("sqrt(5-2*sqrt(6))"), "(√3-√2)");
+        assert_eq_trim(&ok_exact_only("sqrt(2+sqrt(2))"), "√(2+√2)");
+        // dénominateur composé : parenthèses (sinon 1/2√(…) se lit √(…)/2)
+        assert_eq_trim(&ok_exact_only("1/(2*sqrt(2+sqrt(2)))"), "1/(2√(2+√2))");
+        assert_eq_trim(&ok_exact_only("x/(3*sqrt(x+1))"), "x/(3√(1+x))");
+        assert_eq_trim(&ok_exact_only("diff(sqrt(x), x)"), "1/(2√(x))");
+
+        let (_, _, d) = eval_expression("sqrt(3+2*sqrt(2))", 10).unwrap();
+        assert_contains(&d.preuve, "√(3+2√2) = (1+√2) car 3² - 2²·2 = 1²");
+    }
+
+    #[test]
+    fn trig_pi_sur_8_et_12() {
+        assert_eq_trim(&ok_exact_only("sin(pi/8)"), "√(2-√2)/2");
+        assert_eq_trim(&ok_exact_only("tan(pi/8)"), "(-1+√2)");
+        assert_eq_trim(&ok_exact_only("sin(pi/12)"), "(√6-√2)/4");
+        assert_eq_trim(&ok_exact_only("cos(7*pi/12)"), "(√2-√6)/4");
+        assert_eq_trim(&ok_exact_only("tan(pi/12)"), "(2-√3)");
+
+        let (_, lecture, _) = eval_expression("cos(pi/8)", 10).unwrap();
+        assert_eq!(lecture.as_deref(), Some("0.9238795325"));
+    }
+
+    #[test]
+    fn zero_prouve() {
+        assert_eq_trim(&ok_exact_only("iszero(sqrt(6) - sqrt(2)*sqrt(3))"), "vrai");
+        assert_eq_trim(
+            &ok_exact_only("iszero(sin(pi/12) - (sqrt(6)-sqrt(2))/4)"),
+            "vrai",
+        );
+        assert_eq_trim(&ok_exact_only("iszero(pi - 355/113)"), "faux");
+        assert_eq_trim(&ok_exact_only("iszero(x)"), "indécidé");
+        // partie entière d’un entier déguisé : décidée exactement
+        assert_eq_trim(&ok_exact_only("floor(sqrt(2)*sqrt(8) - 1)"), "3");
+    }
+
+    #[test]
+    fn polynome_minimal_commande() {
+        assert_eq_trim(&ok_exact_only("minpoly(cos(2*pi/7))"), "8x^3+4x^2-4x-1");
+        assert_eq_trim(&ok_exact_only("minpoly(sqrt(2)+sqrt(3))"), "x^4-10x^2+1");
+        assert_eq_trim(
+            &ok_exact_only("iszero(cos(2*pi/7) + cos(4*pi/7) + cos(6*pi/7) + 1/2)"),
+            "vrai",
+        );
+    }
+
+    #[test]
+    fn polynomes_developpes_et_factorises() {
+        assert_eq_trim(&ok_exact_only("(x+1)^2 - x^2"), "(1+(2*x))");
+        assert_eq_trim(&ok_exact_only("(x+y)*(x-y) - x^2 + y^2"), "0");
+        assert_eq_trim(&ok_exact_only("expand((x+y)^2)"), "x^2+2x*y+y^2");
+        assert_eq_trim(&ok_exact_only("factor(x^3 - x)"), "x·(x-1)·(x+1)");
+        assert_eq_trim(&ok_exact_only("factor(x^2 - 2*x*y + y^2)"), "(x-y)^2");
+        assert_eq_trim(&ok_exact_only("factor(360)"), "2^3·3^2·5");
+    }
+
+    #[test]
+    fn borne_de_degre_nommee() {
+        // la borne est dite telle quelle, pas confondue avec une expression non polynomiale
+        for (e, nom) in [
+            ("expand((x+1)^100)", "expand"),
+            ("solve(x^100-1)", "solve"),
+            ("factor((x+1)^100)", "factor"),
+            ("apart(1/(x+1)^100)", "apart"),
+        ] {
+            let err = eval_expression(e, 10).unwrap_err();
+            assert_contains(
+                &err,
+                &format!("{nom} : degré trop grand : exposant au plus 64"),
+            );
+        }
+        let err = eval_expression("expand(sqrt(x))", 10).unwrap_err();
+        assert_contains(&err, "expression polynomiale attendue");
+        let err = eval_expression("solve(sin(x)=1)", 10).unwrap_err();
+        assert_contains(
+            &err,
+            "équation rationnelle à coefficients rationnels attendue",
+        );
+    }
+
+    #[test]
+    fn elements_simples_et_multiplication_implicite() {
+        assert_eq_trim(&ok_exact_only("2x + 3x"), "(5*x)");
+        assert_eq_trim(&ok_exact_only("2pi"), "2π");
+        assert_eq_trim(
+            &ok_exact_only("apart((3x+5)/((x+1)(x+2)))"),
+            "2/(x+1)+1/(x+2)",
+        );
+        assert_eq_trim(&ok_exact_only("apart(1/(x^3+x))"), "1/x-x/(x^2+1)");
+        assert_eq_trim(
+            &ok_exact_only("apart((x^2+1)/(x-1)^2)"),
+            "1+2/(x-1)+2/(x-1)^2",
+        );
+
+        // jamais de produit entre un identifiant et '(' : nom inconnu => erreur
+        for e in ["diff(x^2)+1", "factor(360)*2", "sqr(4)", "sinus(pi)"] {
+            let err = eval_expression(e, 10).unwrap_err();
+            assert_contains(&err, "fonction inconnue");
+        }
+    }
+
+    #[test]
+    fn equations_et_solve() {
+        assert_eq_trim(&ok_exact_only("2x + 3 = 7"), "x ∈ {2}");
+        assert_eq_trim(&ok_exact_only("solve(x^2 - 2 = 0)"), "x ∈ {-√2, √2}");
+        assert_eq_trim(&ok_exact_only("solve(t^2 + 4 = 0, t)"), "t ∈ ∅");
+        assert_eq_trim(&ok_exact_only("csolve(t^2 + 4 = 0, t)"), "t ∈ {-2i, 2i}");
+        assert_eq_trim(&ok_exact_only("solve(x^2 - 4x + 4)"), "x ∈ {2}");
+
+        let (_, lecture, d) = eval_expression("x^2 = x + 1", 20).unwrap();
+        assert!(lecture.is_none());
+        assert_contains(&d.preuve, "Δ = b² - 4ac");
+
+        assert_eq_trim(&ok_exact_only("solve(x^3 - 2)"), "x ∈ {∛2}");
+
+        assert!(eval_expression("(x = 1) + 2", 20).is_err());
+        assert!(eval_expression("x = y", 20).is_err());
+    }
+
+    #[test]
+    fn racines_reelles_certifiees() {
+        let (exact, lecture, d) = eval_expression("realroots(x^3 - 3x + 1)", 10).unwrap();
+        assert_eq_trim(
+            &exact,
+            "x ∈ {x₁, x₂, x₃} avec x₁ ∈ ]-1.879386, -1.879385[, x₂ ∈ ]0.347296, 0.347297[, x₃ ∈ ]1.532088, 1.532089[",
+        );
+        assert_eq_trim(
+            &lecture.unwrap(),
+            "x₁ ≈ -1.8793852415, x₂ ≈ 0.3472963553, x₃ ≈ 1.5320888862",
+        );
+        assert_contains(&d.preuve, "V(-B) - V(B) = 3 - 0 = 3");
+
+        // racines multiples (partie sans carré) et racine rationnelle exacte
+        let (exact, _, d) = eval_expression("realroots((x - 1)^2 (x^2 - 2))", 10).unwrap();
+        assert_eq_trim(
+            &exact,
+            "x ∈ {x₁, 1, x₂} avec x₁ ∈ ]-1.414214, -1.414213[, x₂ ∈ ]1.414213, 1.414214[",
+        );
+        assert_contains(&d.preuve, "partie sans carré");
+
+        // solve : même lecture certifiée pour les racines isolées
+        assert_eq_trim(&ok_dec("solve(x^5 - x - 1)", 15), "x₁ ≈ 1.167303978261418");
+        // racine ≈ -10^-30, sous la précision affichée : signe conservé
+        assert_contains(
+            &ok_dec("solve(x^5 - 10^30*x - 1 = 0)", 20),
+            "x₂ ≈ -0.00000000000000000000",
+        );
+        assert!(eval_expression("realroots(1/x)", 10).is_err());
+    }
+
+    #[test]
+    fn systeme_lineaire() {
+        assert_eq_trim(
+            &ok_exact_only(" {2x + y = 3; x - y = 0} "),
+            "(x, y) = (1, 1)",
+        );
+        let (_, lecture, d) = eval_expression("{x + 2y = 1; 2x + 4y = 2}", 20).unwrap();
+        assert!(lecture.is_none());
+        assert_contains(&d.preuve, "y libre");
+        assert!(eval_expression("{x^2 + y = 1; x = 1}", 20).is_err());
+    }
+
+    #[test]
+    fn valeurs_et_vecteurs_propres() {
+        assert_eq_trim(&ok_exact_only("charpoly([[1, 2], [3, 4]])"), "λ^2-5λ-2");
+        assert_eq_trim(
+            &ok_exact_only("eigenvals([[1, 2], [3, 4]])"),
+            "λ ∈ {5/2-√33/2, 5/2+√33/2}",
+        );
+        assert_eq_trim(
+            &ok_exact_only("eigenvals([[0, -1], [1, 0]])"),
+            "λ ∈ {-i, i}",
+        );
+        assert_eq_trim(
+            &ok_exact_only("eigenvals([[2, 0, 0], [0, 2, 0], [0, 0, 3]])"),
+            "λ ∈ {2, 3} ; 2 double",
+        );
+
+        // 3×3 : racine rationnelle puis discriminant, vecteurs propres avec radicaux
+        let (exact, _, d) =
+            eval_expression("eigenvects([[2, 1, 0], [1, 2, 1], [0, 1, 2]])", 10).unwrap();
+        assert_eq_trim(
+            &exact,
+            "λ = 2-√2 : (1, -√2, 1)\nλ = 2 : (-1, 0, 1)\nλ = 2+√2 : (1, √2, 1)",
+        );
+        assert_contains(&d.preuve, "χ_A(λ) = det(λI - A) = λ^3-6λ^2+10λ-4");
+
+        // casus irreducibilis : encadrements affinés aux chiffres de ΣLocal
+        let (exact, _, d) =
+            eval_expression("eigenvects([[0, 1, 0], [0, 0, 1], [-1, 3, 0]])", 12).unwrap();
+        assert_contains(
+            &exact,
+            "λ ∈ ]-1.879385241572, -1.879385241571[ : vecteur propre non calculé",
+        );
+        assert_contains(&exact, "λ ∈ ]1.532088886237, 1.532088886238[");
+        assert_contains(
+            &d.preuve,
+            "λ ∈ ]0.347296355333, 0.347296355334[ : valeur propre",
+        );
+
+        assert!(eval_expression("eigenvals([[x, 1], [1, x]])", 10).is_err());
+        assert!(eval_expression("charpoly([[1, 2, 3], [4, 5, 6]])", 10).is_err());
+    }
+
+    #[test]
+    fn derivees_symboliques() {
+        assert_eq_trim(&ok_exact_only("diff(x^3)"), "(3*x^2)");
+        assert_eq_trim(&ok_exact_only("diff(x*sin(x))"), "(sin(x)+(x*cos(x)))");
+        assert_eq_trim(&ok_exact_only("diff((x+1)/(x-1))"), "-2/(-1+x)^2");
+        assert_eq_trim(&ok_exact_only("diff(sqrt(x^2+1))"), "x/√(1+x^2)");
+        assert_eq_trim(&ok_exact_only("diff(x*y, y)"), "x");
+        // (ᵏ√x)^n dérivé comme x^(n/k) : une seule puissance de la racine, sans ∛x/(∛x)^2
+        assert_eq_trim(&ok_exact_only("diff(x^(2/3), x)"), "2/(3∛(x))");
+        assert_eq_trim(&ok_exact_only("diff(x^(3/2), x)"), "3√(x)/2");
+        let (_, _, d) = eval_expression("diff(x^(2/3), x)", 10).unwrap();
+        assert_contains(&d.preuve, "puissance : d/dx((∛(x))^2) = (2/3)·∛(x)^(-1)");
+
+        // chaque règle appliquée est tracée
+        let (exact, _, d) = eval_expression("diff(sin(x)^2)", 10).unwrap();
+        assert_eq_trim(&exact, "((2*cos(x))*sin(x))");
+        assert_contains(
+            &d.preuve,
+            "puissance : d/dx((sin(x))^2) = 2·sin(x)·d/dx(sin(x))",
+        );
+        assert_contains(&d.preuve, "composée : d/dx(sin(x)) = cos(x)");
+        let (_, _, d) = eval_expression("diff(sin(x)/x)", 10).unwrap();
+        assert_contains(&d.preuve, "quotient : ");
+
+        assert!(eval_expression("diff(2^x)", 10).is_err());
+        // la variable commence par une lettre (comme un identifiant)
+        assert!(eval_expression("diff(x, 2)", 10).is_err());
+        assert!(eval_expression("integrate(x^2, 2)", 10).is_err());
+        assert!(eval_expression("solve(x + 1 = 0, 1)", 10).is_err());
+        assert!(eval_expression("diff(x*y)", 10).is_err());
+        assert!(eval_expression("diff(x!)", 10).is_err());
+    }
+
+    #[test]
+    fn integrales_exactes() {
+        // ∫₀^π sin x dx = 2 : F(π) - F(0) par trig spéciale
+        let (exact, lecture, d) = eval_expression("integrate(sin(x), x, 0, pi)", 10).unwrap();
+        assert_eq_trim(&exact, "2");
+        assert_eq_trim(&lecture.unwrap(), "2.0000000000");
+        assert_contains(&d.preuve, "cos(π) = -1");
+        assert_eq_trim(&ok_exact_only("integrate(sin(x)^2, x, 0, pi)"), "π/2");
+        assert_eq_trim(&ok_exact_only("integrate(sin(pi*x), x, 0, 1)"), "2/π");
+        assert_eq_trim(&ok_exact_only("integrate(3x^2 - 2x + 1, x, 0, 1)"), "1");
+
+        // primitives : argument linéaire, substitution
+        assert_eq_trim(&ok_exact_only("integrate(x^2)"), "x^3/3 + C");
+        assert_eq_trim(&ok_exact_only("integrate((2x+1)^5)"), "(1+(2*x))^6/12 + C");
+        assert_eq_trim(&ok_exact_only("integrate(x/sqrt(x^2+1))"), "√(1+x^2) + C");
+        let (exact, _, d) = eval_expression("integrate(x*sin(x^2))", 10).unwrap();
+        assert_eq_trim(&exact, "(-1/2*cos(x^2)) + C");
+        assert_contains(&d.preuve, "substitution : t = x^2, dt = (2*x)·dx");
+
+        // pas de logarithme : erreur explicite, jamais de primitive devinée
+        let err = eval_expression("integrate(1/(2x+1))", 10).unwrap_err();
+        assert!(err.contains("logarithme"), "{err}");
+        assert!(eval_expression("integrate(1/(x^2+1))", 10).is_err());
+    }
+
     #[test]
     fn espaces_et_majuscules() {
         let exact = ok_exact_only("  SIN ( PI / 4 ) ");
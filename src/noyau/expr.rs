@@ -5,17 +5,38 @@
 // - Pi  : symbole π
 // - Indefini : résultat exact indéfini (ex: tan(π/2))
 // - Var : variable symbolique (ex: x)
+// - Root : racine n-ième (n ≥ 3) ; √ reste Sqrt (forme historique)
+// - Pow : puissance à exposant symbolique (2^x, π^(1+x)) ; PowInt reste le chemin rapide
+// - Abs/Sign/Floor/Ceil/Round/Frac : réduits seulement si le signe / la partie
+//   entière est certifié (voir signe.rs), sinon symboliques
+// - Fact/FactDouble/Binom/Arrang : n!, n!!, C(n,k), P(n,k) ; valeurs exactes
+//   seulement pour des arguments entiers sous la garde de taille (combinatoire.rs)
+// - Pgcd/Ppcm/Modulo : exacts sur les rationnels (arith.rs) ; mod(x, m) symbolique
+//   réduit si ⌊x/m⌋ est certifié
+// - Egal : équation lhs = rhs (racine de l’arbre uniquement, voir rpn.rs) ;
+//   simplifiée membre à membre, résolue par solve(…) (equations.rs)
+// - Matrice : coefficients ligne par ligne ; Matriciel : det / inv / transpose / rank /
+//   rref / trace. Les opérations sont réduites avant simplify (matrices.rs) ; ensuite
+//   chaque passe ne fait que traiter les coefficients un par un
 //
 // IMPORTANT (SAFE):
 // - simplify() ne doit jamais “inventer” une valeur pour Var.
 // - ΣLocal (lecture décimale) sera bloquée dès qu'il y a Var (défense en profondeur).
 
+use crate::noyau::arith::{mod_rationnel_exact, pgcd_rationnel, ppcm_rationnel};
 use crate::noyau::canon::canon_expr;
+use crate::noyau::combinatoire::{
+    arrangement, binomial, binomial_symetrique, double_factorielle, entier_borne, factorielle,
+    rapport_factorielles,
+};
+use crate::noyau::matrices::OpMatrice;
+use crate::noyau::signe::{plancher_certifie, signe_certifie};
 
 use num_bigint::BigInt;
 use num_rational::BigRational;
 use num_traits::{One, Signed, Zero};
 
+use std::cmp::Ordering;
 use std::fmt;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -26,17 +47,50 @@ pub enum Expr {
 
     Var(String),
 
-    Sqrt(Box<Expr>),        // √(x)
-    PowInt(Box<Expr>, i64), // x^n (n entier)
+    Sqrt(Box<Expr>),           // √(x)
+    Root(Box<Expr>, u32),      // ⁿ√(x), n ≥ 3 (n = 2 : Sqrt)
+    PowInt(Box<Expr>, i64),    // x^n (n entier)
+    Pow(Box<Expr>, Box<Expr>), // x^e (e symbolique, non rationnel)
 
     Sin(Box<Expr>),
     Cos(Box<Expr>),
     Tan(Box<Expr>),
 
+    Abs(Box<Expr>),   // |x|
+    Sign(Box<Expr>),  // sign(x) ∈ {-1, 0, 1}
+    Floor(Box<Expr>), // ⌊x⌋
+    Ceil(Box<Expr>),  // ⌈x⌉
+    Round(Box<Expr>), // round(x) = ⌊x + 1/2⌋
+    Frac(Box<Expr>),  // frac(x) = x - ⌊x⌋
+
+    Fact(Box<Expr>),              // n!
+    FactDouble(Box<Expr>),        // n!!
+    Binom(Box<Expr>, Box<Expr>),  // C(n,k)
+    Arrang(Box<Expr>, Box<Expr>), // P(n,k) = n!/(n-k)!
+
+    Pgcd(Box<Expr>, Box<Expr>),   // gcd(a,b)
+    Ppcm(Box<Expr>, Box<Expr>),   // lcm(a,b)
+    Modulo(Box<Expr>, Box<Expr>), // mod(a,m) = a - m·⌊a/m⌋
+
     Add(Box<Expr>, Box<Expr>),
     Sub(Box<Expr>, Box<Expr>),
     Mul(Box<Expr>, Box<Expr>),
     Div(Box<Expr>, Box<Expr>),
+
+    Egal(Box<Expr>, Box<Expr>), // lhs = rhs
+
+    Matrice(Vec<Vec<Expr>>), // [[a, b], [c, d]] (lignes de même longueur)
+    Matriciel(OpMatrice, Box<Expr>), // det(M), inv(M), …
+}
+
+/// Matrice dont chaque coefficient passe par `f` (parcours génériques).
+pub fn map_matrice(lignes: Vec<Vec<Expr>>, mut f: impl FnMut(Expr) -> Expr) -> Expr {
+    Expr::Matrice(
+        lignes
+            .into_iter()
+            .map(|l| l.into_iter().map(&mut f).collect())
+            .collect(),
+    )
 }
 
 impl Expr {
@@ -55,6 +109,13 @@ impl Expr {
             // Feuilles: aucune simplification à faire
             Rat(_) | Pi | Indefini | Var(_) => self,
 
+            // équation : chaque membre séparément (jamais de “valeur” pour =)
+            Egal(a, b) => Egal(Box::new(a.simplify()), Box::new(b.simplify())),
+
+            // matrices : coefficient par coefficient (opérations déjà réduites, matrices.rs)
+            Matrice(l) => map_matrice(l, Expr::simplify),
+            Matriciel(op, x) => Matriciel(op, Box::new(x.simplify())),
+
             Add(a, b) => {
                 let a = a.simplify();
                 let b = b.simplify();
@@ -113,6 +174,28 @@ impl Expr {
                     }
                 }
 
+                // x^a * x^b => x^(a+b) si x > 0 certifié (exposants symboliques)
+                if let (Some((xa, ea)), Some((xb, eb))) = (as_puissance(&a), as_puissance(&b)) {
+                    if xa == xb
+                        && (matches!(a, Pow(_, _)) || matches!(b, Pow(_, _)))
+                        && signe_certifie(&xa) == Some(Ordering::Greater)
+                    {
+                        return Pow(Box::new(xa), Box::new(Add(Box::new(ea), Box::new(eb))))
+                            .simplify();
+                    }
+                }
+
+                // ⁿ√u * ⁿ√v => ⁿ√(u*v) si u,v rationnels (>= 0, ou n impair)
+                if let (Root(u, n), Root(v, m)) = (&a, &b) {
+                    if n == m {
+                        if let (Expr::Rat(ru), Expr::Rat(rv)) = (u.as_ref(), v.as_ref()) {
+                            if n % 2 == 1 || (!ru.is_negative() && !rv.is_negative()) {
+                                return Expr::Root(Box::new(Expr::Rat(ru * rv)), *n).simplify();
+                            }
+                        }
+                    }
+                }
+
                 // (√x / k) * √x => x / k
                 if let (Div(p, q), Sqrt(y)) = (&a, &b) {
                     if let (Sqrt(x), Rat(k)) = (p.as_ref(), q.as_ref()) {
@@ -197,6 +280,13 @@ impl Expr {
                     }
                 }
 
+                // n!/m! => produit fini si n - m entier (n!/(n-1)! = n, (n-1)!/n! = 1/n)
+                if let (Fact(n), Fact(m)) = (&a, &b) {
+                    if let Some(p) = rapport_factorielles(n, m) {
+                        return p.simplify();
+                    }
+                }
+
                 match (&a, &b) {
                     (Rat(x), Rat(y)) => Rat(x / y),
                     (_, Rat(y)) if y.is_one() => a,
@@ -230,11 +320,71 @@ impl Expr {
                     return Rat(BigRational::one());
                 }
                 if let Rat(r) = &base {
+                    // 0^(-n) : division par zéro
+                    if r.is_zero() && n < 0 {
+                        return Indefini;
+                    }
                     return Rat(rational_pow_int(r.clone(), n));
                 }
+                // (ⁿ√x)^(kn) => x^k (√ : x >= 0 exigé ; racine impaire : toujours)
+                let racine = match &base {
+                    Sqrt(x) => Some((x.as_ref(), 2)),
+                    Root(x, k) => Some((x.as_ref(), *k)),
+                    _ => None,
+                };
+                if let Some((x, k)) = racine {
+                    let k = i64::from(k);
+                    if n % k == 0 {
+                        if let Rat(r) = x {
+                            if (k % 2 == 1 || !r.is_negative()) && !(r.is_zero() && n < 0) {
+                                if let Some(p) = rational_pow_borne(r.clone(), n / k) {
+                                    return Rat(p);
+                                }
+                            }
+                        }
+                    }
+                }
+                // (x^a)^n => x^(a·n) (n entier : toujours valide là où x^a est défini)
+                if let Pow(x, a) = base {
+                    let e = Mul(a, Box::new(Rat(BigRational::from_integer(n.into()))));
+                    return Pow(x, Box::new(e)).simplify();
+                }
                 PowInt(Box::new(base), n)
             }
 
+            Pow(base, e) => {
+                let base = base.simplify();
+                let e = e.simplify();
+                if matches!(base, Indefini) || matches!(e, Indefini) {
+                    return Indefini;
+                }
+
+                // exposant rationnel => chemin rapide (PowInt / racines)
+                if let Rat(r) = &e {
+                    if let Ok(p) = puissance_rationnelle(base.clone(), r) {
+                        return p.simplify();
+                    }
+                    return Pow(Box::new(base), Box::new(e));
+                }
+
+                // 1^e => 1
+                if let Rat(r) = &base {
+                    if r.is_one() {
+                        return base;
+                    }
+                }
+
+                // (x^a)^b => x^(a·b) seulement si x > 0 certifié (sinon signe de x^a ambigu)
+                if let (Pow(_, _) | PowInt(_, _), Some((x, a))) = (&base, as_puissance(&base)) {
+                    if signe_certifie(&x) == Some(Ordering::Greater) {
+                        return Pow(Box::new(x), Box::new(Mul(Box::new(a), Box::new(e))))
+                            .simplify();
+                    }
+                }
+
+                Pow(Box::new(base), Box::new(e))
+            }
+
             Sqrt(x) => {
                 let x = x.simplify();
                 if matches!(x, Indefini) {
@@ -248,6 +398,25 @@ impl Expr {
                 Sqrt(Box::new(x))
             }
 
+            Root(x, n) => {
+                let x = x.simplify();
+                if matches!(x, Indefini) {
+                    return Indefini;
+                }
+                match n {
+                    0 => return Indefini,
+                    1 => return x,
+                    2 => return Sqrt(Box::new(x)).simplify(),
+                    _ => {}
+                }
+                if let Rat(r) = &x {
+                    if let Some(s) = rational_root_exact(r, n) {
+                        return Rat(s);
+                    }
+                }
+                Root(Box::new(x), n)
+            }
+
             Sin(x) => {
                 let x = x.simplify();
                 if matches!(x, Indefini) {
@@ -269,9 +438,186 @@ impl Expr {
                 }
                 Tan(Box::new(x))
             }
+
+            Abs(x) => {
+                let x = x.simplify();
+                match &x {
+                    Indefini => Indefini,
+                    Rat(r) => Rat(r.abs()),
+                    Abs(_) => x,
+                    // |0 - y| = |y|
+                    Sub(a, b) if matches!(a.as_ref(), Rat(r) if r.is_zero()) => {
+                        Abs(b.clone()).simplify()
+                    }
+                    _ => match signe_certifie(&x) {
+                        // |a - b| = b - a si a - b < 0 (évite -(a - b))
+                        Some(Ordering::Less) => match x {
+                            Sub(a, b) => Sub(b, a).simplify(),
+                            other => {
+                                Sub(Box::new(Rat(BigRational::zero())), Box::new(other)).simplify()
+                            }
+                        },
+                        Some(_) => x,
+                        None => Abs(Box::new(x)),
+                    },
+                }
+            }
+
+            Sign(x) => {
+                let x = x.simplify();
+                if matches!(x, Indefini) {
+                    return Indefini;
+                }
+                match signe_certifie(&x) {
+                    Some(Ordering::Less) => Rat(-BigRational::one()),
+                    Some(Ordering::Equal) => Rat(BigRational::zero()),
+                    Some(Ordering::Greater) => Rat(BigRational::one()),
+                    None => Sign(Box::new(x)),
+                }
+            }
+
+            Floor(x) => {
+                let x = x.simplify();
+                if matches!(x, Indefini) {
+                    return Indefini;
+                }
+                match plancher_certifie(&x) {
+                    Some(n) => Rat(BigRational::from_integer(n)),
+                    None => Floor(Box::new(x)),
+                }
+            }
+
+            Ceil(x) => {
+                let x = x.simplify();
+                if matches!(x, Indefini) {
+                    return Indefini;
+                }
+                // ⌈x⌉ = -⌊-x⌋
+                let moins_x = Sub(Box::new(Rat(BigRational::zero())), Box::new(x.clone()));
+                match plancher_certifie(&moins_x.simplify()) {
+                    Some(n) => Rat(BigRational::from_integer(-n)),
+                    None => Ceil(Box::new(x)),
+                }
+            }
+
+            Round(x) => {
+                let x = x.simplify();
+                if matches!(x, Indefini) {
+                    return Indefini;
+                }
+                // round(x) = ⌊x + 1/2⌋ (demi-entiers arrondis vers le haut)
+                let demi = Rat(BigRational::new(BigInt::one(), BigInt::from(2)));
+                let decale = Add(Box::new(x.clone()), Box::new(demi)).simplify();
+                match plancher_certifie(&decale) {
+                    Some(n) => Rat(BigRational::from_integer(n)),
+                    None => Round(Box::new(x)),
+                }
+            }
+
+            Frac(x) => {
+                let x = x.simplify();
+                if matches!(x, Indefini) {
+                    return Indefini;
+                }
+                match plancher_certifie(&x) {
+                    Some(n) => {
+                        Sub(Box::new(x), Box::new(Rat(BigRational::from_integer(n)))).simplify()
+                    }
+                    None => Frac(Box::new(x)),
+                }
+            }
+
+            Fact(x) => simplifie_fact(x.simplify()),
+            FactDouble(x) => simplifie_fact_double(x.simplify()),
+            Binom(n, k) => simplifie_binom(n.simplify(), k.simplify()),
+            Arrang(n, k) => simplifie_arrang(n.simplify(), k.simplify()),
+
+            Pgcd(a, b) => match (a.simplify(), b.simplify()) {
+                (Indefini, _) | (_, Indefini) => Indefini,
+                (Rat(x), Rat(y)) => Rat(pgcd_rationnel(&x, &y)),
+                (a, b) => Pgcd(Box::new(a), Box::new(b)),
+            },
+            Ppcm(a, b) => match (a.simplify(), b.simplify()) {
+                (Indefini, _) | (_, Indefini) => Indefini,
+                (Rat(x), Rat(y)) => Rat(ppcm_rationnel(&x, &y)),
+                (a, b) => Ppcm(Box::new(a), Box::new(b)),
+            },
+            Modulo(a, m) => simplifie_modulo(a.simplify(), m.simplify()),
+        }
+    }
+
+    /// Sous-expressions directes (lecture seule, pour les parcours génériques).
+    pub fn enfants(&self) -> Vec<&Expr> {
+        use Expr::*;
+        match self {
+            Rat(_) | Pi | Indefini | Var(_) => vec![],
+
+            Sqrt(x) | Root(x, _) | PowInt(x, _) => vec![x],
+            Sin(x) | Cos(x) | Tan(x) => vec![x],
+            Abs(x) | Sign(x) | Floor(x) | Ceil(x) | Round(x) | Frac(x) => vec![x],
+            Fact(x) | FactDouble(x) => vec![x],
+
+            Pow(a, b) | Binom(a, b) | Arrang(a, b) => vec![a, b],
+            Pgcd(a, b) | Ppcm(a, b) | Modulo(a, b) => vec![a, b],
+            Add(a, b) | Sub(a, b) | Mul(a, b) | Div(a, b) => vec![a, b],
+            Egal(a, b) => vec![a, b],
+            Matrice(l) => l.iter().flatten().collect(),
+            Matriciel(_, x) => vec![x],
         }
     }
 
+    /// Enfants transformés par `f` (même nœud), première erreur propagée.
+    pub fn map_enfants<E>(self, f: &mut impl FnMut(Expr) -> Result<Expr, E>) -> Result<Expr, E> {
+        use Expr::*;
+        let mut g = |x: Box<Expr>| f(*x).map(Box::new);
+        Ok(match self {
+            Rat(_) | Pi | Indefini | Var(_) => self,
+
+            Sqrt(x) => Sqrt(g(x)?),
+            Root(x, k) => Root(g(x)?, k),
+            PowInt(x, n) => PowInt(g(x)?, n),
+            Pow(a, b) => Pow(g(a)?, g(b)?),
+            Sin(x) => Sin(g(x)?),
+            Cos(x) => Cos(g(x)?),
+            Tan(x) => Tan(g(x)?),
+            Abs(x) => Abs(g(x)?),
+            Sign(x) => Sign(g(x)?),
+            Floor(x) => Floor(g(x)?),
+            Ceil(x) => Ceil(g(x)?),
+            Round(x) => Round(g(x)?),
+            Frac(x) => Frac(g(x)?),
+            Fact(x) => Fact(g(x)?),
+            FactDouble(x) => FactDouble(g(x)?),
+            Binom(a, b) => Binom(g(a)?, g(b)?),
+            Arrang(a, b) => Arrang(g(a)?, g(b)?),
+            Pgcd(a, b) => Pgcd(g(a)?, g(b)?),
+            Ppcm(a, b) => Ppcm(g(a)?, g(b)?),
+            Modulo(a, b) => Modulo(g(a)?, g(b)?),
+            Add(a, b) => Add(g(a)?, g(b)?),
+            Sub(a, b) => Sub(g(a)?, g(b)?),
+            Mul(a, b) => Mul(g(a)?, g(b)?),
+            Div(a, b) => Div(g(a)?, g(b)?),
+            Egal(a, b) => Egal(g(a)?, g(b)?),
+            Matrice(l) => Matrice(
+                l.into_iter()
+                    .map(|ligne| ligne.into_iter().map(&mut *f).collect())
+                    .collect::<Result<_, E>>()?,
+            ),
+            Matriciel(op, x) => Matriciel(op, g(x)?),
+        })
+    }
+
+    /// Vrai si un nœud `=` apparaît (à la racine ou plus bas).
+    pub fn contient_egal(&self) -> bool {
+        matches!(self, Expr::Egal(_, _)) || self.enfants().into_iter().any(Expr::contient_egal)
+    }
+
+    /// Vrai si une matrice ou une opération matricielle apparaît.
+    pub fn contient_matrice(&self) -> bool {
+        matches!(self, Expr::Matrice(_) | Expr::Matriciel(_, _))
+            || self.enfants().into_iter().any(Expr::contient_matrice)
+    }
+
     /// Détecte un coeff·π (forme simple historique).
     ///
     /// SAFE: Var => None (on ne “devine” rien).
@@ -326,7 +672,11 @@ impl Expr {
             Add(_, _) => None,
 
             // IMPORTANT: Var(_) NE DOIT PAS ÊTRE RÉPÉTÉ ICI (sinon unreachable)
-            Sqrt(_) | PowInt(_, _) | Sin(_) | Cos(_) | Tan(_) => None,
+            Sqrt(_) | Root(_, _) | PowInt(_, _) | Pow(_, _) | Sin(_) | Cos(_) | Tan(_) => None,
+            Abs(_) | Sign(_) | Floor(_) | Ceil(_) | Round(_) | Frac(_) => None,
+            Fact(_) | FactDouble(_) | Binom(_, _) | Arrang(_, _) => None,
+            Pgcd(_, _) | Ppcm(_, _) | Modulo(_, _) => None,
+            Egal(_, _) | Matrice(_) | Matriciel(_, _) => None,
         }
     }
 
@@ -377,7 +727,13 @@ impl Expr {
                     Rat(_) | Indefini | Var(_) => res.push(None),
 
                     // On refuse de “pousser” coeff·π à travers trig/racines/etc.
-                    Sqrt(_) | PowInt(_, _) | Sin(_) | Cos(_) | Tan(_) => res.push(None),
+                    Sqrt(_) | Root(_, _) | PowInt(_, _) | Pow(_, _) | Sin(_) | Cos(_) | Tan(_) => {
+                        res.push(None)
+                    }
+                    Abs(_) | Sign(_) | Floor(_) | Ceil(_) | Round(_) | Frac(_) => res.push(None),
+                    Fact(_) | FactDouble(_) | Binom(_, _) | Arrang(_, _) => res.push(None),
+                    Pgcd(_, _) | Ppcm(_, _) | Modulo(_, _) => res.push(None),
+                    Egal(_, _) | Matrice(_) | Matriciel(_, _) => res.push(None),
 
                     Add(_, _) => {
                         let rb = res.pop().unwrap_or(None);
@@ -479,6 +835,160 @@ impl Expr {
     }
 }
 
+/* ------------------------ Puissances (rationnelles / symboliques) ------------------------ */
+
+/// Indice de racine maximal (ᵠ√ : coût des racines entières et des lectures en q).
+pub(crate) const RACINE_INDICE_MAX: u32 = 100;
+
+/// a^(p/q) : PowInt si q = 1, sinon (ᵠ√a)^p (√ pour q = 2).
+/// q > RACINE_INDICE_MAX : Err (simplify garde alors la puissance symbolique).
+pub(crate) fn puissance_rationnelle(a: Expr, r: &BigRational) -> Result<Expr, String> {
+    let p = big_to_i64(r.numer()).ok_or("exposant trop grand")?;
+    if r.denom().is_one() {
+        return Ok(Expr::PowInt(Box::new(a), p));
+    }
+
+    let q = big_to_i64(r.denom())
+        .and_then(|q| u32::try_from(q).ok())
+        .filter(|q| *q <= RACINE_INDICE_MAX)
+        .ok_or(format!(
+            "indice de racine trop grand (au plus {RACINE_INDICE_MAX})"
+        ))?;
+    let racine = if q == 2 {
+        Expr::Sqrt(Box::new(a))
+    } else {
+        Expr::Root(Box::new(a), q)
+    };
+
+    if p == 1 {
+        Ok(racine)
+    } else {
+        Ok(Expr::PowInt(Box::new(racine), p))
+    }
+}
+
+/// Vue “base^exposant” : Pow(x,e) -> (x,e), PowInt(x,n) -> (x,n), sinon (x,1).
+/// Sert aux règles x^a·x^b et (x^a)^b.
+fn as_puissance(e: &Expr) -> Option<(Expr, Expr)> {
+    match e {
+        Expr::Pow(x, a) => Some((*x.clone(), *a.clone())),
+        Expr::PowInt(x, n) => Some((
+            *x.clone(),
+            Expr::Rat(BigRational::from_integer((*n).into())),
+        )),
+        Expr::Indefini => None,
+        other => Some((other.clone(), Expr::Rat(BigRational::one()))),
+    }
+}
+
+/// Conversion SAFE vers i64.
+/// (MVP: exposant doit rentrer dans i64, sinon on refuse)
+fn big_to_i64(x: &BigInt) -> Option<i64> {
+    x.to_string().parse::<i64>().ok()
+}
+
+/* ------------------------ Simplifications déportées ------------------------ */
+//
+// Hors de simplify() pour garder son cadre de pile petit (sommes profondes en debug).
+// Les arguments sont déjà simplifiés.
+
+fn simplifie_fact(x: Expr) -> Expr {
+    use Expr::*;
+    match &x {
+        Indefini => Indefini,
+        // entier < 0 : pôle de Γ
+        Rat(r) if r.is_integer() && r.is_negative() => Indefini,
+        Rat(r) => match entier_borne(r) {
+            Some(n) => Rat(BigRational::from_integer(factorielle(n))),
+            None => Fact(Box::new(x)),
+        },
+        _ => Fact(Box::new(x)),
+    }
+}
+
+fn simplifie_fact_double(x: Expr) -> Expr {
+    use Expr::*;
+    match &x {
+        Indefini => Indefini,
+        Rat(r) if r == &-BigRational::one() => Rat(BigRational::one()),
+        Rat(r) if r.is_integer() && r.is_negative() => Indefini,
+        Rat(r) => match entier_borne(r) {
+            Some(n) => Rat(BigRational::from_integer(double_factorielle(n))),
+            None => FactDouble(Box::new(x)),
+        },
+        _ => FactDouble(Box::new(x)),
+    }
+}
+
+fn simplifie_binom(n: Expr, k: Expr) -> Expr {
+    use Expr::*;
+    if matches!(n, Indefini) || matches!(k, Indefini) {
+        return Indefini;
+    }
+    let Rat(rk) = &k else {
+        return Binom(Box::new(n), Box::new(k));
+    };
+    if !rk.is_integer() {
+        return Binom(Box::new(n), Box::new(k));
+    }
+    if rk.is_negative() {
+        return Rat(BigRational::zero());
+    }
+    if let Rat(rn) = &n {
+        if let Some(v) = binomial_symetrique(rn, rk) {
+            return Rat(v);
+        }
+    }
+    match (entier_borne(rk), &n) {
+        (Some(0), _) => Rat(BigRational::one()),
+        (Some(1), _) => n,
+        (Some(kk), Rat(rn)) => Rat(binomial(rn, kk)),
+        _ => Binom(Box::new(n), Box::new(k)),
+    }
+}
+
+fn simplifie_arrang(n: Expr, k: Expr) -> Expr {
+    use Expr::*;
+    if matches!(n, Indefini) || matches!(k, Indefini) {
+        return Indefini;
+    }
+    match (&n, &k) {
+        (_, Rat(rk)) if rk.is_integer() && rk.is_negative() => Indefini,
+        (_, Rat(rk)) if rk.is_zero() => Rat(BigRational::one()),
+        (_, Rat(rk)) if rk.is_one() => n,
+        (Rat(rn), Rat(rk)) => match entier_borne(rk) {
+            Some(kk) => Rat(arrangement(rn, kk)),
+            None => Arrang(Box::new(n), Box::new(k)),
+        },
+        _ => Arrang(Box::new(n), Box::new(k)),
+    }
+}
+
+fn simplifie_modulo(a: Expr, m: Expr) -> Expr {
+    use Expr::*;
+    match (&a, &m) {
+        (Indefini, _) | (_, Indefini) => Indefini,
+        (Rat(x), Rat(y)) => match mod_rationnel_exact(x, y) {
+            Some(r) => Rat(r),
+            None => Indefini,
+        },
+        (_, Rat(y)) if y.is_zero() => Indefini,
+        // a mod m = a - m·⌊a/m⌋ dès que ⌊a/m⌋ est certifié (mod(π, 1) = π - 3)
+        (_, Rat(y)) => {
+            let quotient = Div(Box::new(a.clone()), Box::new(m.clone())).simplify();
+            match plancher_certifie(&quotient) {
+                Some(q) => Sub(
+                    Box::new(a.clone()),
+                    Box::new(Rat(y * BigRational::from_integer(q))),
+                )
+                .simplify(),
+                None => Modulo(Box::new(a), Box::new(m)),
+            }
+        }
+        _ => Modulo(Box::new(a), Box::new(m)),
+    }
+}
+
 /* ------------------------ Modulo rationnel exact (sans flottants) ------------------------ */
 
 /// Réduction modulo `periode` sur un coefficient rationnel (ex: periode=2 pour sin/cos, 1 pour tan).
@@ -539,21 +1049,48 @@ impl fmt::Display for Expr {
             Indefini => write!(f, "indéfini"),
             Var(s) => write!(f, "{s}"),
             Sqrt(x) => write!(f, "√({x})"),
+            Root(x, n) => write!(f, "root({x},{n})"),
             PowInt(x, n) => write!(f, "({x})^{n}"),
+            Pow(x, e) => write!(f, "({x})^({e})"),
             Sin(x) => write!(f, "sin({x})"),
             Cos(x) => write!(f, "cos({x})"),
             Tan(x) => write!(f, "tan({x})"),
+            Abs(x) => write!(f, "|{x}|"),
+            Sign(x) => write!(f, "sign({x})"),
+            Floor(x) => write!(f, "⌊{x}⌋"),
+            Ceil(x) => write!(f, "⌈{x}⌉"),
+            Round(x) => write!(f, "round({x})"),
+            Frac(x) => write!(f, "frac({x})"),
+            Fact(x) => write!(f, "({x})!"),
+            FactDouble(x) => write!(f, "({x})!!"),
+            Binom(n, k) => write!(f, "C({n},{k})"),
+            Arrang(n, k) => write!(f, "P({n},{k})"),
+            Pgcd(a, b) => write!(f, "gcd({a},{b})"),
+            Ppcm(a, b) => write!(f, "lcm({a},{b})"),
+            Modulo(a, m) => write!(f, "mod({a},{m})"),
             Add(a, b) => write!(f, "({a}+{b})"),
             Sub(a, b) => write!(f, "({a}-{b})"),
             Mul(a, b) => write!(f, "({a}*{b})"),
             Div(a, b) => write!(f, "({a}/{b})"),
+            Egal(a, b) => write!(f, "{a} = {b}"),
+            Matrice(l) => {
+                let lignes: Vec<String> = l
+                    .iter()
+                    .map(|ligne| {
+                        let c: Vec<String> = ligne.iter().map(|x| x.to_string()).collect();
+                        format!("[{}]", c.join(", "))
+                    })
+                    .collect();
+                write!(f, "[{}]", lignes.join(", "))
+            }
+            Matriciel(op, x) => write!(f, "{}({x})", op.nom()),
         }
     }
 }
 
 /* ------------------------ Outils rationnels (utilisés par simplify) ------------------------ */
 
-fn rational_pow_int(base: BigRational, exp: i64) -> BigRational {
+pub(crate) fn rational_pow_int(base: BigRational, exp: i64) -> BigRational {
     if exp == 0 {
         return BigRational::one();
     }
@@ -578,7 +1115,20 @@ fn rational_pow_int(base: BigRational, exp: i64) -> BigRational {
     acc
 }
 
-fn rational_sqrt_exact(r: &BigRational) -> Option<BigRational> {
+/// Taille max (bits) d’une puissance de rationnel sortie d’une racine ((√2)^n, (ᵏ√r)^n) :
+/// au-delà, la puissance reste symbolique.
+pub(crate) const PUISSANCE_BITS_MAX: u64 = 1 << 20;
+
+/// base^exp si sa taille (estimée) tient en PUISSANCE_BITS_MAX bits, sinon None.
+pub(crate) fn rational_pow_borne(base: BigRational, exp: i64) -> Option<BigRational> {
+    let bits = base.numer().bits().max(base.denom().bits());
+    if bits > 1 && bits.saturating_mul(exp.unsigned_abs()) > PUISSANCE_BITS_MAX {
+        return None;
+    }
+    Some(rational_pow_int(base, exp))
+}
+
+pub(crate) fn rational_sqrt_exact(r: &BigRational) -> Option<BigRational> {
     if r.is_negative() {
         return None;
     }
@@ -589,6 +1139,60 @@ fn rational_sqrt_exact(r: &BigRational) -> Option<BigRational> {
     Some(BigRational::new(sn, sd))
 }
 
+/// ⁿ√r exact (r rationnel) : racine impaire d’un négatif => négatif ; paire d’un négatif => None.
+fn rational_root_exact(r: &BigRational, n: u32) -> Option<BigRational> {
+    if r.is_negative() {
+        if n.is_multiple_of(2) {
+            return None;
+        }
+        return rational_root_exact(&-r, n).map(|s| -s);
+    }
+    let sn = int_root_exact(r.numer(), n)?;
+    let sd = int_root_exact(r.denom(), n)?;
+    Some(BigRational::new(sn, sd))
+}
+
+fn int_root_exact(x: &BigInt, n: u32) -> Option<BigInt> {
+    let s = int_root_floor(x, n);
+    if s.pow(n) == *x {
+        Some(s)
+    } else {
+        None
+    }
+}
+
+/// ⌊ⁿ√x⌋ pour x >= 0 (Newton entier, départ au-dessus de la racine).
+/// SAFE: x < 0 ou n = 0 => 0.
+pub(crate) fn int_root_floor(x: &BigInt, n: u32) -> BigInt {
+    if x.is_zero() || x.is_negative() || n == 0 {
+        return BigInt::zero();
+    }
+    if n == 1 {
+        return x.clone();
+    }
+
+    // départ : 2^ceil(bits/n) >= ⁿ√x
+    let mut y = BigInt::one() << x.bits().div_ceil(u64::from(n));
+    let n_big = BigInt::from(n);
+    let n_moins_1 = BigInt::from(n - 1);
+    loop {
+        // y' = ((n-1)·y + x / y^(n-1)) / n
+        let y_next = (&n_moins_1 * &y + x / y.pow(n - 1)) / &n_big;
+        if y_next >= y {
+            break;
+        }
+        y = y_next;
+    }
+    // ajustement final (floor)
+    while (&y + 1u32).pow(n) <= *x {
+        y += 1u32;
+    }
+    while y.pow(n) > *x {
+        y -= 1u32;
+    }
+    y
+}
+
 fn int_sqrt_exact(x: &BigInt) -> Option<BigInt> {
     if x.is_negative() {
         return None;
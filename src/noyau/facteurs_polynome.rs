@@ -0,0 +1,512 @@
+// src/noyau/facteurs_polynome.rs
+//
+// Factorisation des polynômes de Z[x] en irréductibles (Zassenhaus)
+// -----------------------------------------------------------------
+// 1) sans carré (Yun, polynome.rs), partie primitive
+// 2) premier p : p ∤ lc(f), f sans carré mod p ; parmi quelques candidats, celui qui
+//    donne le moins de facteurs modulaires
+// 3) factorisation mod p : degrés distincts, puis Cantor–Zassenhaus (p impair)
+// 4) relèvement de Hensel quadratique (arbre binaire) jusqu’à p^(2^j) > 2·|lc|·B
+//    (B : borne de Mignotte sur les coefficients d’un facteur)
+// 5) recombinaison : sous-ensembles de taille croissante, test de division exacte
+//
+// IMPORTANT (SAFE):
+// - Arithmétique modulaire en BigInt (modules p et p^k avec le même code).
+// - Tirages “aléatoires” de Cantor–Zassenhaus déterministes (suite congruentielle).
+// - Recombinaison bornée : au-delà du budget, Err (on ne rend jamais un facteur
+//   non prouvé irréductible comme irréductible).
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+use super::polynome::Polynome;
+
+/// Premiers candidats pour la réduction modulaire (impairs).
+const PREMIERS: [u32; 24] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Nombre de premiers “valides” essayés avant de choisir.
+const ESSAIS_PREMIERS: usize = 5;
+
+/// Budget de sous-ensembles testés à la recombinaison.
+const BUDGET_RECOMBINAISON: u64 = 200_000;
+
+/// Polynôme modulaire : coefficients dans [0, m), degrés croissants, sans zéro en tête.
+type PolyMod = Vec<BigInt>;
+
+/* ------------------------ API ------------------------ */
+
+/// Factorisation de f ∈ Q[x] : f = contenu · ∏ g_i^e_i, g_i ∈ Z[x] primitifs irréductibles
+/// (coefficient dominant > 0), triés par degré puis coefficients.
+pub fn factorise_polynome(f: &Polynome) -> Result<(BigRational, Vec<(Polynome, u32)>), String> {
+    if f.est_zero() {
+        return Err("factorisation : polynôme nul".into());
+    }
+    let (contenu, _) = f.partie_primitive();
+    let mut facteurs: Vec<(Polynome, u32)> = Vec::new();
+    for (a, e) in f.sans_carre() {
+        for g in factorise_sans_carre(&a.primitif())? {
+            facteurs.push((g, e));
+        }
+    }
+    facteurs.sort_by(|(a, _), (b, _)| {
+        a.degre()
+            .cmp(&b.degre())
+            .then_with(|| a.coeffs.cmp(&b.coeffs))
+    });
+    Ok((contenu, facteurs))
+}
+
+/// Facteurs irréductibles de f ∈ Z[x] primitif, sans carré, dominant > 0.
+pub fn factorise_sans_carre(f: &Polynome) -> Result<Vec<Polynome>, String> {
+    let f = f.primitif();
+    if f.est_zero() || f.degre() == 0 {
+        return Ok(vec![]);
+    }
+    if f.degre() == 1 {
+        return Ok(vec![f]);
+    }
+
+    // x | f : facteur x à part (simplifie le choix du premier)
+    if f.coeff(0).is_zero() {
+        let (qq, _) = f.divrem(&Polynome::x());
+        let mut r = vec![Polynome::x()];
+        r.extend(factorise_sans_carre(&qq)?);
+        return Ok(r);
+    }
+
+    let fz = entiers(&f);
+    let (p, modulaires) = choisit_premier(&fz)?;
+    if modulaires.len() == 1 {
+        return Ok(vec![f]);
+    }
+
+    // Borne de Mignotte (large) : |coeff d’un facteur| ≤ 2^n·(n+1)·‖f‖∞
+    let n = f.degre();
+    let norme = fz
+        .iter()
+        .map(|c| c.abs())
+        .max()
+        .unwrap_or_else(BigInt::zero);
+    let lc = fz[n].clone();
+    let borne = (BigInt::one() << n) * BigInt::from(n + 1) * norme * lc.abs() * 2;
+
+    let mut m = BigInt::from(p);
+    let pb = BigInt::from(p);
+    while m <= borne {
+        m = &m * &m;
+    }
+
+    let releves = releve_hensel(&fz, &modulaires, &pb, &m);
+    recombine(&f, releves, &m)
+}
+
+/* ------------------------ Choix du premier ------------------------ */
+
+fn entiers(f: &Polynome) -> Vec<BigInt> {
+    f.coeffs.iter().map(|c| c.to_integer()).collect()
+}
+
+fn choisit_premier(f: &[BigInt]) -> Result<(u32, Vec<PolyMod>), String> {
+    let mut meilleur: Option<(u32, Vec<PolyMod>)> = None;
+    let mut essais = 0;
+    for p in PREMIERS {
+        let pb = BigInt::from(p);
+        if (f.last().unwrap() % &pb).is_zero() {
+            continue;
+        }
+        let fp = reduit(f, &pb);
+        let d = derivee_mod(&fp, &pb);
+        if pgcd_mod(&fp, &d, &pb).len() != 1 {
+            continue; // pas sans carré mod p
+        }
+        let facteurs = factorise_mod_p(&fp, &pb);
+        if meilleur
+            .as_ref()
+            .is_none_or(|(_, m)| facteurs.len() < m.len())
+        {
+            meilleur = Some((p, facteurs));
+        }
+        essais += 1;
+        if essais >= ESSAIS_PREMIERS {
+            break;
+        }
+    }
+    meilleur.ok_or_else(|| "factorisation : aucun premier adapté".to_string())
+}
+
+/* ------------------------ Arithmétique Z/mZ[x] ------------------------ */
+
+fn normalise(mut a: PolyMod) -> PolyMod {
+    while a.last().is_some_and(|c| c.is_zero()) {
+        a.pop();
+    }
+    a
+}
+
+fn reduit(a: &[BigInt], m: &BigInt) -> PolyMod {
+    normalise(a.iter().map(|c| c.mod_floor(m)).collect())
+}
+
+fn add_mod(a: &PolyMod, b: &PolyMod, m: &BigInt) -> PolyMod {
+    let n = a.len().max(b.len());
+    let z = BigInt::zero();
+    normalise(
+        (0..n)
+            .map(|i| (a.get(i).unwrap_or(&z) + b.get(i).unwrap_or(&z)).mod_floor(m))
+            .collect(),
+    )
+}
+
+fn sub_mod(a: &PolyMod, b: &PolyMod, m: &BigInt) -> PolyMod {
+    let n = a.len().max(b.len());
+    let z = BigInt::zero();
+    normalise(
+        (0..n)
+            .map(|i| (a.get(i).unwrap_or(&z) - b.get(i).unwrap_or(&z)).mod_floor(m))
+            .collect(),
+    )
+}
+
+fn mul_mod(a: &PolyMod, b: &PolyMod, m: &BigInt) -> PolyMod {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut c = vec![BigInt::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for (j, y) in b.iter().enumerate() {
+            c[i + j] += x * y;
+        }
+    }
+    reduit(&c, m)
+}
+
+fn scalaire_mod(a: &PolyMod, k: &BigInt, m: &BigInt) -> PolyMod {
+    reduit(&a.iter().map(|c| c * k).collect::<Vec<_>>(), m)
+}
+
+/// Inverse de a modulo m (pgcd(a, m) = 1).
+fn inverse_mod(a: &BigInt, m: &BigInt) -> Option<BigInt> {
+    let e = a.mod_floor(m).extended_gcd(m);
+    e.gcd.is_one().then(|| e.x.mod_floor(m))
+}
+
+/// Division euclidienne mod m (dominant de d inversible).
+fn divrem_mod(a: &PolyMod, d: &PolyMod, m: &BigInt) -> (PolyMod, PolyMod) {
+    let dd = d.len() - 1;
+    let inv = inverse_mod(&d[dd], m).expect("dominant non inversible");
+    if a.len() < d.len() {
+        return (vec![], a.clone());
+    }
+    let mut r = a.clone();
+    let mut quot = vec![BigInt::zero(); a.len() - dd];
+    for i in (0..quot.len()).rev() {
+        let c = (&r[i + dd] * &inv).mod_floor(m);
+        if !c.is_zero() {
+            for (j, dj) in d.iter().enumerate() {
+                r[i + j] = (&r[i + j] - &c * dj).mod_floor(m);
+            }
+        }
+        quot[i] = c;
+    }
+    r.truncate(dd);
+    (normalise(quot), normalise(r))
+}
+
+fn unitaire_mod(a: &PolyMod, m: &BigInt) -> PolyMod {
+    match a.last() {
+        None => vec![],
+        Some(lc) => scalaire_mod(a, &inverse_mod(lc, m).unwrap(), m),
+    }
+}
+
+/// pgcd unitaire mod p (p premier).
+fn pgcd_mod(a: &PolyMod, b: &PolyMod, p: &BigInt) -> PolyMod {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    while !b.is_empty() {
+        let r = divrem_mod(&a, &b, p).1;
+        a = b;
+        b = r;
+    }
+    unitaire_mod(&a, p)
+}
+
+/// s, t avec s·a + t·b = 1 mod p (a, b premiers entre eux), deg s < deg b, deg t < deg a.
+fn bezout_mod(a: &PolyMod, b: &PolyMod, p: &BigInt) -> (PolyMod, PolyMod) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1): (PolyMod, PolyMod) = (vec![BigInt::one()], vec![]);
+    let (mut t0, mut t1): (PolyMod, PolyMod) = (vec![], vec![BigInt::one()]);
+    while !r1.is_empty() {
+        let (qq, r) = divrem_mod(&r0, &r1, p);
+        r0 = std::mem::replace(&mut r1, r);
+        let s = sub_mod(&s0, &mul_mod(&qq, &s1, p), p);
+        s0 = std::mem::replace(&mut s1, s);
+        let t = sub_mod(&t0, &mul_mod(&qq, &t1, p), p);
+        t0 = std::mem::replace(&mut t1, t);
+    }
+    // r0 constant non nul : on normalise
+    let inv = inverse_mod(&r0[0], p).unwrap();
+    (scalaire_mod(&s0, &inv, p), scalaire_mod(&t0, &inv, p))
+}
+
+fn derivee_mod(a: &PolyMod, m: &BigInt) -> PolyMod {
+    reduit(
+        &a.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c * BigInt::from(i))
+            .collect::<Vec<_>>(),
+        m,
+    )
+}
+
+/// b^e mod (f, p)
+fn puissance_mod(b: &PolyMod, e: &BigInt, f: &PolyMod, p: &BigInt) -> PolyMod {
+    let mut r: PolyMod = vec![BigInt::one()];
+    let mut base = divrem_mod(b, f, p).1;
+    let bits = e.bits();
+    for i in 0..bits {
+        if e.bit(i) {
+            r = divrem_mod(&mul_mod(&r, &base, p), f, p).1;
+        }
+        if i + 1 < bits {
+            base = divrem_mod(&mul_mod(&base, &base, p), f, p).1;
+        }
+    }
+    r
+}
+
+/* ------------------------ Factorisation mod p ------------------------ */
+
+/// f sans carré mod p (p impair) => facteurs irréductibles unitaires.
+fn factorise_mod_p(f: &PolyMod, p: &BigInt) -> Vec<PolyMod> {
+    let mut reste = unitaire_mod(f, p);
+    let x: PolyMod = vec![BigInt::zero(), BigInt::one()];
+    let mut h = x.clone();
+    let mut res = Vec::new();
+    let mut d = 1;
+
+    // degrés distincts
+    while reste.len() > 2 * d {
+        h = puissance_mod(&h, p, &reste, p);
+        let g = pgcd_mod(&reste, &sub_mod(&h, &x, p), p);
+        if g.len() > 1 {
+            scinde_egal(&g, d, p, &mut res, &mut 1u64);
+            reste = divrem_mod(&reste, &g, p).0;
+            h = divrem_mod(&h, &reste, p).1;
+        }
+        d += 1;
+    }
+    if reste.len() > 1 {
+        res.push(reste);
+    }
+    res
+}
+
+/// Cantor–Zassenhaus : g produit de facteurs de degré d.
+fn scinde_egal(g: &PolyMod, d: usize, p: &BigInt, out: &mut Vec<PolyMod>, graine: &mut u64) {
+    let n = g.len() - 1;
+    if n == d {
+        out.push(g.clone());
+        return;
+    }
+    let e: BigInt = (p.pow(d as u32) - 1) / 2;
+    loop {
+        // a aléatoire (déterministe) de degré < n
+        let a: PolyMod = normalise(
+            (0..n)
+                .map(|_| {
+                    *graine = graine
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    BigInt::from(*graine >> 33) % p
+                })
+                .collect(),
+        );
+        if a.len() <= 1 {
+            continue;
+        }
+        let b = sub_mod(&puissance_mod(&a, &e, g, p), &vec![BigInt::one()], p);
+        let h = pgcd_mod(g, &b, p);
+        if h.len() > 1 && h.len() < g.len() {
+            let autre = divrem_mod(g, &h, p).0;
+            scinde_egal(&h, d, p, out, graine);
+            scinde_egal(&autre, d, p, out, graine);
+            return;
+        }
+    }
+}
+
+/* ------------------------ Hensel ------------------------ */
+
+/// Relève f ≡ lc·∏ g_i (mod p) en f ≡ lc·∏ G_i (mod m), m = p^(2^j), G_i unitaires.
+fn releve_hensel(f: &[BigInt], facteurs: &[PolyMod], p: &BigInt, m: &BigInt) -> Vec<PolyMod> {
+    let fm = reduit(f, m);
+    let mut out = Vec::new();
+    releve_arbre(&fm, facteurs, p, m, &mut out);
+    out
+}
+
+fn releve_arbre(f: &PolyMod, facteurs: &[PolyMod], p: &BigInt, m: &BigInt, out: &mut Vec<PolyMod>) {
+    if facteurs.len() == 1 {
+        out.push(unitaire_mod(f, m));
+        return;
+    }
+    let (gauche, droite) = facteurs.split_at(facteurs.len() / 2);
+    let lc = f.last().unwrap().mod_floor(p);
+    let produit = |v: &[PolyMod]| {
+        v.iter()
+            .fold(vec![BigInt::one()], |acc, g| mul_mod(&acc, g, p))
+    };
+    let g0 = scalaire_mod(&produit(gauche), &lc, p);
+    let h0 = produit(droite);
+    let (s0, t0) = bezout_mod(&g0, &h0, p);
+
+    // pas quadratiques : modulo p, p², p⁴, … jusqu’à m
+    let (mut g, mut h, mut s, mut t) = (g0, h0, s0, t0);
+    let mut mm = p.clone();
+    while &mm < m {
+        let m2 = &mm * &mm;
+        let fm = reduit(f, &m2);
+        let e = sub_mod(&fm, &mul_mod(&g, &h, &m2), &m2);
+        let (qq, r) = divrem_mod(&mul_mod(&s, &e, &m2), &h, &m2);
+        let g2 = add_mod(
+            &add_mod(&g, &mul_mod(&t, &e, &m2), &m2),
+            &mul_mod(&qq, &g, &m2),
+            &m2,
+        );
+        let h2 = add_mod(&h, &r, &m2);
+        let b = sub_mod(
+            &add_mod(&mul_mod(&s, &g2, &m2), &mul_mod(&t, &h2, &m2), &m2),
+            &vec![BigInt::one()],
+            &m2,
+        );
+        let (c, d) = divrem_mod(&mul_mod(&s, &b, &m2), &h2, &m2);
+        s = sub_mod(&s, &d, &m2);
+        t = sub_mod(
+            &sub_mod(&t, &mul_mod(&t, &b, &m2), &m2),
+            &mul_mod(&c, &g2, &m2),
+            &m2,
+        );
+        g = g2;
+        h = h2;
+        mm = m2;
+    }
+
+    releve_arbre(&g, gauche, p, m, out);
+    releve_arbre(&h, droite, p, m, out);
+}
+
+/* ------------------------ Recombinaison ------------------------ */
+
+/// Représentant symétrique dans ]-m/2, m/2].
+fn symetrique(a: &PolyMod, m: &BigInt) -> Vec<BigInt> {
+    let demi = m / 2;
+    a.iter()
+        .map(|c| if c > &demi { c - m } else { c.clone() })
+        .collect()
+}
+
+fn recombine(
+    f: &Polynome,
+    mut modulaires: Vec<PolyMod>,
+    m: &BigInt,
+) -> Result<Vec<Polynome>, String> {
+    let mut f = f.clone();
+    let mut res = Vec::new();
+    let mut budget = BUDGET_RECOMBINAISON;
+    let mut taille = 1;
+
+    while 2 * taille <= modulaires.len() {
+        let r = modulaires.len();
+        let mut trouve = false;
+        let mut choix: Vec<usize> = (0..taille).collect();
+        loop {
+            budget = budget
+                .checked_sub(1)
+                .ok_or("factorisation : recombinaison trop coûteuse")?;
+            let lc = f.dominant().to_integer();
+            let g = choix.iter().fold(vec![lc.mod_floor(m)], |acc, &i| {
+                mul_mod(&acc, &modulaires[i], m)
+            });
+            let candidat = Polynome::depuis_entiers(&symetrique(&g, m)).primitif();
+            if candidat.degre() > 0 {
+                let (qq, reste) = f.divrem(&candidat);
+                if reste.est_zero() && qq.coeffs.iter().all(|c| c.is_integer()) {
+                    res.push(candidat);
+                    f = qq.primitif();
+                    for &i in choix.iter().rev() {
+                        modulaires.remove(i);
+                    }
+                    trouve = true;
+                    break;
+                }
+            }
+            if !combinaison_suivante(&mut choix, r) {
+                break;
+            }
+        }
+        if !trouve {
+            taille += 1;
+        }
+    }
+
+    if f.degre() > 0 {
+        res.push(f.primitif());
+    }
+    Ok(res)
+}
+
+/// Combinaison suivante (ordre lexicographique) de |choix| indices parmi n.
+pub(crate) fn combinaison_suivante(choix: &mut [usize], n: usize) -> bool {
+    let k = choix.len();
+    for i in (0..k).rev() {
+        if choix[i] < n - k + i {
+            choix[i] += 1;
+            for j in i + 1..k {
+                choix[j] = choix[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(c: &[i64]) -> Polynome {
+        Polynome::depuis_entiers(&c.iter().map(|&n| BigInt::from(n)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn zassenhaus_petits_cas() {
+        // x^4 - 10x^2 + 1 : irréductible sur Q, mais scindé modulo tout premier
+        assert_eq!(
+            factorise_sans_carre(&p(&[1, 0, -10, 0, 1])).unwrap().len(),
+            1
+        );
+
+        // x^4 - 1 = (x - 1)(x + 1)(x^2 + 1)
+        let (_, f) = factorise_polynome(&p(&[-1, 0, 0, 0, 1])).unwrap();
+        assert_eq!(
+            f,
+            vec![(p(&[-1, 1]), 1), (p(&[1, 1]), 1), (p(&[1, 0, 1]), 1)]
+        );
+
+        // 6x^2 + 5x + 1 = (2x + 1)(3x + 1), (x + 2)^3
+        let (_, f) = factorise_polynome(&p(&[1, 5, 6])).unwrap();
+        assert_eq!(f, vec![(p(&[1, 2]), 1), (p(&[1, 3]), 1)]);
+        let (c, f) = factorise_polynome(&p(&[16, 24, 12, 2])).unwrap();
+        assert_eq!(c, BigRational::from_integer(BigInt::from(2)));
+        assert_eq!(f, vec![(p(&[2, 1]), 3)]);
+    }
+}
@@ -2,7 +2,7 @@
 
 use num_bigint::BigInt;
 use num_rational::BigRational;
-use num_traits::{One, Zero};
+use num_traits::{One, Signed, Zero};
 
 use super::expr::Expr;
 
@@ -18,12 +18,36 @@ fn format_rat_pretty(r: &BigRational) -> String {
     }
 }
 
-fn format_sqrt_of_int(n: &BigInt) -> String {
-    format!("√{n}")
+/// Préfixe radical : √, ∛, ∜, puis ⁵√, ⁶√, ... (indice en exposant).
+fn prefixe_racine(k: u32) -> String {
+    match k {
+        2 => "√".to_string(),
+        3 => "∛".to_string(),
+        4 => "∜".to_string(),
+        _ => {
+            const EXPOSANTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+            let mut out: String = k
+                .to_string()
+                .chars()
+                .map(|c| EXPOSANTS[c.to_digit(10).unwrap_or(0) as usize])
+                .collect();
+            out.push('√');
+            out
+        }
+    }
+}
+
+fn format_racine_of_int(k: u32, n: &BigInt) -> String {
+    format!("{}{n}", prefixe_racine(k))
+}
+
+/// (p/q)*ᵏ√n -> pᵏ√n/q ; ᵏ√n/q si p=1 ; -ᵏ√n/q si p=-1
+fn format_mul_rat_racine(r: &BigRational, k: u32, n: &BigInt) -> String {
+    format_mul_rat_txt(r, &format_racine_of_int(k, n))
 }
 
-/// (p/q)*√n -> p√n/q ; √n/q si p=1 ; -√n/q si p=-1
-fn format_mul_rat_sqrt(r: &BigRational, n: &BigInt) -> String {
+/// (p/q)*R -> pR/q pour un radical R déjà mis en forme (√2, √(2+√2), …).
+fn format_mul_rat_txt(r: &BigRational, rad: &str) -> String {
     let p = r.numer();
     let q = r.denom();
 
@@ -34,49 +58,72 @@ fn format_mul_rat_sqrt(r: &BigRational, n: &BigInt) -> String {
     // p == 1
     if p == &BigInt::one() {
         if q.is_one() {
-            return format_sqrt_of_int(n);
+            return rad.to_string();
         }
-        return format!("{}/{}", format_sqrt_of_int(n), q);
+        return format!("{rad}/{q}");
     }
 
     // p == -1
     if p == &BigInt::from(-1) {
         if q.is_one() {
-            return format!("-{}", format_sqrt_of_int(n));
+            return format!("-{rad}");
         }
-        return format!("-{}/{}", format_sqrt_of_int(n), q);
+        return format!("-{rad}/{q}");
     }
 
     // p entier quelconque
     if q.is_one() {
-        return format!("{p}{}", format_sqrt_of_int(n));
+        return format!("{p}{rad}");
     }
-    format!("{p}{}/{}", format_sqrt_of_int(n), q)
+    format!("{p}{rad}/{q}")
 }
 
-/// Tente de reconnaître √(entier) et renvoie cet entier (n) si oui.
-fn as_sqrt_of_int(e: &Expr) -> Option<&BigInt> {
-    if let Expr::Sqrt(inner) = e {
-        if let Expr::Rat(r) = inner.as_ref() {
-            if r.denom().is_one() {
-                return Some(r.numer());
+/// "(a+b)" reste tel quel, "a/b" devient "(a/b)" : parenthèses externes sans doublon.
+pub(crate) fn entre_parentheses(s: String) -> String {
+    let mut profondeur = 0i32;
+    let externe = s.starts_with('(')
+        && s.ends_with(')')
+        && s.char_indices().all(|(i, c)| {
+            match c {
+                '(' => profondeur += 1,
+                ')' => profondeur -= 1,
+                _ => {}
             }
+            profondeur > 0 || i + c.len_utf8() == s.len()
+        });
+    if externe {
+        s
+    } else {
+        format!("({s})")
+    }
+}
+
+/// Tente de reconnaître √(entier) ou ᵏ√(entier) et renvoie (k, entier) si oui.
+fn as_racine_of_int(e: &Expr) -> Option<(u32, &BigInt)> {
+    let (inner, k) = match e {
+        Expr::Sqrt(inner) => (inner, 2),
+        Expr::Root(inner, k) => (inner, *k),
+        _ => return None,
+    };
+    if let Expr::Rat(r) = inner.as_ref() {
+        if r.denom().is_one() {
+            return Some((k, r.numer()));
         }
     }
     None
 }
 
-/// Tente de reconnaître (Rat r) * √(entier) ou √(entier) * (Rat r).
-/// Renvoie (r, n) si oui.
-fn as_mul_rat_sqrt(e: &Expr) -> Option<(BigRational, BigInt)> {
+/// Tente de reconnaître (Rat r) * ᵏ√(entier) ou ᵏ√(entier) * (Rat r).
+/// Renvoie (r, k, n) si oui.
+fn as_mul_rat_racine(e: &Expr) -> Option<(BigRational, u32, BigInt)> {
     if let Expr::Mul(a, b) = e {
-        // Rat * Sqrt(int)
-        if let (Expr::Rat(r), Some(n)) = (a.as_ref(), as_sqrt_of_int(b.as_ref())) {
-            return Some((r.clone(), n.clone()));
+        // Rat * ᵏ√(int)
+        if let (Expr::Rat(r), Some((k, n))) = (a.as_ref(), as_racine_of_int(b.as_ref())) {
+            return Some((r.clone(), k, n.clone()));
         }
-        // Sqrt(int) * Rat
-        if let (Some(n), Expr::Rat(r)) = (as_sqrt_of_int(a.as_ref()), b.as_ref()) {
-            return Some((r.clone(), n.clone()));
+        // ᵏ√(int) * Rat
+        if let (Some((k, n)), Expr::Rat(r)) = (as_racine_of_int(a.as_ref()), b.as_ref()) {
+            return Some((r.clone(), k, n.clone()));
         }
     }
     None
@@ -90,6 +137,26 @@ fn needs_parens_for_unary_minus(e: &Expr) -> bool {
     matches!(e, Expr::Add(_, _) | Expr::Sub(_, _))
 }
 
+/// Base / exposant d’une puissance : pas de parenthèses pour les atomes (2, x, π).
+fn format_atome_puissance(e: &Expr) -> String {
+    match e {
+        Expr::Rat(r) if r.denom().is_one() && !r.numer().is_negative() => format_rat_pretty(r),
+        Expr::Var(_) | Expr::Pi => format_expr_pretty(e),
+        _ if as_racine_of_int(e).is_some() => format_expr_pretty(e),
+        // Add/Sub/Mul génériques sont déjà parenthésés par format_expr_pretty
+        Expr::Add(_, _) | Expr::Mul(_, _) => {
+            let s = format_expr_pretty(e);
+            if s.starts_with('(') {
+                s
+            } else {
+                format!("({s})")
+            }
+        }
+        Expr::Sub(a, _) if !is_zero_expr(a) => format_expr_pretty(e),
+        _ => format!("({})", format_expr_pretty(e)),
+    }
+}
+
 /* ------------------------ π “joli” ------------------------ */
 
 /// coeff*π : affichage joli (π/2, 3π/2, -2π, etc.)
@@ -142,35 +209,71 @@ pub fn format_expr_pretty(e: &Expr) -> String {
         Pi => "π".to_string(),
         Var(s) => s.clone(),
 
-        // √2, √3, etc. si argument entier
-        Sqrt(x) => match &**x {
-            Rat(r) if r.denom().is_one() => format_sqrt_of_int(r.numer()),
-            _ => format!("√({})", format_expr_pretty(x)),
+        // √2, √3, ∛2, etc. si argument entier
+        Sqrt(x) => match as_racine_of_int(e) {
+            Some((k, n)) => format_racine_of_int(k, n),
+            None => format!("√{}", entre_parentheses(format_expr_pretty(x))),
+        },
+        Root(x, k) => match as_racine_of_int(e) {
+            Some((k, n)) => format_racine_of_int(k, n),
+            None => format!(
+                "{}{}",
+                prefixe_racine(*k),
+                entre_parentheses(format_expr_pretty(x))
+            ),
         },
 
-        PowInt(x, n) => format!("({})^{n}", format_expr_pretty(x)),
+        PowInt(x, n) => format!("{}^{n}", format_atome_puissance(x)),
+        Pow(x, e) => format!(
+            "{}^{}",
+            format_atome_puissance(x),
+            format_atome_puissance(e)
+        ),
 
         Sin(x) => format!("sin({})", format_expr_pretty(x)),
         Cos(x) => format!("cos({})", format_expr_pretty(x)),
         Tan(x) => format!("tan({})", format_expr_pretty(x)),
 
-        // cas joli : (p/q)*√n => p√n/q (donc √2/2, √3/3, etc.)
+        Abs(x) => format!("|{}|", format_expr_pretty(x)),
+        Sign(x) => format!("sign({})", format_expr_pretty(x)),
+        Floor(x) => format!("⌊{}⌋", format_expr_pretty(x)),
+        Ceil(x) => format!("⌈{}⌉", format_expr_pretty(x)),
+        Round(x) => format!("round({})", format_expr_pretty(x)),
+        Frac(x) => format!("frac({})", format_expr_pretty(x)),
+
+        Fact(x) => format!("{}!", format_atome_puissance(x)),
+        FactDouble(x) => format!("{}!!", format_atome_puissance(x)),
+        Binom(n, k) => format!("C({}, {})", format_expr_pretty(n), format_expr_pretty(k)),
+        Arrang(n, k) => format!("P({}, {})", format_expr_pretty(n), format_expr_pretty(k)),
+
+        Pgcd(a, b) => format!("gcd({}, {})", format_expr_pretty(a), format_expr_pretty(b)),
+        Ppcm(a, b) => format!("lcm({}, {})", format_expr_pretty(a), format_expr_pretty(b)),
+        Modulo(a, m) => format!("mod({}, {})", format_expr_pretty(a), format_expr_pretty(m)),
+
+        Egal(a, b) => format!("{} = {}", format_expr_pretty(a), format_expr_pretty(b)),
+
+        // en ligne (démarche, sous-expressions) ; la grille est réservée à l’EXACT final
+        Matrice(l) => {
+            let lignes: Vec<String> = l
+                .iter()
+                .map(|ligne| {
+                    let c: Vec<String> = ligne.iter().map(format_expr_pretty).collect();
+                    format!("[{}]", c.join(", "))
+                })
+                .collect();
+            format!("[{}]", lignes.join(", "))
+        }
+        Matriciel(op, x) => format!("{}({})", op.nom(), format_expr_pretty(x)),
+
+        // cas joli : (p/q)*ᵏ√n => pᵏ√n/q (donc √2/2, √3/3, 3∛2, etc.)
         Mul(a, b) => {
-            // (Rat)*(Sqrt(Rat(int)))
-            if let (Rat(r), Sqrt(inner)) = (&**a, &**b) {
-                if let Rat(nr) = &**inner {
-                    if nr.denom().is_one() {
-                        return format_mul_rat_sqrt(r, nr.numer());
-                    }
-                }
+            if let Some((r, k, n)) = as_mul_rat_racine(e) {
+                return format_mul_rat_racine(&r, k, &n);
             }
-            // (Sqrt(Rat(int)))*(Rat)
-            if let (Sqrt(inner), Rat(r)) = (&**a, &**b) {
-                if let Rat(nr) = &**inner {
-                    if nr.denom().is_one() {
-                        return format_mul_rat_sqrt(r, nr.numer());
-                    }
-                }
+
+            // (p/q)*√(…) => p√(…)/q (racines dénouées impossibles : √(2+√2)/2)
+            if let (Rat(r), Sqrt(_) | Root(_, _)) = (a.as_ref(), b.as_ref()) {
+                return format_mul_rat_txt(r, &format_expr_pretty(b));
             }
 
             format!("({}*{})", format_expr_pretty(a), format_expr_pretty(b))
@@ -183,15 +286,15 @@ pub fn format_expr_pretty(e: &Expr) -> String {
                 if rden.denom().is_one() {
                     let k = rden.numer();
 
-                    // √n / k  -> √n/k
-                    if let Some(n) = as_sqrt_of_int(a.as_ref()) {
-                        return format!("{}/{}", format_sqrt_of_int(n), k);
+                    // ᵏ√n / m  -> ᵏ√n/m
+                    if let Some((kr, n)) = as_racine_of_int(a.as_ref()) {
+                        return format!("{}/{}", format_racine_of_int(kr, n), k);
                     }
 
-                    // ((p/q)*√n) / k -> (p/qk)*√n -> p√n/(qk)
-                    if let Some((r, n)) = as_mul_rat_sqrt(a.as_ref()) {
+                    // ((p/q)*ᵏ√n) / m -> (p/qm)*ᵏ√n -> pᵏ√n/(qm)
+                    if let Some((r, kr, n)) = as_mul_rat_racine(a.as_ref()) {
                         let rk = r / BigRational::from_integer(k.clone());
-                        return format_mul_rat_sqrt(&rk, &n);
+                        return format_mul_rat_racine(&rk, kr, &n);
                     }
 
                     // cas général : expr/k
@@ -200,9 +303,14 @@ pub fn format_expr_pretty(e: &Expr) -> String {
                 }
             }
 
-            // sinon affichage normal
+            // sinon affichage normal ; dénominateur composé entre parenthèses
+            // (1/(2√(x)) et non 1/2√(x), qui se lirait √x/2)
             let sa = format_expr_pretty(a);
-            format!("{sa}/{}", format_expr_pretty(b))
+            let sb = format_expr_pretty(b);
+            match b.as_ref() {
+                Mul(_, _) | Div(_, _) | Rat(_) => format!("{sa}/{}", entre_parentheses(sb)),
+                _ => format!("{sa}/{sb}"),
+            }
         }
 
         Add(a, b) => format!("({}+{})", format_expr_pretty(a), format_expr_pretty(b)),
@@ -231,8 +339,143 @@ pub fn format_exact_final(expr_simplifie: &Expr) -> String {
     if matches!(expr_simplifie, Expr::Indefini) {
         return "indéfini".to_string();
     }
+    if let Expr::Matrice(l) = expr_simplifie {
+        return format_grille(l);
+    }
     if let Some(c) = expr_simplifie.as_coeff_pi() {
         return format_coeff_pi(&c);
     }
     format_expr_pretty(expr_simplifie)
 }
+
+/* ------------------------ Matrices : grille ------------------------ */
+
+/// Grille alignée (colonnes à droite) :
+/// ⎡1  2⎤
+/// ⎣3  4⎦   (une seule ligne : [1  2])
+fn format_grille(l: &[Vec<Expr>]) -> String {
+    let cases: Vec<Vec<String>> = l
+        .iter()
+        .map(|ligne| ligne.iter().map(format_exact_final).collect())
+        .collect();
+    let nb_colonnes = cases.first().map_or(0, |c| c.len());
+    let largeurs: Vec<usize> = (0..nb_colonnes)
+        .map(|j| {
+            cases
+                .iter()
+                .map(|c| c[j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let n = cases.len();
+    cases
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let (g, d) = match (n, i) {
+                (1, _) => ('[', ']'),
+                (_, 0) => ('⎡', '⎤'),
+                (_, i) if i + 1 == n => ('⎣', '⎦'),
+                _ => ('⎢', '⎥'),
+            };
+            let texte: Vec<String> = c
+                .iter()
+                .zip(&largeurs)
+                .map(|(x, &w)| format!("{}{x}", " ".repeat(w - x.chars().count())))
+                .collect();
+            format!("{g}{}{d}", texte.join("  "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/* ------------------------ LaTeX ------------------------ */
+
+/// Forme LaTeX (matrices : pmatrix), pour copier dans un document.
+pub fn format_latex(e: &Expr) -> String {
+    use Expr::*;
+
+    let fonction = |nom: &str, x: &Expr| format!("{nom}\\left({}\\right)", format_latex(x));
+    let deux = |nom: &str, a: &Expr, b: &Expr| {
+        format!(
+            "\\operatorname{{{nom}}}\\left({}, {}\\right)",
+            format_latex(a),
+            format_latex(b)
+        )
+    };
+    match e {
+        Indefini => "\\text{indéfini}".to_string(),
+        Rat(r) if r.denom().is_one() => r.numer().to_string(),
+        Rat(r) => {
+            let signe = if r.is_negative() { "-" } else { "" };
+            format!("{signe}\\frac{{{}}}{{{}}}", r.numer().abs(), r.denom())
+        }
+        Pi => "\\pi".to_string(),
+        Var(s) => s.clone(),
+
+        Sqrt(x) => format!("\\sqrt{{{}}}", format_latex(x)),
+        Root(x, k) => format!("\\sqrt[{k}]{{{}}}", format_latex(x)),
+        PowInt(x, n) => format!("{}^{{{n}}}", latex_facteur(x)),
+        Pow(x, y) => format!("{}^{{{}}}", latex_facteur(x), format_latex(y)),
+
+        Sin(x) => fonction("\\sin", x),
+        Cos(x) => fonction("\\cos", x),
+        Tan(x) => fonction("\\tan", x),
+        Abs(x) => format!("\\left|{}\\right|", format_latex(x)),
+        Floor(x) => format!("\\left\\lfloor {}\\right\\rfloor", format_latex(x)),
+        Ceil(x) => format!("\\left\\lceil {}\\right\\rceil", format_latex(x)),
+        Sign(x) => fonction("\\operatorname{sign}", x),
+        Round(x) => fonction("\\operatorname{round}", x),
+        Frac(x) => fonction("\\operatorname{frac}", x),
+
+        Fact(x) => format!("{}!", latex_facteur(x)),
+        FactDouble(x) => format!("{}!!", latex_facteur(x)),
+        Binom(n, k) => format!("\\binom{{{}}}{{{}}}", format_latex(n), format_latex(k)),
+        Arrang(n, k) => deux("P", n, k),
+        Pgcd(a, b) => deux("gcd", a, b),
+        Ppcm(a, b) => deux("lcm", a, b),
+        Modulo(a, m) => deux("mod", a, m),
+
+        Add(a, b) => format!("{} + {}", format_latex(a), format_latex(b)),
+        Sub(a, b) if is_zero_expr(a) => format!("-{}", latex_facteur(b)),
+        Sub(a, b) => format!("{} - {}", format_latex(a), latex_facteur(b)),
+        // 2√3, \frac{1}{2}x : coefficient rationnel en tête
+        Mul(a, b) => match a.as_ref() {
+            Rat(r) if r.denom().is_one() && r.numer() == &BigInt::from(-1) => {
+                format!("-{}", latex_facteur(b))
+            }
+            Rat(r) if r.denom().is_one() => format!("{}{}", r.numer(), latex_facteur(b)),
+            Rat(_) => format!("{}{}", format_latex(a), latex_facteur(b)),
+            _ => format!("{} \\cdot {}", latex_facteur(a), latex_facteur(b)),
+        },
+        Div(a, b) => format!("\\frac{{{}}}{{{}}}", format_latex(a), format_latex(b)),
+        Egal(a, b) => format!("{} = {}", format_latex(a), format_latex(b)),
+
+        Matrice(l) => {
+            let lignes: Vec<String> = l
+                .iter()
+                .map(|ligne| {
+                    let c: Vec<String> = ligne.iter().map(format_latex).collect();
+                    c.join(" & ")
+                })
+                .collect();
+            format!(
+                "\\begin{{pmatrix}} {} \\end{{pmatrix}}",
+                lignes.join(" \\\\ ")
+            )
+        }
+        Matriciel(op, x) => fonction(&format!("\\operatorname{{{}}}", op.nom()), x),
+    }
+}
+
+/// Facteur / base de puissance : sommes et négatifs entre parenthèses.
+fn latex_facteur(e: &Expr) -> String {
+    match e {
+        Expr::Add(_, _) | Expr::Sub(_, _) => format!("\\left({}\\right)", format_latex(e)),
+        Expr::Rat(r) if r.is_negative() || !r.denom().is_one() => {
+            format!("\\left({}\\right)", format_latex(e))
+        }
+        _ => format_latex(e),
+    }
+}
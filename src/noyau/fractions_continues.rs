@@ -0,0 +1,463 @@
+// src/noyau/fractions_continues.rs
+//
+// Fractions continues [a0; a1, a2, …] + réduites + meilleure approximation
+// ------------------------------------------------------------------------
+// Trois sources, de la plus exacte à la moins exacte :
+// - Rat            : algorithme d’Euclide (développement fini)
+// - a + b·√d       : irrationnel quadratique, développement périodique EXACT
+//                    (états (P, Q) de (P + √D)/Q ; √2 = [1; (2)])
+// - le reste       : termes certifiés par encadrement (intervalles rationnels, signe.rs),
+//                    on n’émet un terme que si les deux bornes le partagent
+//
+// IMPORTANT (SAFE):
+// - Pour la troisième source, le développement est toujours affiché tronqué (“…”) :
+//   on ne prétend jamais qu’il s’arrête.
+// - Meilleure approximation : réduite ou réduite intermédiaire (semi-convergente),
+//   départagées par comparaison EXACTE (ou encadrement) avec le milieu.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::expr::Expr;
+use super::signe::encadrement_rationnel;
+
+/// Nombre de termes par défaut pour un développement infini.
+pub const TERMES_DEFAUT: usize = 20;
+
+/// Borne dure sur le nombre de termes demandés.
+pub const TERMES_MAX: usize = 500;
+
+/// Précision initiale / maximale de l’encadrement (chiffres).
+const DIGITS_INITIAL: usize = 60;
+const DIGITS_MAX: usize = 2000;
+
+/// Longueur maximale de période explorée (garde-fou).
+const PERIODE_MAX: usize = 10_000;
+
+/* ------------------------ Sources ------------------------ */
+
+/// x tel qu’on sait le manipuler exactement (ou par encadrement).
+#[derive(Clone, Debug)]
+pub enum Source {
+    Rationnel(BigRational),
+    /// a + b·√d, b ≠ 0, d entier > 0 non carré.
+    Quadratique {
+        a: BigRational,
+        b: BigRational,
+        d: BigInt,
+    },
+    Encadrement(Expr),
+}
+
+impl Source {
+    /// Classe une Expr (forme canon) : rationnel, quadratique, ou encadrement ΣLocal.
+    pub fn depuis_expr(e: &Expr) -> Source {
+        match as_quadratique(e) {
+            Some((a, b, _)) if b.is_zero() => Source::Rationnel(a),
+            Some((a, b, Some(d))) => Source::Quadratique { a, b, d },
+            _ => Source::Encadrement(e.clone()),
+        }
+    }
+
+    /// Compare x à un rationnel m (None : indécidable à la précision maximale).
+    pub fn compare(&self, m: &BigRational) -> Option<Ordering> {
+        match self {
+            Source::Rationnel(r) => Some(r.cmp(m)),
+            Source::Quadratique { a, b, d } => {
+                // signe de (a - m) + b√d
+                let u = a - m;
+                if !u.is_negative() && b.is_positive() {
+                    return Some(Ordering::Greater);
+                }
+                if !u.is_positive() && b.is_negative() {
+                    return Some(Ordering::Less);
+                }
+                // signes opposés : le plus grand de u² et b²d l’emporte (jamais égaux, d non carré)
+                let c = (&u * &u).cmp(&(b * b * BigRational::from_integer(d.clone())));
+                Some(if u.is_positive() { c } else { c.reverse() })
+            }
+            Source::Encadrement(e) => {
+                let mut digits = DIGITS_INITIAL;
+                while digits <= DIGITS_MAX {
+                    let (bas, haut) = encadrement_rationnel(e, digits)?;
+                    if &bas > m {
+                        return Some(Ordering::Greater);
+                    }
+                    if &haut < m {
+                        return Some(Ordering::Less);
+                    }
+                    digits *= 2;
+                }
+                None
+            }
+        }
+    }
+}
+
+/// a + b·√d depuis une Expr canon (d None : pas encore de radical rencontré).
+type Quad = (BigRational, BigRational, Option<BigInt>);
+
+fn as_quadratique(e: &Expr) -> Option<Quad> {
+    use Expr::*;
+    match e {
+        Rat(r) => Some((r.clone(), BigRational::zero(), None)),
+        Sqrt(x) => match x.as_ref() {
+            Rat(r) if r.is_integer() && r.is_positive() => {
+                let n = r.to_integer();
+                let s = n.sqrt();
+                if &s * &s == n {
+                    Some((BigRational::from_integer(s), BigRational::zero(), None))
+                } else {
+                    Some((BigRational::zero(), BigRational::one(), Some(n)))
+                }
+            }
+            _ => None,
+        },
+        Add(x, y) | Sub(x, y) => {
+            let (a1, b1, d1) = as_quadratique(x)?;
+            let (a2, b2, d2) = as_quadratique(y)?;
+            let d = meme_radical(d1, d2)?;
+            if matches!(e, Add(_, _)) {
+                Some((a1 + a2, b1 + b2, d))
+            } else {
+                Some((a1 - a2, b1 - b2, d))
+            }
+        }
+        Mul(x, y) => {
+            let (a1, b1, d1) = as_quadratique(x)?;
+            let (a2, b2, d2) = as_quadratique(y)?;
+            let d = meme_radical(d1, d2)?;
+            // (a1 + b1√d)(a2 + b2√d) = a1a2 + b1b2·d + (a1b2 + b1a2)√d
+            let bb = match &d {
+                Some(p) => &b1 * &b2 * BigRational::from_integer(p.clone()),
+                None => BigRational::zero(),
+            };
+            Some((&a1 * &a2 + bb, &a1 * &b2 + &b1 * &a2, d))
+        }
+        Div(x, y) => {
+            let (a, b, d) = as_quadratique(x)?;
+            match y.as_ref() {
+                Rat(r) if !r.is_zero() => Some((a / r, b / r, d)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Fusion des radicaux : None si deux √d différents (hors de Q(√d)).
+fn meme_radical(d1: Option<BigInt>, d2: Option<BigInt>) -> Option<Option<BigInt>> {
+    match (d1, d2) {
+        (Some(p), Some(q)) if p != q => None,
+        (p, q) => Some(p.or(q)),
+    }
+}
+
+/* ------------------------ Développements ------------------------ */
+
+/// Développement : termes + indice de début de période (quadratique) + exactitude.
+#[derive(Clone, Debug)]
+pub struct FractionContinue {
+    pub termes: Vec<BigInt>,
+    /// Some(k) : termes[k..] se répète indéfiniment (k ≥ 1).
+    pub periode: Option<usize>,
+    /// true : développement fini et complet (rationnel).
+    pub fini: bool,
+}
+
+impl FractionContinue {
+    /// n-ième terme (la période est déroulée ; None au-delà des termes connus).
+    pub fn terme(&self, n: usize) -> Option<BigInt> {
+        if let Some(t) = self.termes.get(n) {
+            return Some(t.clone());
+        }
+        let k = self.periode?;
+        let l = self.termes.len() - k;
+        Some(self.termes[k + (n - k) % l].clone())
+    }
+
+    /// "[a0; a1, a2]" ; période entre parenthèses ; “…” si tronqué.
+    pub fn format(&self) -> String {
+        let txt = |v: &[BigInt]| {
+            v.iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let a0 = &self.termes[0];
+        let queue = match self.periode {
+            Some(k) => {
+                let avant = txt(&self.termes[1..k]);
+                let per = format!("({})", txt(&self.termes[k..]));
+                if avant.is_empty() {
+                    per
+                } else {
+                    format!("{avant}, {per}")
+                }
+            }
+            None if self.fini => txt(&self.termes[1..]),
+            None if self.termes.len() == 1 => "…".to_string(),
+            None => format!("{}, …", txt(&self.termes[1..])),
+        };
+        if queue.is_empty() {
+            format!("[{a0}]")
+        } else {
+            format!("[{a0}; {queue}]")
+        }
+    }
+}
+
+/// Développement de x (au plus `n_max` termes pour une source par encadrement).
+pub fn developpe(src: &Source, n_max: usize) -> Result<FractionContinue, String> {
+    match src {
+        Source::Rationnel(r) => Ok(developpe_rationnel(r)),
+        Source::Quadratique { a, b, d } => Ok(developpe_quadratique(a, b, d)),
+        Source::Encadrement(e) => developpe_encadrement(e, n_max),
+    }
+}
+
+fn developpe_rationnel(r: &BigRational) -> FractionContinue {
+    let mut termes = Vec::new();
+    let (mut p, mut q) = (r.numer().clone(), r.denom().clone());
+    while !q.is_zero() {
+        let (a, reste) = p.div_mod_floor(&q);
+        termes.push(a);
+        p = q;
+        q = reste;
+    }
+    FractionContinue {
+        termes,
+        periode: None,
+        fini: true,
+    }
+}
+
+/// x = a + b√d mis sous la forme (P + √D)/Q avec Q | D - P².
+fn developpe_quadratique(a: &BigRational, b: &BigRational, d: &BigInt) -> FractionContinue {
+    // x = (p1 + p2√d)/q, q > 0 commun
+    let q0 = a.denom().lcm(b.denom());
+    let p1 = (a * BigRational::from_integer(q0.clone())).to_integer();
+    let p2 = (b * BigRational::from_integer(q0.clone())).to_integer();
+
+    // p2√d = ±√(p2²d)
+    let (mut pp, mut qq) = if p2.is_positive() {
+        (p1, q0)
+    } else {
+        (-p1, -q0)
+    };
+    let mut dd = &p2 * &p2 * d;
+    if !((&dd - &pp * &pp) % &qq).is_zero() {
+        let aq = qq.abs();
+        pp *= &aq;
+        dd *= &aq * &aq;
+        qq *= &aq;
+    }
+
+    let s = dd.sqrt();
+    let mut termes: Vec<BigInt> = Vec::new();
+    let mut vus: HashMap<(BigInt, BigInt), usize> = HashMap::new();
+    loop {
+        if let Some(&k) = vus.get(&(pp.clone(), qq.clone())) {
+            // période : termes[k..]
+            let periode = if k == 0 {
+                // purement périodique : on garde a0 à part et on tourne la période
+                let t0 = termes[0].clone();
+                termes.push(t0);
+                1
+            } else {
+                k
+            };
+            return FractionContinue {
+                termes,
+                periode: Some(periode),
+                fini: false,
+            };
+        }
+        if termes.len() > PERIODE_MAX {
+            return FractionContinue {
+                termes,
+                periode: None,
+                fini: false,
+            };
+        }
+        vus.insert((pp.clone(), qq.clone()), termes.len());
+
+        let t = if qq.is_positive() {
+            (&pp + &s).div_floor(&qq)
+        } else {
+            let num: BigInt = -&pp - &s - 1;
+            num.div_floor(&-&qq)
+        };
+        pp = &t * &qq - &pp;
+        qq = (&dd - &pp * &pp) / &qq;
+        termes.push(t);
+    }
+}
+
+fn developpe_encadrement(e: &Expr, n_max: usize) -> Result<FractionContinue, String> {
+    let mut digits = DIGITS_INITIAL;
+    loop {
+        let (bas, haut) = encadrement_rationnel(e, digits)
+            .ok_or("cf : valeur non évaluable (ΣLocal indisponible)")?;
+        let termes = termes_communs(bas, haut, n_max);
+        if termes.len() >= n_max || digits >= DIGITS_MAX {
+            if termes.is_empty() {
+                return Err("cf : encadrement insuffisant (valeur trop proche d’un entier)".into());
+            }
+            return Ok(FractionContinue {
+                termes,
+                periode: None,
+                fini: false,
+            });
+        }
+        digits *= 2;
+    }
+}
+
+/// Termes communs aux développements de toutes les valeurs de [bas, haut].
+fn termes_communs(mut bas: BigRational, mut haut: BigRational, n_max: usize) -> Vec<BigInt> {
+    let mut termes = Vec::new();
+    while termes.len() < n_max {
+        let fb = bas.floor();
+        if fb != haut.floor() {
+            break;
+        }
+        let rb = &bas - &fb;
+        let rh = &haut - &fb;
+        termes.push(fb.to_integer());
+        if rb.is_zero() {
+            break;
+        }
+        // x -> 1/(x - a) : l’intervalle se retourne
+        bas = rh.recip();
+        haut = rb.recip();
+    }
+    termes
+}
+
+/* ------------------------ Réduites et approximation ------------------------ */
+
+/// Réduites p_k/q_k des `n` premiers termes.
+pub fn reduites(fc: &FractionContinue, n: usize) -> Vec<BigRational> {
+    let mut out = Vec::new();
+    let (mut p0, mut q0) = (BigInt::zero(), BigInt::one());
+    let (mut p1, mut q1) = (BigInt::one(), BigInt::zero());
+    for k in 0..n {
+        let Some(a) = fc.terme(k) else { break };
+        let p = &a * &p1 + &p0;
+        let q = &a * &q1 + &q0;
+        out.push(BigRational::new(p.clone(), q.clone()));
+        (p0, q0, p1, q1) = (p1, q1, p, q);
+    }
+    out
+}
+
+/// Meilleure approximation p/q de x avec 1 ≤ q ≤ q_max.
+/// Retourne (approximation, explication courte).
+pub fn meilleure_approximation(
+    src: &Source,
+    q_max: &BigInt,
+) -> Result<(BigRational, String), String> {
+    if !q_max.is_positive() {
+        return Err("approx : borne de dénominateur ≥ 1 attendue".into());
+    }
+
+    // assez de termes pour dépasser q_max (croissance au moins de Fibonacci)
+    let n = (q_max.bits() as usize * 2 + 4).min(TERMES_MAX);
+    let fc = developpe(src, n)?;
+
+    let (mut p0, mut q0) = (BigInt::zero(), BigInt::one());
+    let (mut p1, mut q1) = (BigInt::one(), BigInt::zero());
+    let mut k = 0;
+    loop {
+        let Some(a) = fc.terme(k) else {
+            // développement fini (x rationnel atteint) ou précision épuisée
+            if q1.is_zero() {
+                return Err("approx : développement indisponible".into());
+            }
+            let r = BigRational::new(p1, q1);
+            let pourquoi = if fc.fini {
+                "x lui-même (dénominateur sous la borne)".to_string()
+            } else {
+                "dernière réduite certifiée (précision épuisée)".to_string()
+            };
+            return Ok((r, pourquoi));
+        };
+        let p = &a * &p1 + &p0;
+        let q = &a * &q1 + &q0;
+        if &q > q_max {
+            // réduite intermédiaire (p0 + t·p1)/(q0 + t·q1), t maximal sous la borne
+            let t = (q_max - &q0).div_floor(&q1);
+            let conv = BigRational::new(p1.clone(), q1.clone());
+            if t.is_zero() || (&t * 2) < a {
+                return Ok((conv, format!("réduite p{}/q{}", k - 1, k - 1)));
+            }
+            let semi = BigRational::new(&p0 + &t * &p1, &q0 + &t * &q1);
+            if (&t * 2) > a {
+                return Ok((semi, format!("réduite intermédiaire (t = {t})")));
+            }
+            // t = a/2 : on compare x au milieu des deux candidats
+            let milieu = (&conv + &semi) / BigRational::from_integer(BigInt::from(2));
+            let semi_plus_proche = match src.compare(&milieu) {
+                Some(Ordering::Greater) => semi > conv,
+                Some(Ordering::Less) => semi < conv,
+                _ => false,
+            };
+            return Ok(if semi_plus_proche {
+                (semi, format!("réduite intermédiaire (t = {t}, cas limite)"))
+            } else {
+                (conv, format!("réduite p{}/q{} (cas limite)", k - 1, k - 1))
+            });
+        }
+        (p0, q0, p1, q1) = (p1, q1, p, q);
+        k += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rat(p: i64, q: i64) -> BigRational {
+        BigRational::new(BigInt::from(p), BigInt::from(q))
+    }
+
+    #[test]
+    fn quadratiques_periodiques() {
+        let racine = |a: i64, b: i64, d: i64| Source::Quadratique {
+            a: rat(a, 1),
+            b: rat(b, 1),
+            d: BigInt::from(d),
+        };
+        assert_eq!(developpe(&racine(0, 1, 2), 0).unwrap().format(), "[1; (2)]");
+        assert_eq!(
+            developpe(&racine(0, 1, 7), 0).unwrap().format(),
+            "[2; (1, 1, 1, 4)]"
+        );
+        // nombre d’or (1 + √5)/2 = [1; (1)]
+        let phi = Source::Quadratique {
+            a: rat(1, 2),
+            b: rat(1, 2),
+            d: BigInt::from(5),
+        };
+        assert_eq!(developpe(&phi, 0).unwrap().format(), "[1; (1)]");
+        // -√2 = [-2; 1, 1, (2)]
+        assert_eq!(
+            developpe(&racine(0, -1, 2), 0).unwrap().format(),
+            "[-2; 1, 1, (2)]"
+        );
+    }
+
+    #[test]
+    fn approximation_semi_convergente() {
+        let src = Source::Rationnel(rat(31415926, 10000000));
+        let (r, _) = meilleure_approximation(&src, &BigInt::from(10)).unwrap();
+        assert_eq!(r, rat(22, 7));
+        let (r, _) = meilleure_approximation(&src, &BigInt::from(100)).unwrap();
+        assert_eq!(r, rat(311, 99));
+    }
+}
@@ -0,0 +1,207 @@
+// src/noyau/fractions_rationnelles.rs
+//
+// Fractions rationnelles : simplification par le pgcd
+// ---------------------------------------------------
+// Pour chaque quotient P/Q de polynômes (polynome_multi.rs, Q non constant) :
+//   g = pgcd(P, Q) ; si g n’est pas constant, P/Q => (P/g)/(Q/g)
+//   (x^2-1)/(x-1) => x+1
+//
+// Domaine : la simplification n’est vraie qu’en dehors des zéros de g. Chaque facteur
+// commun h est tracé dans la preuve comme point exclu (x ≠ 1, ou “h ≠ 0” en général),
+// dans l’esprit de “indéfini” : on ne fait pas disparaître une division par zéro en silence.
+//
+// IMPORTANT (SAFE):
+// - Factorisation hors budget => quotient laissé tel quel.
+// - Aucun changement si P ou Q n’est pas polynomial (√x, 1/x, sin(x)…).
+//
+// lit_fraction : lecture num/den d’une expression rationnelle (sommes de fractions
+// comprises), utilisée par apart(…) (elements_simples.rs).
+
+use num_rational::BigRational;
+use num_traits::One;
+
+use super::expr::{map_matrice, Expr};
+use super::polynome_multi::{
+    degre_trop_grand, pgcd_multi, PolyMulti, EXPOSANT_MAX, NON_POLYNOMIALE,
+};
+
+/// Simplifie récursivement les quotients de polynômes ; points exclus ajoutés à `preuve`.
+pub fn simplifie_fractions(e: Expr, preuve: &mut String) -> Expr {
+    use Expr::*;
+    let r = |x: Box<Expr>, preuve: &mut String| Box::new(simplifie_fractions(*x, preuve));
+
+    match e {
+        Div(a, b) => {
+            let a = simplifie_fractions(*a, preuve);
+            let b = simplifie_fractions(*b, preuve);
+            match simplifie_quotient(&a, &b, preuve) {
+                Some(q) => q,
+                None => Div(Box::new(a), Box::new(b)),
+            }
+        }
+
+        Add(a, b) => Add(r(a, preuve), r(b, preuve)),
+        Sub(a, b) => Sub(r(a, preuve), r(b, preuve)),
+        Mul(a, b) => Mul(r(a, preuve), r(b, preuve)),
+
+        Sqrt(x) => Sqrt(r(x, preuve)),
+        Root(x, k) => Root(r(x, preuve), k),
+        PowInt(x, n) => PowInt(r(x, preuve), n),
+        Pow(x, y) => Pow(r(x, preuve), r(y, preuve)),
+
+        Sin(x) => Sin(r(x, preuve)),
+        Cos(x) => Cos(r(x, preuve)),
+        Tan(x) => Tan(r(x, preuve)),
+
+        Abs(x) => Abs(r(x, preuve)),
+        Sign(x) => Sign(r(x, preuve)),
+        Floor(x) => Floor(r(x, preuve)),
+        Ceil(x) => Ceil(r(x, preuve)),
+        Round(x) => Round(r(x, preuve)),
+        Frac(x) => Frac(r(x, preuve)),
+
+        Fact(x) => Fact(r(x, preuve)),
+        FactDouble(x) => FactDouble(r(x, preuve)),
+        Binom(n, k) => Binom(r(n, preuve), r(k, preuve)),
+        Arrang(n, k) => Arrang(r(n, preuve), r(k, preuve)),
+
+        Pgcd(a, b) => Pgcd(r(a, preuve), r(b, preuve)),
+        Ppcm(a, b) => Ppcm(r(a, preuve), r(b, preuve)),
+        Modulo(a, m) => Modulo(r(a, preuve), r(m, preuve)),
+        Egal(a, b) => Egal(r(a, preuve), r(b, preuve)),
+        Matrice(l) => map_matrice(l, |x| simplifie_fractions(x, preuve)),
+        Matriciel(op, x) => Matriciel(op, r(x, preuve)),
+
+        Rat(_) | Pi | Indefini | Var(_) => e,
+    }
+}
+
+/// a/b simplifié par pgcd(a, b), ou None si rien à simplifier.
+fn simplifie_quotient(a: &Expr, b: &Expr, preuve: &mut String) -> Option<Expr> {
+    let pa = PolyMulti::depuis_expr(a).ok()?;
+    let pb = PolyMulti::depuis_expr(b).ok()?;
+    if pa.est_zero() || pb.constante_seule().is_some() {
+        return None;
+    }
+
+    let (g, communs) = pgcd_multi(&pa, &pb).ok()?;
+    if g.constante_seule().is_some() {
+        return None;
+    }
+    let num = pa.divise(&g)?;
+    let den = pb.divise(&g)?;
+
+    let resultat = match den.constante_seule() {
+        Some(c) => num.mul_scalaire(&c.recip()).vers_expr(),
+        None => Expr::Div(Box::new(num.vers_expr()), Box::new(den.vers_expr())),
+    };
+
+    let exclus: Vec<String> = communs.iter().map(point_exclu).collect();
+    let simplifie = match den.constante_seule() {
+        Some(c) => num.mul_scalaire(&c.recip()).format(),
+        None => format!("({})/({})", num.format(), den.format()),
+    };
+    preuve.push_str(&format!(
+        "pgcd({}, {}) = {} : ({})/({}) = {} pour {}\n",
+        pa.format(),
+        pb.format(),
+        g.format(),
+        pa.format(),
+        pb.format(),
+        simplifie,
+        exclus.join(", ")
+    ));
+    Some(resultat)
+}
+
+/// Lecture d’une fraction rationnelle : e = num/den (polynômes, sans simplification).
+/// Err(NON_POLYNOMIALE) si e n’est pas rationnelle en ses variables ou si un dénominateur
+/// est nul ; refus de borne (degré, termes) transmis tels quels.
+pub fn lit_fraction(e: &Expr) -> Result<(PolyMulti, PolyMulti), String> {
+    use Expr::*;
+    let un = || PolyMulti::constante(BigRational::one());
+    let refus = || NON_POLYNOMIALE.to_string();
+    match e {
+        Add(a, b) | Sub(a, b) => {
+            let (na, da) = lit_fraction(a)?;
+            let (nb, db) = lit_fraction(b)?;
+            let (x, y) = (na.mul(&db), nb.mul(&da));
+            let num = if matches!(e, Add(_, _)) {
+                x.add(&y)
+            } else {
+                x.sub(&y)
+            };
+            Ok((num, da.mul(&db)))
+        }
+        Mul(a, b) => {
+            let (na, da) = lit_fraction(a)?;
+            let (nb, db) = lit_fraction(b)?;
+            Ok((na.mul(&nb), da.mul(&db)))
+        }
+        Div(a, b) => {
+            let (na, da) = lit_fraction(a)?;
+            let (nb, db) = lit_fraction(b)?;
+            if nb.est_zero() {
+                return Err(refus());
+            }
+            Ok((na.mul(&db), da.mul(&nb)))
+        }
+        PowInt(a, n) if n.unsigned_abs() <= EXPOSANT_MAX.unsigned_abs() => {
+            let (na, da) = lit_fraction(a)?;
+            let k = n.unsigned_abs() as u32;
+            let (na, da) = na.puissance(k).zip(da.puissance(k)).ok_or_else(refus)?;
+            if *n >= 0 {
+                Ok((na, da))
+            } else if na.est_zero() {
+                Err(refus())
+            } else {
+                Ok((da, na))
+            }
+        }
+        PowInt(a, n) => {
+            // base rationnelle : c’est la borne qui refuse, on le dit
+            lit_fraction(a)?;
+            Err(degre_trop_grand(*n))
+        }
+        Rat(_) | Var(_) => Ok((PolyMulti::depuis_expr(e)?, un())),
+        _ => Err(refus()),
+    }
+}
+
+/// Condition d’exclusion d’un facteur commun : “x ≠ r” si h = a·x + b, sinon “h ≠ 0”.
+fn point_exclu(h: &PolyMulti) -> String {
+    if let [v] = h.variables().as_slice() {
+        if h.degre_en(v) == 1 {
+            let pente = h.coefficient(&[(v.clone(), 1)]);
+            let r = -h.coefficient(&[]) / pente;
+            return format!("{v} ≠ {r}");
+        }
+    }
+    format!("{} ≠ 0", h.format())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::noyau::eval::forme_exacte;
+    use crate::noyau::format::format_expr_pretty;
+
+    fn simplifie(s: &str) -> (String, String) {
+        let (e, d) = forme_exacte(s).unwrap();
+        (format_expr_pretty(&e), d.preuve)
+    }
+
+    #[test]
+    fn quotients_simplifies() {
+        let (e, preuve) = simplifie("(x^2-1)/(x-1)");
+        assert_eq!(e, "(1+x)");
+        assert!(preuve.contains("x ≠ 1"), "{preuve}");
+
+        let (e, preuve) = simplifie("(x^2-y^2)/(x^2+2*x*y+y^2)");
+        assert!(e.contains('/'), "{e}");
+        assert!(preuve.contains("x+y ≠ 0"), "{preuve}");
+
+        // rien à simplifier
+        let (_, preuve) = simplifie("(x^2+1)/(x-1)");
+        assert!(!preuve.contains("pgcd"));
+    }
+}
@@ -37,7 +37,7 @@
 // IMPORTANT : B7 (développement) est volontairement évité : ça GROSSIT l’arbre.
 //
 
-use crate::noyau::expr::Expr;
+use crate::noyau::expr::{map_matrice, Expr};
 use num_rational::BigRational;
 use num_traits::{One, Zero};
 
@@ -164,7 +164,31 @@ fn rewrite_once(e: Expr) -> Expr {
 
         // --- sqrt / pow : descente ---
         Sqrt(x) => Sqrt(Box::new(rewrite_once(*x))),
+        Root(x, k) => Root(Box::new(rewrite_once(*x)), k),
         PowInt(x, n) => PowInt(Box::new(rewrite_once(*x)), n),
+        Pow(x, e) => Pow(Box::new(rewrite_once(*x)), Box::new(rewrite_once(*e))),
+
+        // --- abs / arrondis : descente ---
+        Abs(x) => Abs(Box::new(rewrite_once(*x))),
+        Sign(x) => Sign(Box::new(rewrite_once(*x))),
+        Floor(x) => Floor(Box::new(rewrite_once(*x))),
+        Ceil(x) => Ceil(Box::new(rewrite_once(*x))),
+        Round(x) => Round(Box::new(rewrite_once(*x))),
+        Frac(x) => Frac(Box::new(rewrite_once(*x))),
+
+        // --- combinatoire : descente ---
+        Fact(x) => Fact(Box::new(rewrite_once(*x))),
+        FactDouble(x) => FactDouble(Box::new(rewrite_once(*x))),
+        Binom(n, k) => Binom(Box::new(rewrite_once(*n)), Box::new(rewrite_once(*k))),
+        Arrang(n, k) => Arrang(Box::new(rewrite_once(*n)), Box::new(rewrite_once(*k))),
+
+        // --- arithmétique entière : descente ---
+        Pgcd(a, b) => Pgcd(Box::new(rewrite_once(*a)), Box::new(rewrite_once(*b))),
+        Ppcm(a, b) => Ppcm(Box::new(rewrite_once(*a)), Box::new(rewrite_once(*b))),
+        Modulo(a, m) => Modulo(Box::new(rewrite_once(*a)), Box::new(rewrite_once(*m))),
+        Egal(a, b) => Egal(Box::new(rewrite_once(*a)), Box::new(rewrite_once(*b))),
+        Matrice(l) => map_matrice(l, rewrite_once),
+        Matriciel(op, x) => Matriciel(op, Box::new(rewrite_once(*x))),
 
         // --- binaires : descente puis règles structurales ---
         Add(a, b) => {
@@ -236,16 +260,46 @@ fn score(e: &Expr) -> (usize, usize) {
                 (n + 1, d + 1)
             }
 
-            PowInt(x, _) => {
+            Abs(x) | Sign(x) | Floor(x) | Ceil(x) | Round(x) | Frac(x) => {
                 let (n, d) = walk(x);
                 (n + 1, d + 1)
             }
 
-            Add(a, b) | Sub(a, b) | Mul(a, b) | Div(a, b) => {
+            Fact(x) | FactDouble(x) => {
+                let (n, d) = walk(x);
+                (n + 1, d + 1)
+            }
+
+            PowInt(x, _) | Root(x, _) => {
+                let (n, d) = walk(x);
+                (n + 1, d + 1)
+            }
+
+            Add(a, b)
+            | Sub(a, b)
+            | Mul(a, b)
+            | Div(a, b)
+            | Pow(a, b)
+            | Binom(a, b)
+            | Arrang(a, b)
+            | Pgcd(a, b)
+            | Ppcm(a, b)
+            | Modulo(a, b)
+            | Egal(a, b) => {
                 let (na, da) = walk(a);
                 let (nb, db) = walk(b);
                 (na + nb + 1, 1 + da.max(db))
             }
+
+            Matriciel(_, x) => {
+                let (n, d) = walk(x);
+                (n + 1, d + 1)
+            }
+            Matrice(l) => l
+                .iter()
+                .flatten()
+                .map(walk)
+                .fold((1, 1), |(n, d), (nx, dx)| (n + nx, d.max(1 + dx))),
         }
     }
     walk(e)
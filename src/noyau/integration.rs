@@ -0,0 +1,646 @@
+// src/noyau/integration.rs
+//
+// Primitives et intégrales définies : integrate(sin(x), x, 0, pi) => 2
+// --------------------------------------------------------------------
+// Règles (une par nœud, tracées), u = a·x + b désignant un argument linéaire (a constant ≠ 0) :
+// - constante : ∫k dx = k·x ; linéarité : ∫(u ± v) = ∫u ± ∫v, ∫k·u = k·∫u, ∫u/k = (∫u)/k
+// - puissances : ∫u^n = u^(n+1)/((n+1)·a) (n ≠ -1), ∫√u, ∫ⁿ√u, ∫u^c (c constant ≠ -1)
+// - trigonométrie : ∫sin u = -cos(u)/a, ∫cos u = sin(u)/a, ∫sin²u, ∫cos²u, ∫tan²u, ∫1/cos²u
+// - substitution : ∫f(g(x))·k·g'(x) dx = k·F(g(x)) (k constant, F primitive de f)
+// - polynômes à coefficients rationnels : terme à terme
+// Définie : F(b) - F(a), repassée par le pipeline (trig spéciale : cos(π) = -1…).
+//
+// IMPORTANT (SAFE):
+// - Pas de logarithme dans le noyau : ∫1/(a·x+b), ∫tan u… => erreur explicite.
+// - Aucune règle applicable => erreur (jamais de primitive devinée).
+// - Intégrale définie : continuité de l’intégrande PROUVÉE sur [a, b] (dénominateurs sans
+//   zéro par Sturm ou par encadrement de cos/sin, radicandes positifs) ; sinon erreur.
+
+use std::convert::Infallible;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+use super::canon::rapport_rationnel;
+use super::derivation::{contient_indefini, depend, derive, groupe, nu, variables};
+use super::eval::forme_normale;
+use super::expr::Expr;
+use super::format::format_expr_pretty;
+use super::isolation::SuiteSturm;
+use super::nullite::{nullite, Nullite};
+use super::polynome::Polynome;
+use super::polynome_multi::{vers_univarie, PolyMulti};
+use super::signe::{encadrement_rationnel, signe_certifie};
+
+/// Chiffres des encadrements des bornes (continuité).
+const DIGITS_BORNES: usize = 30;
+
+fn b(e: Expr) -> Box<Expr> {
+    Box::new(e)
+}
+
+fn rat(n: i64, d: i64) -> Expr {
+    Expr::Rat(BigRational::new(BigInt::from(n), BigInt::from(d)))
+}
+
+fn reduit(e: Expr) -> Expr {
+    forme_normale(e, &mut String::new())
+}
+
+fn p(e: &Expr) -> String {
+    groupe(format_expr_pretty(e))
+}
+
+/// Pente a de u = a·x + b (a constant, non nul prouvé), None sinon.
+fn pente(u: &Expr, x: &str) -> Option<Expr> {
+    let a = reduit(derive(u, x, &mut String::new()).ok()?);
+    (!depend(&a, x) && nullite(&a) == Nullite::NonNul).then_some(a)
+}
+
+/// e où la variable x est remplacée par v.
+pub fn remplace(e: Expr, x: &str, v: &Expr) -> Expr {
+    match e {
+        Expr::Var(ref y) if y == x => v.clone(),
+        autre => autre
+            .map_enfants(&mut |c| Ok::<_, Infallible>(remplace(c, x, v)))
+            .unwrap_or_else(|e| match e {}),
+    }
+}
+
+fn est_un(a: &Expr) -> bool {
+    matches!(a, Expr::Rat(r) if r.is_one())
+}
+
+/// Dénominateur “(n·a)” de la trace, “n” si a = 1.
+fn fois_pente(n: impl std::fmt::Display, a: &Expr) -> String {
+    let n = n.to_string();
+    if est_un(a) && !n.starts_with('-') {
+        n
+    } else if est_un(a) {
+        format!("({n})")
+    } else {
+        format!("({n}·{})", p(a))
+    }
+}
+
+/// Facteur “k·” de la trace, rien si k = 1.
+fn facteur(k: &Expr) -> String {
+    if est_un(k) {
+        String::new()
+    } else {
+        format!("{}·", p(k))
+    }
+}
+
+/// “/a” dans la trace, rien si a = 1.
+fn sur_pente(a: &Expr) -> String {
+    if est_un(a) {
+        String::new()
+    } else {
+        format!("/{}", p(a))
+    }
+}
+
+fn sans_logarithme(f: &str, x: &str) -> String {
+    format!("integrate : ∫{f} d{x} demande un logarithme (non disponible)")
+}
+
+/* ------------------------ Primitives ------------------------ */
+
+/// Primitive brute de e par rapport à x (non simplifiée) ; règles tracées dans `preuve`.
+pub fn primitive(e: &Expr, x: &str, preuve: &mut String) -> Result<Expr, String> {
+    use Expr::*;
+
+    if contient_indefini(e) {
+        return Err("integrate : valeur indéfinie".into());
+    }
+    if matches!(e, Egal(_, _) | Matrice(_) | Matriciel(_, _)) {
+        return Err("integrate : expression scalaire attendue".into());
+    }
+    let f = p(e);
+    if !depend(e, x) {
+        preuve.push_str(&format!("constante : ∫{f} d{x} = {f}·{x}\n"));
+        return Ok(Mul(b(e.clone()), b(Var(x.into()))));
+    }
+
+    let directe = match e {
+        Var(_) => {
+            preuve.push_str(&format!("puissance : ∫{x} d{x} = {x}^2/2\n"));
+            Some(Div(b(PowInt(b(e.clone()), 2)), b(rat(2, 1))))
+        }
+        Add(u, v) | Sub(u, v) => {
+            let signe = if matches!(e, Add(_, _)) { '+' } else { '-' };
+            preuve.push_str(&format!(
+                "linéarité : ∫{f} d{x} = ∫{} d{x} {signe} ∫{} d{x}\n",
+                p(u),
+                p(v)
+            ));
+            let (pu, pv) = (primitive(u, x, preuve)?, primitive(v, x, preuve)?);
+            Some(match e {
+                Add(_, _) => Add(b(pu), b(pv)),
+                _ => Sub(b(pu), b(pv)),
+            })
+        }
+        Mul(k, u) | Mul(u, k) if !depend(k, x) => {
+            preuve.push_str(&format!(
+                "facteur constant : ∫{f} d{x} = {}·∫{} d{x}\n",
+                p(k),
+                p(u)
+            ));
+            Some(Mul(k.clone(), b(primitive(u, x, preuve)?)))
+        }
+        Div(u, k) if !depend(k, x) => {
+            preuve.push_str(&format!(
+                "facteur constant : ∫{f} d{x} = (∫{} d{x})/{}\n",
+                p(u),
+                p(k)
+            ));
+            Some(Div(b(primitive(u, x, preuve)?), k.clone()))
+        }
+        Div(k, v) if !depend(k, x) => inverse(k, v, x, preuve)?,
+        PowInt(u, n) => puissance(u, *n, x, preuve)?,
+        Sqrt(u) => pente(u, x).map(|a| {
+            preuve.push_str(&format!(
+                "puissance 1/2 : ∫{f} d{x} = 2·{}·{f}/{}\n",
+                p(u),
+                fois_pente(3, &a)
+            ));
+            Div(
+                b(Mul(b(Mul(b(rat(2, 1)), u.clone())), b(e.clone()))),
+                b(Mul(b(rat(3, 1)), b(a))),
+            )
+        }),
+        Root(u, k) => pente(u, x).map(|a| {
+            preuve.push_str(&format!(
+                "puissance 1/{k} : ∫{f} d{x} = {k}·{}·{f}/{}\n",
+                p(u),
+                fois_pente(k + 1, &a)
+            ));
+            let k = *k as i64;
+            Div(
+                b(Mul(b(Mul(b(rat(k, 1)), u.clone())), b(e.clone()))),
+                b(Mul(b(rat(k + 1, 1)), b(a))),
+            )
+        }),
+        Pow(u, c) if !depend(c, x) => match pente(u, x) {
+            Some(a) => {
+                let c1 = reduit(Add(c.clone(), b(rat(1, 1))));
+                if nullite(&c1) != Nullite::NonNul {
+                    return Err(sans_logarithme(&f, x));
+                }
+                preuve.push_str(&format!(
+                    "puissance : ∫{f} d{x} = {}^{}/{}\n",
+                    p(u),
+                    p(&c1),
+                    fois_pente(p(&c1), &a)
+                ));
+                Some(Div(b(Pow(u.clone(), b(c1.clone()))), b(Mul(b(c1), b(a)))))
+            }
+            None => None,
+        },
+        Sin(u) => pente(u, x).map(|a| {
+            preuve.push_str(&format!(
+                "trigonométrie : ∫{f} d{x} = -cos({}){}\n",
+                nu(format_expr_pretty(u)),
+                sur_pente(&a)
+            ));
+            Div(b(Mul(b(rat(-1, 1)), b(Cos(u.clone())))), b(a))
+        }),
+        Cos(u) => pente(u, x).map(|a| {
+            preuve.push_str(&format!(
+                "trigonométrie : ∫{f} d{x} = sin({}){}\n",
+                nu(format_expr_pretty(u)),
+                sur_pente(&a)
+            ));
+            Div(b(Sin(u.clone())), b(a))
+        }),
+        Tan(u) if pente(u, x).is_some() => return Err(sans_logarithme(&f, x)),
+        _ => None,
+    };
+    if let Some(r) = directe {
+        return Ok(r);
+    }
+    let echec_substitution = match substitution(e, x, preuve) {
+        Ok(Some(r)) => return Ok(r),
+        Ok(None) => None,
+        Err(err) => Some(err),
+    };
+    if let Some(r) = polynome(e, x, preuve) {
+        return Ok(r);
+    }
+    Err(echec_substitution.unwrap_or_else(|| {
+        format!(
+            "integrate : aucune règle pour ∫{f} d{x} (polynômes, puissances et sin/cos d’argument linéaire, substitution)"
+        )
+    }))
+}
+
+/// ∫u^n : argument linéaire, carrés trigonométriques, 1/cos²u.
+fn puissance(u: &Expr, n: i64, x: &str, preuve: &mut String) -> Result<Option<Expr>, String> {
+    use Expr::*;
+    let f = format!("{}^{n}", p(u));
+    if let Some(a) = pente(u, x) {
+        if n == -1 {
+            return Err(sans_logarithme(&f, x));
+        }
+        preuve.push_str(&format!(
+            "puissance : ∫{f} d{x} = {}^{}/{}\n",
+            p(u),
+            n + 1,
+            fois_pente(n + 1, &a)
+        ));
+        let den = Mul(b(rat(n + 1, 1)), b(a));
+        return Ok(Some(Div(b(PowInt(b(u.clone()), n + 1)), b(den))));
+    }
+
+    let (w, a) = match u {
+        Sin(w) | Cos(w) | Tan(w) => match pente(w, x) {
+            Some(a) => (w, a),
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let vx = || b(Var(x.into()));
+    // sin(2w)/(4a)
+    let sin_double = || {
+        Div(
+            b(Sin(b(Mul(b(rat(2, 1)), w.clone())))),
+            b(Mul(b(rat(4, 1)), b(a.clone()))),
+        )
+    };
+    let (formule, r) = match (u, n) {
+        (Sin(_), 2) => (
+            format!("{x}/2 - sin(2·{})/{}", p(w), fois_pente(4, &a)),
+            Sub(b(Div(vx(), b(rat(2, 1)))), b(sin_double())),
+        ),
+        (Cos(_), 2) => (
+            format!("{x}/2 + sin(2·{})/{}", p(w), fois_pente(4, &a)),
+            Add(b(Div(vx(), b(rat(2, 1)))), b(sin_double())),
+        ),
+        (Tan(_), 2) => (
+            format!("tan({}){} - {x}", nu(format_expr_pretty(w)), sur_pente(&a)),
+            Sub(b(Div(b(Tan(w.clone())), b(a.clone()))), vx()),
+        ),
+        (Cos(_), -2) => (
+            format!("tan({}){}", nu(format_expr_pretty(w)), sur_pente(&a)),
+            Div(b(Tan(w.clone())), b(a.clone())),
+        ),
+        _ => return Ok(None),
+    };
+    preuve.push_str(&format!("trigonométrie : ∫{f} d{x} = {formule}\n"));
+    Ok(Some(r))
+}
+
+/// ∫k/v : v = u^n, √u, ⁿ√u (u linéaire) ; k/u => logarithme (erreur).
+fn inverse(k: &Expr, v: &Expr, x: &str, preuve: &mut String) -> Result<Option<Expr>, String> {
+    use Expr::*;
+    let f = format!("{}/{}", p(k), p(v));
+    let r = match v {
+        PowInt(u, n) => puissance(u, -n, x, preuve)?,
+        Sqrt(u) => pente(u, x).map(|a| {
+            preuve.push_str(&format!(
+                "puissance -1/2 : ∫{f} d{x} = 2·{}{}{}\n",
+                facteur(k),
+                p(v),
+                sur_pente(&a)
+            ));
+            Div(b(Mul(b(rat(2, 1)), b(v.clone()))), b(a))
+        }),
+        Root(u, n) if *n >= 2 => pente(u, x).map(|a| {
+            let n = *n as i64;
+            preuve.push_str(&format!(
+                "puissance -1/{n} : ∫{f} d{x} = {n}·{}{}^{}/{}\n",
+                facteur(k),
+                p(v),
+                n - 1,
+                fois_pente(n - 1, &a)
+            ));
+            Div(
+                b(Mul(b(rat(n, 1)), b(PowInt(b(v.clone()), n - 1)))),
+                b(Mul(b(rat(n - 1, 1)), b(a))),
+            )
+        }),
+        _ if pente(v, x).is_some() => return Err(sans_logarithme(&f, x)),
+        _ => None,
+    };
+    Ok(r.map(|r| Mul(b(k.clone()), b(r))))
+}
+
+/// ∫F(g(x))·c(x) dx avec c = k·g' (k constant) : t = g(x), k·∫F(t) dt.
+/// Err : substitution trouvée mais ∫F(t) dt hors d’atteinte (logarithme…).
+fn substitution(e: &Expr, x: &str, preuve: &mut String) -> Result<Option<Expr>, String> {
+    use Expr::*;
+    let un = || b(rat(1, 1));
+    let candidats: Vec<(Expr, Expr)> = match e {
+        Mul(u, v) => vec![(*u.clone(), *v.clone()), (*v.clone(), *u.clone())],
+        Div(u, v) => vec![
+            (Div(un(), v.clone()), *u.clone()),
+            (*u.clone(), Div(un(), v.clone())),
+        ],
+        _ => return Ok(None),
+    };
+    let vars = variables(e);
+    let Some(t) = ["t", "u", "w", "s"]
+        .into_iter()
+        .find(|t| *t != x && !vars.iter().any(|v| v == t))
+    else {
+        return Ok(None);
+    };
+    let vt = Var(t.into());
+    let mut erreur = None;
+
+    for (compose, cofacteur) in candidats {
+        for (g, externe) in decompositions(&compose, &vt, x) {
+            if !depend(&g, x) {
+                continue;
+            }
+            let Ok(dg) = derive(&g, x, &mut String::new()) else {
+                continue;
+            };
+            // g' peut contenir x : seule la nullité identique est écartée
+            let dg = reduit(dg);
+            if matches!(&dg, Rat(r) if r.is_zero()) {
+                continue;
+            }
+            // k = cofacteur / g' : rapport rationnel par structure (sin·cos² contre
+            // g' = -sin, sans annulation symbolique), sinon quotient réduit
+            let k = match rapport_rationnel(&cofacteur, &dg) {
+                Some(c) => Rat(c),
+                None => reduit(Div(b(cofacteur.clone()), b(dg.clone()))),
+            };
+            if depend(&k, x) {
+                continue;
+            }
+            let mut sous_preuve = String::new();
+            let primitive_t = match primitive(&externe, t, &mut sous_preuve) {
+                Ok(r) => r,
+                Err(err) => {
+                    erreur.get_or_insert(err);
+                    continue;
+                }
+            };
+            preuve.push_str(&format!(
+                "substitution : {t} = {}, d{t} = {}·d{x} ; ∫{} d{x} = {}∫{} d{t}\n{sous_preuve}",
+                nu(format_expr_pretty(&g)),
+                p(&dg),
+                p(e),
+                facteur(&k),
+                p(&externe)
+            ));
+            return Ok(Some(Mul(b(k), b(remplace(primitive_t, t, &g)))));
+        }
+    }
+    erreur.map_or(Ok(None), Err)
+}
+
+/// Écritures F = f(g) : (g, f(t)) pour l’argument de F, et (F, t) ; f ne dépend pas de x.
+fn decompositions(facteur: &Expr, t: &Expr, x: &str) -> Vec<(Expr, Expr)> {
+    use Expr::*;
+    let vt = || b(t.clone());
+    let mut out = match facteur {
+        PowInt(g, n) => vec![(*g.clone(), PowInt(vt(), *n))],
+        Sqrt(g) => vec![(*g.clone(), Sqrt(vt()))],
+        Root(g, k) => vec![(*g.clone(), Root(vt(), *k))],
+        Pow(g, c) if !depend(c, x) => vec![(*g.clone(), Pow(vt(), c.clone()))],
+        Sin(g) => vec![(*g.clone(), Sin(vt()))],
+        Cos(g) => vec![(*g.clone(), Cos(vt()))],
+        Tan(g) => vec![(*g.clone(), Tan(vt()))],
+        Div(k, v) if !depend(k, x) => decompositions(v, t, x)
+            .into_iter()
+            .map(|(g, f)| (g, Div(k.clone(), b(f))))
+            .collect(),
+        _ => vec![],
+    };
+    if !matches!(facteur, Var(_) | Div(_, _)) {
+        out.push((facteur.clone(), t.clone()));
+    }
+    out
+}
+
+/// Polynôme en x à coefficients rationnels : ∫Σ c_k·x^k = Σ c_k·x^(k+1)/(k+1).
+fn polynome(e: &Expr, x: &str, preuve: &mut String) -> Option<Expr> {
+    let q = vers_univarie(&PolyMulti::depuis_expr(e).ok()?, x)?;
+    let mut coeffs = vec![BigRational::zero()];
+    coeffs.extend(
+        q.coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c / BigRational::from_integer(BigInt::from(k + 1))),
+    );
+    let r = Polynome::nouveau(coeffs);
+    preuve.push_str(&format!(
+        "polynôme : ∫({}) d{x} = {}\n",
+        q.format(x),
+        r.format(x)
+    ));
+    let termes = r
+        .coeffs
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.is_zero())
+        .map(|(k, c)| {
+            Expr::Mul(
+                b(Expr::Rat(c.clone())),
+                b(Expr::PowInt(b(Expr::Var(x.into())), k as i64)),
+            )
+        });
+    termes.reduce(|a, c| Expr::Add(b(a), b(c)))
+}
+
+/* ------------------------ Intégrale définie ------------------------ */
+
+/// ∫_a^b f dx = F(b) - F(a), après preuve de la continuité de f sur [a, b].
+pub fn integrale_definie(
+    f: &Expr,
+    primitive: &Expr,
+    x: &str,
+    a: &Expr,
+    b_: &Expr,
+    preuve: &mut String,
+) -> Result<Expr, String> {
+    let bornes = Bornes::nouvelles(a, b_)?;
+    verifie_continuite(f, x, &bornes)?;
+    preuve.push_str(&format!(
+        "continuité de {} prouvée sur [{}, {}]\n",
+        p(f),
+        format_expr_pretty(a),
+        format_expr_pretty(b_)
+    ));
+    let fb = remplace(primitive.clone(), x, b_);
+    let fa = remplace(primitive.clone(), x, a);
+    preuve.push_str(&format!(
+        "∫ = F({}) - F({}) avec F({x}) = {}\n",
+        format_expr_pretty(b_),
+        format_expr_pretty(a),
+        format_expr_pretty(primitive)
+    ));
+    Ok(forme_normale(Expr::Sub(b(fb), b(fa)), preuve))
+}
+
+/// Bornes exactes (a, b) et intervalle rationnel [bas, haut] qui les contient.
+struct Bornes<'a> {
+    a: &'a Expr,
+    b: &'a Expr,
+    bas: BigRational,
+    haut: BigRational,
+    exactes: bool,
+}
+
+impl<'a> Bornes<'a> {
+    fn nouvelles(a: &'a Expr, b: &'a Expr) -> Result<Self, String> {
+        let encadre = |e: &Expr| match e {
+            Expr::Rat(r) => Ok((r.clone(), r.clone())),
+            _ => encadrement_rationnel(e, DIGITS_BORNES)
+                .ok_or_else(|| format!("integrate : borne non réelle ({})", format_expr_pretty(e))),
+        };
+        let (ab, ah) = encadre(a)?;
+        let (bb, bh) = encadre(b)?;
+        Ok(Bornes {
+            a,
+            b,
+            bas: ab.min(bb),
+            haut: ah.max(bh),
+            exactes: matches!((a, b), (Expr::Rat(_), Expr::Rat(_))),
+        })
+    }
+}
+
+fn verifie_continuite(f: &Expr, x: &str, bornes: &Bornes) -> Result<(), String> {
+    use Expr::*;
+    let echec = |detail: String| {
+        Err(format!(
+            "integrate : continuité non prouvée sur [{}, {}] ({detail})",
+            format_expr_pretty(bornes.a),
+            format_expr_pretty(bornes.b)
+        ))
+    };
+    match f {
+        Div(_, q) if depend(q, x) && !non_nul(q, x, bornes) => {
+            return echec(format!("{} peut s’annuler", format_expr_pretty(q)))
+        }
+        PowInt(u, n) if *n < 0 && depend(u, x) && !non_nul(u, x, bornes) => {
+            return echec(format!("{} peut s’annuler", format_expr_pretty(u)))
+        }
+        Tan(u) if !non_nul(&Cos(u.clone()), x, bornes) => {
+            return echec(format!("cos({}) peut s’annuler", format_expr_pretty(u)))
+        }
+        Sqrt(u) if depend(u, x) && !positif(u, x, bornes, true) => {
+            return echec(format!("{} peut être négatif", format_expr_pretty(u)))
+        }
+        Root(u, k) if k % 2 == 0 && depend(u, x) && !positif(u, x, bornes, true) => {
+            return echec(format!("{} peut être négatif", format_expr_pretty(u)))
+        }
+        Pow(u, c) if depend(u, x) => {
+            let large = signe_certifie(c) == Some(std::cmp::Ordering::Greater);
+            if !positif(u, x, bornes, large) {
+                return echec(format!("{} doit rester positif", format_expr_pretty(u)));
+            }
+        }
+        _ => {}
+    }
+    f.enfants()
+        .into_iter()
+        .try_for_each(|e| verifie_continuite(e, x, bornes))
+}
+
+fn univarie(e: &Expr, x: &str) -> Option<Polynome> {
+    vers_univarie(&PolyMulti::depuis_expr(e).ok()?, x).filter(|p| !p.est_zero())
+}
+
+/// q ≠ 0 sur [a, b] : polynôme sans racine (Sturm), facteurs, ou sin/cos d’argument linéaire.
+fn non_nul(q: &Expr, x: &str, bornes: &Bornes) -> bool {
+    use Expr::*;
+    if !depend(q, x) {
+        return nullite(q) == Nullite::NonNul;
+    }
+    if let Some(poly) = univarie(q, x) {
+        let sturm = SuiteSturm::nouvelle(&poly);
+        return sturm.nb_racines(&bornes.bas, &bornes.haut) == 0
+            && !poly.evalue(&bornes.bas).is_zero();
+    }
+    match q {
+        PowInt(u, _) | Sqrt(u) | Root(u, _) => non_nul(u, x, bornes),
+        Mul(u, v) => non_nul(u, x, bornes) && non_nul(v, x, bornes),
+        Div(u, _) => non_nul(u, x, bornes),
+        // zéros de cos : u = π/2 + kπ ; de sin : u = kπ
+        Cos(u) => hors_multiples_de_pi(u, rat(1, 2), x, bornes),
+        Sin(u) => hors_multiples_de_pi(u, rat(0, 1), x, bornes),
+        _ => false,
+    }
+}
+
+/// (u - φ·π)/π reste strictement dans un même ]n, n+1[ quand x parcourt [a, b] (u linéaire).
+fn hors_multiples_de_pi(u: &Expr, phi: Expr, x: &str, bornes: &Bornes) -> bool {
+    use Expr::*;
+    if pente(u, x).is_none() {
+        return false;
+    }
+    let t = |borne: &Expr| {
+        let ub = remplace(u.clone(), x, borne);
+        let decale = Sub(b(ub), b(Mul(b(phi.clone()), b(Pi))));
+        encadrement_rationnel(&reduit(Div(b(decale), b(Pi))), DIGITS_BORNES)
+    };
+    let (Some((ab, ah)), Some((bb, bh))) = (t(bornes.a), t(bornes.b)) else {
+        return false;
+    };
+    let (bas, haut) = (ab.min(bb), ah.max(bh));
+    let n = bas.floor();
+    bas > n && haut < n + BigRational::one()
+}
+
+/// u ≥ 0 (large) ou u > 0 sur [a, b] : u polynôme, sans racine intérieure, positif au milieu.
+fn positif(u: &Expr, x: &str, bornes: &Bornes, large: bool) -> bool {
+    let Some(poly) = univarie(u, x) else {
+        return !depend(u, x) && signe_certifie(u) == Some(std::cmp::Ordering::Greater);
+    };
+    let (bas, haut) = (&bornes.bas, &bornes.haut);
+    let aux_bornes = |r: &BigRational| poly.evalue(r).is_zero();
+    // racines dans ]bas, haut[ (Sturm compte ]bas, haut])
+    let interieures =
+        SuiteSturm::nouvelle(&poly).nb_racines(bas, haut) - usize::from(aux_bornes(haut));
+    if interieures > 0 {
+        return false;
+    }
+    if (aux_bornes(bas) || aux_bornes(haut)) && !(large && bornes.exactes) {
+        return false;
+    }
+    let milieu = (bas + haut) / BigRational::from_integer(BigInt::from(2));
+    poly.evalue(&milieu).is_positive()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::noyau::eval::eval_expression;
+
+    fn exact(s: &str) -> String {
+        eval_expression(s, 10).unwrap().0
+    }
+
+    #[test]
+    fn continuite_prouvee_ou_refusee() {
+        assert_eq!(exact("integrate(1/x^2, x, 1, 2)"), "1/2");
+        assert_eq!(exact("integrate(sqrt(x), x, 0, 1)"), "2/3");
+        assert_eq!(exact("integrate(1/cos(x)^2, x, 0, pi/4)"), "1");
+        // pôle dans l’intervalle : F(b) - F(a) serait faux (-2)
+        assert!(eval_expression("integrate(1/x^2, x, -1, 1)", 10).is_err());
+        assert!(eval_expression("integrate(tan(x)^2, x, 0, pi)", 10).is_err());
+        assert!(eval_expression("integrate(sqrt(x), x, -1, 1)", 10).is_err());
+    }
+
+    #[test]
+    fn substitution_trigonometrique() {
+        // cofacteur = k·g' reconnu par structure (pas d’annulation sin/sin)
+        assert_eq!(
+            exact("integrate(sin(x)*cos(x)^2, x)"),
+            "(-1/3*(cos(x))^3) + C"
+        );
+        assert_eq!(exact("integrate(cos(x)*sin(x)^2, x)"), "(sin(x))^3/3 + C");
+        assert_eq!(exact("integrate(sin(x)*cos(x), x)"), "(sin(x))^2/2 + C");
+        assert_eq!(
+            exact("integrate(3*sin(2*x)*cos(2*x)^2, x)"),
+            "(-1/2*(cos((2*x)))^3) + C"
+        );
+        assert_eq!(exact("integrate(sin(x)*cos(x), x, 0, pi/2)"), "1/2");
+    }
+}
@@ -0,0 +1,300 @@
+// src/noyau/isolation.rs
+//
+// Isolation des racines réelles (suite de Sturm)
+// ----------------------------------------------
+// p sans carré, coefficients rationnels :
+//   S_0 = p, S_1 = p', S_{k+1} = -rem(S_{k-1}, S_k)
+//   nombre de racines dans ]a, b] = V(a) - V(b)  (V : changements de signe de la suite)
+// 1) borne de Cauchy B = 1 + max |a_i / a_n| : toutes les racines dans ]-B, B[
+// 2) bissection jusqu’à une racine par intervalle (racine rationnelle au milieu : exacte)
+// 3) affinage par signe de p à la borne droite, puis encadrement décimal à k chiffres
+//    (bornes arrondies vers l’extérieur, unicité revérifiée par Sturm)
+// 4) lecture ΣLocal certifiée : bissection jusqu’à ce que les troncatures à `digits`
+//    chiffres des deux bornes coïncident (chaque chiffre affiché est garanti)
+//
+// IMPORTANT (SAFE):
+// - Arithmétique exacte sur Q : un encadrement rendu contient exactement une racine.
+// - Aucun flottant, aucune “racine approchée” sans intervalle certifié.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::cmp::Ordering;
+
+use super::lecture::scaled_to_decimal;
+use super::polynome::Polynome;
+
+/// Encadrement ]bas, haut[ d’une racine réelle (bas = haut : racine rationnelle exacte).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Encadrement {
+    pub bas: BigRational,
+    pub haut: BigRational,
+}
+
+/* ------------------------ Suite de Sturm ------------------------ */
+
+pub struct SuiteSturm {
+    suite: Vec<Polynome>,
+}
+
+impl SuiteSturm {
+    pub fn nouvelle(p: &Polynome) -> Self {
+        let mut suite = vec![p.clone(), p.derivee()];
+        while !suite.last().unwrap().est_zero() {
+            let n = suite.len();
+            let r = suite[n - 2].rem(&suite[n - 1]);
+            suite.push(r.mul_scalaire(&-BigRational::one()));
+        }
+        suite.pop();
+        SuiteSturm { suite }
+    }
+
+    /// V(x) : changements de signe de (S_0(x), S_1(x), …), zéros ignorés.
+    fn variations(&self, x: &BigRational) -> usize {
+        let mut n = 0;
+        let mut precedent = Ordering::Equal;
+        for s in &self.suite {
+            let v = s.evalue(x).cmp(&BigRational::zero());
+            if v == Ordering::Equal {
+                continue;
+            }
+            if precedent != Ordering::Equal && v != precedent {
+                n += 1;
+            }
+            precedent = v;
+        }
+        n
+    }
+
+    /// Nombre de racines distinctes dans ]a, b].
+    pub fn nb_racines(&self, a: &BigRational, b: &BigRational) -> usize {
+        self.variations(a).saturating_sub(self.variations(b))
+    }
+}
+
+/* ------------------------ Isolation ------------------------ */
+
+/// Borne de Cauchy : |racine| < 1 + max |a_i / a_n|.
+fn borne_cauchy(p: &Polynome) -> BigRational {
+    let an = p.dominant();
+    let m = p.coeffs[..p.degre()]
+        .iter()
+        .map(|c| (c / &an).abs())
+        .max()
+        .unwrap_or_else(BigRational::zero);
+    m + BigRational::one()
+}
+
+/// Racines réelles de p (sans carré, degré ≥ 1), isolées et triées.
+pub fn isole_racines(p: &Polynome) -> Vec<Encadrement> {
+    let sturm = SuiteSturm::nouvelle(p);
+    let b = borne_cauchy(p);
+    let deux = BigRational::from_integer(2.into());
+    let mut out = Vec::new();
+    // (a, b, n) : n racines dans ]a, b]
+    let n = sturm.nb_racines(&-b.clone(), &b);
+    let mut pile = vec![(-b.clone(), b, n)];
+    while let Some((a, b, n)) = pile.pop() {
+        match n {
+            0 => {}
+            // racine unique : exacte si c’est la borne droite
+            1 if p.evalue(&b).is_zero() => out.push(Encadrement {
+                bas: b.clone(),
+                haut: b,
+            }),
+            1 => out.push(Encadrement { bas: a, haut: b }),
+            _ => {
+                let m = (&a + &b) / &deux;
+                let gauche = sturm.nb_racines(&a, &m);
+                pile.push((m.clone(), b, n - gauche));
+                pile.push((a, m, gauche));
+            }
+        }
+    }
+    out.sort_by(|x, y| x.bas.cmp(&y.bas));
+    out
+}
+
+/// Bissection jusqu’à haut - bas ≤ largeur. Racine simple dans ]bas, haut[ et p(haut) ≠ 0 :
+/// p a le signe de p(haut) à droite de la racine, l’opposé à gauche (p(bas) peut être nul).
+pub fn affine(p: &Polynome, e: &Encadrement, largeur: &BigRational) -> Encadrement {
+    let (mut a, mut b) = (e.bas.clone(), e.haut.clone());
+    let deux = BigRational::from_integer(2.into());
+    let signe_b = p.evalue(&b).signum();
+    while &b - &a > *largeur {
+        let m = (&a + &b) / &deux;
+        let v = p.evalue(&m);
+        if v.is_zero() {
+            return Encadrement {
+                bas: m.clone(),
+                haut: m,
+            };
+        }
+        if v.signum() == signe_b {
+            b = m;
+        } else {
+            a = m;
+        }
+    }
+    Encadrement { bas: a, haut: b }
+}
+
+/// Encadrement décimal à k chiffres (bornes ⌊·⌋ et ⌈·⌉ sur 10^-k), racine unique revérifiée ;
+/// k augmente si deux racines partagent le même encadrement décimal.
+pub fn encadrement_decimal(p: &Polynome, e: &Encadrement, k: usize) -> (String, String) {
+    if e.bas == e.haut {
+        let s = format_decimal_exact(&e.bas, k);
+        return (s.clone(), s);
+    }
+    let sturm = SuiteSturm::nouvelle(p);
+    let mut k = k;
+    loop {
+        let echelle = BigRational::from_integer(BigInt::from(10).pow(k as u32));
+        // affinage à 10^-(k+2) : les bornes arrondies ne s’écartent en général que d’un ulp
+        let fin = affine(
+            p,
+            e,
+            &(echelle.recip() / BigRational::from_integer(100.into())),
+        );
+        if fin.bas == fin.haut {
+            let s = format_decimal_exact(&fin.bas, k);
+            return (s.clone(), s);
+        }
+        let bas = (&fin.bas * &echelle).floor().to_integer();
+        let haut = (&fin.haut * &echelle).ceil().to_integer();
+        let (rb, rh) = (
+            BigRational::new(bas.clone(), echelle.to_integer()),
+            BigRational::new(haut.clone(), echelle.to_integer()),
+        );
+        if sturm.nb_racines(&rb, &rh) == 1 && !p.evalue(&rh).is_zero() {
+            return (scaled_to_decimal(bas, k), scaled_to_decimal(haut, k));
+        }
+        k += 2;
+    }
+}
+
+/// Lecture ΣLocal certifiée à `digits` chiffres (troncature vers 0, comme eval_scaled) :
+/// bissection jusqu’à ce que les deux bornes aient la même troncature, donc la racine aussi.
+/// Racine négative plus petite que 10^-digits : “-0.000…” (le signe n’est pas perdu).
+pub fn decimales_certifiees(p: &Polynome, e: &Encadrement, digits: usize) -> String {
+    let echelle = BigInt::from(10).pow(digits as u32);
+    let tronque = |r: &BigRational| (r.numer() * &echelle) / r.denom();
+    let lecture = |t: BigInt, negatif: bool| {
+        let s = scaled_to_decimal(t.clone(), digits);
+        if negatif && t.is_zero() {
+            format!("-{s}")
+        } else {
+            s
+        }
+    };
+    let mut enc = e.clone();
+    let mut largeur = BigRational::new(BigInt::one(), echelle.clone());
+    loop {
+        if enc.bas == enc.haut {
+            return lecture(tronque(&enc.bas), enc.bas.is_negative());
+        }
+        let (a, b) = (tronque(&enc.bas), tronque(&enc.haut));
+        // troncature nulle : il faut encore le signe (intervalle à cheval sur 0 => affinage,
+        // sauf si 0 est la racine)
+        let signe_connu = !a.is_zero()
+            || enc.haut.is_negative()
+            || !enc.bas.is_negative()
+            || p.evalue(&BigRational::zero()).is_zero();
+        if a == b && signe_connu {
+            return lecture(a, enc.haut.is_negative());
+        }
+        // racine décimale exacte sur une frontière : jamais atteinte par bissection dyadique
+        for c in [&a, &b] {
+            let r = BigRational::new(c.clone(), echelle.clone());
+            if enc.bas <= r && r <= enc.haut && p.evalue(&r).is_zero() {
+                return scaled_to_decimal(c.clone(), digits);
+            }
+        }
+        largeur /= BigRational::from_integer(16.into());
+        enc = affine(p, &enc, &largeur);
+    }
+}
+
+/// Démarche : suite de Sturm, borne de Cauchy et nombre de racines réelles.
+pub fn trace_sturm(p: &Polynome, var: &str) -> String {
+    let sturm = SuiteSturm::nouvelle(p);
+    let b = borne_cauchy(p);
+    let mut s = String::from("suite de Sturm :");
+    for (i, si) in sturm.suite.iter().enumerate() {
+        s.push_str(&format!(" S{i} = {} ;", si.format(var)));
+    }
+    s.pop();
+    let (va, vb) = (sturm.variations(&-b.clone()), sturm.variations(&b));
+    s.push_str(&format!(
+        "\nborne de Cauchy B = {b} : V(-B) - V(B) = {va} - {vb} = {} racine(s) réelle(s)\n",
+        va - vb
+    ));
+    s
+}
+
+/// Rationnel en décimal : exact si le dénominateur divise 10^k, sinon fraction.
+fn format_decimal_exact(r: &BigRational, k: usize) -> String {
+    let echelle = BigInt::from(10).pow(k as u32);
+    if echelle.is_multiple_of(r.denom()) {
+        let n = r.numer() * (&echelle / r.denom());
+        return scaled_to_decimal(n, k)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string();
+    }
+    r.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(c: &[i64]) -> Polynome {
+        Polynome::depuis_entiers(&c.iter().map(|&n| BigInt::from(n)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn sturm_compte_et_isole() {
+        // x^3 - 3x + 1 : trois racines réelles (2cos(2π/9), …)
+        let f = p(&[1, -3, 0, 1]);
+        let r = isole_racines(&f);
+        assert_eq!(r.len(), 3);
+        let d: Vec<(String, String)> = r.iter().map(|e| encadrement_decimal(&f, e, 6)).collect();
+        assert_eq!(d[0], ("-1.879386".into(), "-1.879385".into()));
+        assert_eq!(d[1], ("0.347296".into(), "0.347297".into()));
+        assert_eq!(d[2], ("1.532088".into(), "1.532089".into()));
+
+        // x^2 + 1 : aucune racine réelle ; x(x-1)(x+1) : racines exactes au milieu
+        assert!(isole_racines(&p(&[1, 0, 1])).is_empty());
+        let g = p(&[0, -1, 0, 1]);
+        let r = isole_racines(&g);
+        assert_eq!(r.len(), 3);
+        assert_eq!(encadrement_decimal(&g, &r[1], 6), ("0".into(), "0".into()));
+    }
+
+    #[test]
+    fn decimales_garanties() {
+        // x^2 - 2 : √2 = 1.41421356237309504880…
+        let f = p(&[-2, 0, 1]);
+        let r = isole_racines(&f);
+        assert_eq!(
+            decimales_certifiees(&f, &r[1], 20),
+            "1.41421356237309504880"
+        );
+        assert_eq!(decimales_certifiees(&f, &r[0], 5), "-1.41421");
+
+        // 10x - 1 : racine décimale exacte 0.1 (frontière jamais atteinte par bissection)
+        let g = p(&[-1, 10]);
+        let r = isole_racines(&g);
+        assert_eq!(decimales_certifiees(&g, &r[0], 4), "0.1000");
+
+        // 10^6·x + 1 : racine -10^-6, plus petite que la précision : le signe reste
+        let h = p(&[1, 1_000_000]);
+        let r = isole_racines(&h);
+        assert_eq!(decimales_certifiees(&h, &r[0], 4), "-0.0000");
+
+        let t = trace_sturm(&p(&[1, -3, 0, 1]), "x");
+        assert!(t.contains("V(-B) - V(B) = 3 - 0 = 3"), "{t}");
+    }
+}
@@ -18,20 +18,37 @@ pub enum Tok {
     Star,
     Slash,
     Caret, // ^
+    Egal,  // = (équation : au plus un, au niveau racine)
+
+    // Postfixés : factorielle n! et double factorielle n!!
+    Bang,
+    BangBang,
 
     LPar,
     RPar,
+    Comma, // séparateur d’arguments : C(n, k), et de coefficients : [1, 2]
+
+    LCrochet, // [ (matrice / ligne)
+    RCrochet, // ]
+
+    // Interne (produit par to_rpn, jamais par tokenize) : moins unaire après un opérateur
+    Neg,
+    // Interne : crochets fermés sur n éléments ([a, b, c] => a b c liste3)
+    Liste(usize),
 }
 
 /// Tokenize une chaîne en jetons.
 /// Supporte:
 /// - entiers (ex: 12)
-/// - fractions littérales sans espaces (ex: 12/34) -> Num(12/34)
-/// - opérateurs + - * / ^
-/// - parenthèses ( )
+/// - fractions littérales sans espaces (ex: 12/34) -> Num(12/34), sauf juste après ^
+///   (x^2/4 = (x^2)/4 ; exposant fractionnaire : x^(2/3))
+/// - opérateurs + - * / ^ et = (équation)
+/// - postfixés ! et !! (un seul jeton, pas de (n!)! implicite)
+/// - parenthèses ( ) et virgule d’arguments
+/// - crochets [ ] (matrices : [[1, 2], [3, 4]])
 /// - π ou pi
 /// - identifiants [a-zA-Z_][a-zA-Z0-9_]* (normalisés en minuscules)
-/// - √ (équivaut à ident("sqrt"))
+/// - √ (équivaut à ident("sqrt")), ∛ (ident("cbrt")), ∜ (ident("root4"))
 pub fn tokenize(s: &str) -> Result<Vec<Tok>, String> {
     let mut out = Vec::new();
     let chars: Vec<char> = s.chars().collect();
@@ -56,6 +73,21 @@ pub fn tokenize(s: &str) -> Result<Vec<Tok>, String> {
             i += 1;
             continue;
         }
+        if c == ',' {
+            out.push(Tok::Comma);
+            i += 1;
+            continue;
+        }
+        if c == '[' {
+            out.push(Tok::LCrochet);
+            i += 1;
+            continue;
+        }
+        if c == ']' {
+            out.push(Tok::RCrochet);
+            i += 1;
+            continue;
+        }
 
         // Opérateurs
         match c {
@@ -84,6 +116,21 @@ pub fn tokenize(s: &str) -> Result<Vec<Tok>, String> {
                 i += 1;
                 continue;
             }
+            '=' => {
+                out.push(Tok::Egal);
+                i += 1;
+                continue;
+            }
+            '!' => {
+                if i + 1 < chars.len() && chars[i + 1] == '!' {
+                    out.push(Tok::BangBang);
+                    i += 2;
+                } else {
+                    out.push(Tok::Bang);
+                    i += 1;
+                }
+                continue;
+            }
             _ => {}
         }
 
@@ -108,6 +155,16 @@ pub fn tokenize(s: &str) -> Result<Vec<Tok>, String> {
             i += 1;
             continue;
         }
+        if c == '∛' {
+            out.push(Tok::Ident("cbrt".to_string()));
+            i += 1;
+            continue;
+        }
+        if c == '∜' {
+            out.push(Tok::Ident("root4".to_string()));
+            i += 1;
+            continue;
+        }
 
         // Identifiants ASCII : [a-zA-Z_][a-zA-Z0-9_]*
         if c.is_ascii_alphabetic() || c == '_' {
@@ -144,8 +201,14 @@ pub fn tokenize(s: &str) -> Result<Vec<Tok>, String> {
             // par défaut: entier
             let mut rat = BigRational::from_integer(n.clone());
 
+            // exposant : x^2/4 = (x^2)/4, pas x^(2/4) (idem après ^-)
+            let exposant = matches!(
+                out.as_slice(),
+                [.., Tok::Caret] | [.., Tok::Caret, Tok::Minus]
+            );
+
             // fraction immédiate: 12/34 (pas de parenthèses, pas d’espaces)
-            if i < chars.len() && chars[i] == '/' {
+            if !exposant && i < chars.len() && chars[i] == '/' {
                 let save = i;
                 i += 1;
                 let start_d = i;
@@ -201,9 +264,17 @@ pub fn format_tokens(tokens: &[Tok]) -> String {
             Tok::Star => "*".to_string(),
             Tok::Slash => "/".to_string(),
             Tok::Caret => "^".to_string(),
+            Tok::Egal => "=".to_string(),
+            Tok::Bang => "!".to_string(),
+            Tok::BangBang => "!!".to_string(),
 
             Tok::LPar => "(".to_string(),
             Tok::RPar => ")".to_string(),
+            Tok::Comma => ",".to_string(),
+            Tok::LCrochet => "[".to_string(),
+            Tok::RCrochet => "]".to_string(),
+            Tok::Neg => "neg".to_string(),
+            Tok::Liste(n) => format!("liste{n}"),
         };
         out.push(s);
     }
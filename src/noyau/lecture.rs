@@ -6,7 +6,7 @@ use num_traits::{One, Signed, Zero};
 use std::collections::HashMap;
 use std::sync::{Mutex, OnceLock};
 
-use super::expr::Expr;
+use super::expr::{int_root_floor, Expr};
 
 /* ------------------------ Décimal (scaled -> texte) ------------------------ */
 
@@ -109,7 +109,7 @@ fn pi_scaled_compute(digits: usize) -> BigInt {
 
 static PI_CACHE: OnceLock<Mutex<HashMap<usize, BigInt>>> = OnceLock::new();
 
-fn pi_scaled_cached(digits: usize) -> BigInt {
+pub(crate) fn pi_scaled_cached(digits: usize) -> BigInt {
     let m = PI_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
     let mut guard = m.lock().expect("mutex π");
 
@@ -174,15 +174,36 @@ fn rational_sqrt_scaled(r: &BigRational, digits: usize) -> BigInt {
     y
 }
 
+/// ⁿ√r en entier scalé : floor( ⁿ√r * 10^digits ), r >= 0
+/// (Newton entier sur numer·10^(n·digits) / denom).
+fn rational_root_scaled(r: &BigRational, n: u32, digits: usize) -> BigInt {
+    let cible = (r.numer() * pow10(n as usize * digits)) / r.denom();
+    int_root_floor(&cible, n)
+}
+
 /* ------------------------ ΣLocal : évaluation scalée ------------------------ */
 
+/// Chiffres de garde des lectures intermédiaires (troncatures cumulées).
+const GARDE: usize = 10;
+
+/// ΣLocal : eval_scaled avec chiffres de garde, tronquée à `digits` (les troncatures
+/// des sommes et produits intermédiaires restent sous le dernier chiffre affiché).
+pub fn lecture_scaled(expr: &Expr, digits: usize) -> Result<BigInt, String> {
+    Ok(eval_scaled(expr, digits + GARDE)? / pow10(GARDE))
+}
+
 /// Évalue une expression en entier “scalé” (×10^digits).
 /// - Bloque si Indefini.
 /// - Bloque si Var (défense en profondeur).
 /// - Pi utilise cache.
 /// - Trig: on compte sur simplify() (angles spéciaux) => Rat ou Indefini.
-/// - PowInt: base rationnelle seulement (MVP).
-/// - Sqrt: argument rationnel seulement (MVP).
+/// - PowInt: base rationnelle exacte, sinon lecture de la base (chiffres de garde).
+/// - Pow (exposant symbolique) : bloquée.
+/// - Sqrt / Root: exact (Newton) si argument rationnel, sinon à partir de la lecture (garde)
+///   de l’argument ; racine paire d’un négatif refusée, racine impaire : signe conservé.
+/// - Abs: |lecture| ; Sign/Floor/Ceil/Round/Frac : seulement si simplify() a pu décider.
+/// - Fact/FactDouble/Binom/Arrang/Pgcd/Ppcm/Modulo : seulement si simplify() a produit
+///   une valeur exacte.
 pub fn eval_scaled(expr: &Expr, digits: usize) -> Result<BigInt, String> {
     use Expr::*;
 
@@ -200,7 +221,14 @@ pub fn eval_scaled(expr: &Expr, digits: usize) -> Result<BigInt, String> {
         Add(a, b) => Ok(eval_scaled(a, digits)? + eval_scaled(b, digits)?),
         Sub(a, b) => Ok(eval_scaled(a, digits)? - eval_scaled(b, digits)?),
 
+        // facteur rationnel c : l’autre facteur est lu avec les chiffres de c en plus
+        // (c·√2 avec c ~ 10^24 : l’erreur de troncature n’est pas multipliée par c)
         Mul(a, b) => {
+            if let (Rat(c), x) | (x, Rat(c)) = (&**a, &**b) {
+                let extra = c.numer().abs().to_string().len() + GARDE;
+                let sx = eval_scaled(x, digits + extra)?;
+                return Ok(sx * c.numer() / (c.denom() * pow10(extra)));
+            }
             let sa = eval_scaled(a, digits)?;
             let sb = eval_scaled(b, digits)?;
             Ok((sa * sb) / &scale)
@@ -216,25 +244,22 @@ pub fn eval_scaled(expr: &Expr, digits: usize) -> Result<BigInt, String> {
         }
 
         PowInt(base, n) => {
-            // MVP : seulement si base rationnelle
+            // base rationnelle : exact
             if let Rat(r) = &**base {
+                if r.is_zero() && *n < 0 {
+                    return Err("division par zéro".into());
+                }
                 let rr = rational_pow_int(r.clone(), *n);
                 return Ok(rational_scaled(&rr, digits));
             }
-            Err("puissance : base non rationnelle (à étendre)".into())
+            eval_pow_int_scaled(base, *n, digits)
         }
 
-        Sqrt(x) => {
-            // MVP : seulement si argument rationnel
-            let xr = match &**x {
-                Rat(r) => r.clone(),
-                _ => return Err("√ : argument non rationnel (à étendre)".into()),
-            };
-            if xr.is_negative() {
-                return Err("√ : argument négatif".into());
-            }
-            Ok(rational_sqrt_scaled(&xr, digits))
-        }
+        // exposant symbolique : pas de lecture (ni exp ni ln dans le noyau)
+        Pow(_, _) => Err("puissance : exposant symbolique (ΣLocal bloquée)".into()),
+
+        Sqrt(x) => eval_racine_scaled(x, 2, digits),
+        Root(x, n) => eval_racine_scaled(x, *n, digits),
 
         Sin(_) | Cos(_) | Tan(_) => {
             // MVP : on simplifie d’abord; si ça devient Rat/Indefini/Pi, ok; sinon non reconnu
@@ -247,10 +272,109 @@ pub fn eval_scaled(expr: &Expr, digits: usize) -> Result<BigInt, String> {
                 _ => Err("trig : angle non reconnu (angles spéciaux seulement)".into()),
             }
         }
+
+        Abs(x) => Ok(eval_scaled(x, digits)?.abs()),
+
+        Sign(_) | Floor(_) | Ceil(_) | Round(_) | Frac(_) => {
+            // Pas de lecture “à la frontière” : il faut une décision exacte (simplify).
+            let simp = expr.clone().simplify();
+            match simp {
+                Indefini => Err("indéfini".into()),
+                Sign(_) => Err("signe : valeur trop proche de zéro (non décidé)".into()),
+                Floor(_) | Ceil(_) | Round(_) | Frac(_) => {
+                    Err("arrondi : valeur trop proche d’un entier (non décidé)".into())
+                }
+                other => eval_scaled(&other, digits),
+            }
+        }
+
+        Fact(_) | FactDouble(_) | Binom(_, _) | Arrang(_, _) => {
+            // valeur exacte seulement (argument entier sous la garde de taille)
+            let simp = expr.clone().simplify();
+            match simp {
+                Indefini => Err("indéfini".into()),
+                Fact(_) | FactDouble(_) | Binom(_, _) | Arrang(_, _) => {
+                    Err("combinatoire : argument non entier ou trop grand (ΣLocal bloquée)".into())
+                }
+                other => eval_scaled(&other, digits),
+            }
+        }
+
+        Pgcd(_, _) | Ppcm(_, _) | Modulo(_, _) => {
+            let simp = expr.clone().simplify();
+            match simp {
+                Indefini => Err("indéfini".into()),
+                Pgcd(_, _) | Ppcm(_, _) | Modulo(_, _) => {
+                    Err("arithmétique : arguments non rationnels (ΣLocal bloquée)".into())
+                }
+                other => eval_scaled(&other, digits),
+            }
+        }
+
+        Egal(_, _) => Err("équation : pas de valeur décimale (ΣLocal bloquée)".into()),
+        Matrice(_) | Matriciel(_, _) => {
+            Err("matrice : pas de valeur décimale (ΣLocal bloquée)".into())
+        }
     }
 }
 
-/* ------------------------ Outil interne (PowInt) ------------------------ */
+/* ------------------------ Outils internes (racines, PowInt) ------------------------ */
+
+/// base^n en entier scalé, base quelconque : lecture de la base avec chiffres de garde.
+fn eval_pow_int_scaled(base: &Expr, n: i64, digits: usize) -> Result<BigInt, String> {
+    let d = digits + GARDE;
+    let sb = eval_scaled(base, d)?;
+
+    if n == 0 {
+        return Ok(pow10(digits));
+    }
+
+    let k = n.unsigned_abs() as usize;
+    if k > 10_000 {
+        return Err("puissance : exposant trop grand pour ΣLocal".into());
+    }
+    let p = sb.pow(k as u32);
+    let scaled_d = if n > 0 {
+        // (sb/10^d)^k · 10^d
+        p / pow10(d * (k - 1))
+    } else {
+        if p.is_zero() {
+            return Err("division par zéro".into());
+        }
+        // 10^d / (sb/10^d)^k
+        pow10(d * (k + 1)) / p
+    };
+    Ok(scaled_d / pow10(GARDE))
+}
+
+fn eval_racine_scaled(x: &Expr, n: u32, digits: usize) -> Result<BigInt, String> {
+    let symbole = if n == 2 { "√" } else { "ⁿ√" };
+
+    // Argument rationnel : racine exacte à la précision demandée
+    if let Expr::Rat(r) = x {
+        if r.is_negative() {
+            if n.is_multiple_of(2) {
+                return Err(format!("{symbole} : argument négatif"));
+            }
+            return Ok(-rational_root_scaled(&-r, n, digits));
+        }
+        if n == 2 {
+            return Ok(rational_sqrt_scaled(r, digits));
+        }
+        return Ok(rational_root_scaled(r, n, digits));
+    }
+
+    // Sinon : lecture de l’argument avec chiffres de garde (comme eval_pow_int_scaled),
+    // puis ⁿ√(s/10^d)·10^d = ⁿ√(s·10^(d(n-1))), tronquée à la précision demandée
+    let d = digits + GARDE;
+    let s = eval_scaled(x, d)?;
+    if s.is_negative() && n.is_multiple_of(2) {
+        return Err(format!("{symbole} : argument négatif"));
+    }
+    let cible = s.abs() * pow10(d * (n as usize - 1));
+    let y = int_root_floor(&cible, n) / pow10(GARDE);
+    Ok(if s.is_negative() { -y } else { y })
+}
 
 fn rational_pow_int(base: BigRational, exp: i64) -> BigRational {
     if exp == 0 {
@@ -0,0 +1,641 @@
+// src/noyau/matrices.rs
+//
+// Matrices exactes : [[1, 2], [3, 4]]
+// -----------------------------------
+// Coefficients : Expr quelconques (rationnels, radicaux, π, variables…), chacun ramené à sa
+// forme normale par le pipeline scalaire (eval::forme_normale) après chaque opération.
+// Étape 3b du pipeline : toutes les opérations matricielles sont réduites AVANT simplify ;
+// il ne reste ensuite qu’une matrice littérale (à la racine) ou un scalaire.
+// - A + B, A - B (mêmes dimensions), k·A, A·B (colonnes de A = lignes de B), A / k
+// - A^n : carrée, exponentiation rapide ; A^0 = I ; A^-n = inv(A)^n
+// - transpose(A), trace(A)
+// - rref(A) : Gauss–Jordan (pivots à 1, zéros au-dessus et au-dessous)
+// - rank(A) : nombre de pivots ; det(A) : ± produit des pivots
+//   (coefficients symboliques : développement de Laplace, sans test de nullité)
+// - inv(A) : Gauss–Jordan sur [A | I]
+//
+// IMPORTANT (SAFE):
+// - Un pivot n’est choisi que si sa non-nullité est PROUVÉE (nullite.rs) ; nullité
+//   indécidable => erreur explicite, jamais un rang ou un inverse deviné.
+// - Dimensions incompatibles, matrice singulière, fonction scalaire d’une matrice : Err.
+// - Tailles et exposants bornés (TAILLE_MAX, PUISSANCE_MAX, LAPLACE_MAX).
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+use super::eval::forme_normale;
+use super::expr::Expr;
+use super::format::format_expr_pretty;
+use super::nullite::{nullite, Nullite};
+
+/// Nombre maximal de lignes et de colonnes.
+const TAILLE_MAX: usize = 12;
+
+/// Exposant maximal |n| dans A^n.
+const PUISSANCE_MAX: i64 = 1024;
+
+/// Taille maximale pour le développement de Laplace (n! termes).
+const LAPLACE_MAX: usize = 6;
+
+/// Opération matricielle unaire (fonction reconnue par rpn.rs).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpMatrice {
+    Det,
+    Inverse,
+    Transposee,
+    Rang,
+    Echelonnee,
+    Trace,
+}
+
+impl OpMatrice {
+    /// Nom de fonction (et alias français) => opération.
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        match nom {
+            "det" => Some(OpMatrice::Det),
+            "inv" | "inverse" => Some(OpMatrice::Inverse),
+            "transpose" | "transposee" => Some(OpMatrice::Transposee),
+            "rank" | "rang" => Some(OpMatrice::Rang),
+            "rref" | "echelonne" => Some(OpMatrice::Echelonnee),
+            "trace" => Some(OpMatrice::Trace),
+            _ => None,
+        }
+    }
+
+    pub fn nom(self) -> &'static str {
+        match self {
+            OpMatrice::Det => "det",
+            OpMatrice::Inverse => "inv",
+            OpMatrice::Transposee => "transpose",
+            OpMatrice::Rang => "rank",
+            OpMatrice::Echelonnee => "rref",
+            OpMatrice::Trace => "trace",
+        }
+    }
+}
+
+/* ------------------------ Construction (rpn.rs) ------------------------ */
+
+/// [a, b, c] => ligne 1×3 ; [[1, 2], [3, 4]] (lignes littérales) => 2×2.
+pub fn depuis_liste(elements: Vec<Expr>) -> Result<Expr, String> {
+    let lignes: Vec<Vec<Expr>> = if elements
+        .iter()
+        .all(|e| matches!(e, Expr::Matrice(l) if l.len() == 1))
+    {
+        elements
+            .into_iter()
+            .map(|e| match e {
+                Expr::Matrice(mut l) => l.remove(0),
+                _ => unreachable!(),
+            })
+            .collect()
+    } else if elements.iter().any(|e| matches!(e, Expr::Matrice(_))) {
+        return Err("matrice : lignes [a, b] ou coefficients attendus, pas un mélange".into());
+    } else {
+        vec![elements]
+    };
+    if lignes.iter().any(|l| l.len() != lignes[0].len()) {
+        return Err("matrice : toutes les lignes doivent avoir la même longueur".into());
+    }
+    if lignes.len() > TAILLE_MAX || lignes[0].len() > TAILLE_MAX {
+        return Err(format!("matrice : au plus {TAILLE_MAX}×{TAILLE_MAX}"));
+    }
+    Ok(Expr::Matrice(lignes))
+}
+
+/* ------------------------ Réduction (étape 3b) ------------------------ */
+
+#[derive(Clone, Debug)]
+struct Mat {
+    l: Vec<Vec<Expr>>,
+}
+
+enum Valeur {
+    Scalaire(Expr),
+    Matrice(Mat),
+}
+
+/// Réduit toutes les opérations matricielles : matrice littérale (racine) ou scalaire.
+pub fn evalue_matrices(e: Expr, preuve: &mut String) -> Result<Expr, String> {
+    if !e.contient_matrice() {
+        return Ok(e);
+    }
+    Ok(match valeur(e, preuve)? {
+        Valeur::Scalaire(s) => s,
+        Valeur::Matrice(m) => Expr::Matrice(m.l),
+    })
+}
+
+fn valeur(e: Expr, preuve: &mut String) -> Result<Valeur, String> {
+    use Expr::*;
+    use Valeur::Scalaire as S;
+
+    if !e.contient_matrice() {
+        return Ok(S(e));
+    }
+    Ok(match e {
+        Matrice(l) => {
+            let l = l
+                .into_iter()
+                .map(|ligne| {
+                    ligne
+                        .into_iter()
+                        .map(|x| scalaire(valeur(x, preuve)?, "matrice : coefficient"))
+                        .map(|x| x.map(reduit))
+                        .collect::<Result<Vec<_>, String>>()
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            Valeur::Matrice(Mat { l })
+        }
+        Matriciel(op, x) => {
+            let m = matrice(valeur(*x, preuve)?, op.nom())?;
+            applique(op, m, preuve)?
+        }
+
+        Add(a, b) => match (valeur(*a, preuve)?, valeur(*b, preuve)?) {
+            (Valeur::Matrice(a), Valeur::Matrice(b)) => {
+                Valeur::Matrice(a.terme_a_terme(&b, "+", somme)?)
+            }
+            (S(a), S(b)) => S(Add(Box::new(a), Box::new(b))),
+            _ => return Err("matrice + scalaire : opération non définie".into()),
+        },
+        Sub(a, b) => match (valeur(*a, preuve)?, valeur(*b, preuve)?) {
+            (Valeur::Matrice(a), Valeur::Matrice(b)) => {
+                Valeur::Matrice(a.terme_a_terme(&b, "-", difference)?)
+            }
+            // -A (moins unaire : 0 - A)
+            (S(Rat(z)), Valeur::Matrice(b)) if z.is_zero() => {
+                Valeur::Matrice(b.scalaire(&Rat(-BigRational::one())))
+            }
+            (S(a), S(b)) => S(Sub(Box::new(a), Box::new(b))),
+            _ => return Err("matrice - scalaire : opération non définie".into()),
+        },
+        Mul(a, b) => match (valeur(*a, preuve)?, valeur(*b, preuve)?) {
+            (Valeur::Matrice(a), Valeur::Matrice(b)) => Valeur::Matrice(a.produit(&b)?),
+            (S(k), Valeur::Matrice(m)) | (Valeur::Matrice(m), S(k)) => {
+                Valeur::Matrice(m.scalaire(&k))
+            }
+            (S(a), S(b)) => S(Mul(Box::new(a), Box::new(b))),
+        },
+        Div(a, b) => match (valeur(*a, preuve)?, valeur(*b, preuve)?) {
+            (Valeur::Matrice(m), S(k)) => {
+                if est_nul(&reduit(k.clone()), "division")? {
+                    return Err("matrice / 0 : division par zéro".into());
+                }
+                Valeur::Matrice(m.map(|x| quotient(x, &k)))
+            }
+            (S(a), S(b)) => S(Div(Box::new(a), Box::new(b))),
+            _ => return Err("division par une matrice : utiliser inv(…)".into()),
+        },
+        PowInt(a, n) => match valeur(*a, preuve)? {
+            Valeur::Matrice(m) => Valeur::Matrice(m.puissance(n, preuve)?),
+            S(a) => S(PowInt(Box::new(a), n)),
+        },
+        Egal(_, _) => return Err("'=' : égalité de matrices non prise en charge".into()),
+
+        // fonction scalaire : ses arguments doivent redevenir scalaires (sqrt(det(A)))
+        autre => S(autre.map_enfants(&mut |x| scalaire(valeur(x, preuve)?, "fonction scalaire"))?),
+    })
+}
+
+fn scalaire(v: Valeur, contexte: &str) -> Result<Expr, String> {
+    match v {
+        Valeur::Scalaire(s) => Ok(s),
+        Valeur::Matrice(_) => Err(format!(
+            "{contexte} : argument matriciel non pris en charge"
+        )),
+    }
+}
+
+fn matrice(v: Valeur, nom: &str) -> Result<Mat, String> {
+    match v {
+        Valeur::Matrice(m) => Ok(m),
+        Valeur::Scalaire(s) => Err(format!(
+            "{nom} : matrice attendue (reçu {})",
+            format_expr_pretty(&s)
+        )),
+    }
+}
+
+fn applique(op: OpMatrice, m: Mat, preuve: &mut String) -> Result<Valeur, String> {
+    let nom = op.nom();
+    Ok(match op {
+        OpMatrice::Transposee => Valeur::Matrice(m.transposee()),
+        OpMatrice::Trace => {
+            m.carree(nom)?;
+            let diag = (0..m.lignes()).map(|i| m.l[i][i].clone());
+            Valeur::Scalaire(reduit(somme_de(diag)))
+        }
+        OpMatrice::Det => Valeur::Scalaire(m.det(preuve)?),
+        OpMatrice::Inverse => Valeur::Matrice(m.inverse(preuve)?),
+        OpMatrice::Rang => {
+            let (_, pivots) = m.gauss_jordan(m.colonnes(), nom)?;
+            preuve.push_str(&format!(
+                "rank : Gauss–Jordan, {} pivot(s) (colonnes {})\n",
+                pivots.len(),
+                liste_colonnes(&pivots)
+            ));
+            Valeur::Scalaire(Expr::Rat(BigRational::from_integer(pivots.len().into())))
+        }
+        OpMatrice::Echelonnee => {
+            let (r, pivots) = m.gauss_jordan(m.colonnes(), nom)?;
+            preuve.push_str(&format!(
+                "rref : Gauss–Jordan, pivots en colonnes {}\n",
+                liste_colonnes(&pivots)
+            ));
+            Valeur::Matrice(r)
+        }
+    })
+}
+
+/// Base du noyau de l (Gauss–Jordan) : une colonne libre f vaut 1, les autres libres 0,
+/// l’inconnue du pivot de la ligne i vaut -R[i][f]. Noyau réduit à {0} => base vide.
+pub(crate) fn noyau(l: Vec<Vec<Expr>>, nom: &str) -> Result<Vec<Vec<Expr>>, String> {
+    let m = Mat { l };
+    let n = m.colonnes();
+    let (r, pivots) = m.gauss_jordan(n, nom)?;
+    let libres = (0..n).filter(|k| !pivots.contains(k));
+    Ok(libres
+        .map(|f| {
+            let mut v = vec![zero(); n];
+            v[f] = un();
+            for (i, &k) in pivots.iter().enumerate() {
+                v[k] = difference(&zero(), &r.l[i][f]);
+            }
+            v
+        })
+        .collect())
+}
+
+/* ------------------------ Coefficients ------------------------ */
+
+/// Forme normale d’un coefficient (pipeline scalaire, preuve non conservée).
+fn reduit(e: Expr) -> Expr {
+    forme_normale(e, &mut String::new())
+}
+
+fn somme(a: &Expr, b: &Expr) -> Expr {
+    reduit(Expr::Add(Box::new(a.clone()), Box::new(b.clone())))
+}
+
+fn difference(a: &Expr, b: &Expr) -> Expr {
+    reduit(Expr::Sub(Box::new(a.clone()), Box::new(b.clone())))
+}
+
+fn produit(a: &Expr, b: &Expr) -> Expr {
+    reduit(Expr::Mul(Box::new(a.clone()), Box::new(b.clone())))
+}
+
+fn quotient(a: &Expr, b: &Expr) -> Expr {
+    reduit(Expr::Div(Box::new(a.clone()), Box::new(b.clone())))
+}
+
+/// Somme non réduite (une seule normalisation à la fin).
+fn somme_de(termes: impl Iterator<Item = Expr>) -> Expr {
+    termes
+        .reduce(|a, b| Expr::Add(Box::new(a), Box::new(b)))
+        .unwrap_or_else(|| Expr::Rat(BigRational::zero()))
+}
+
+fn zero() -> Expr {
+    Expr::Rat(BigRational::zero())
+}
+
+fn un() -> Expr {
+    Expr::Rat(BigRational::one())
+}
+
+/// Nullité prouvée (Rat immédiat, sinon nullite.rs) ; indécidable => Err.
+fn est_nul(e: &Expr, contexte: &str) -> Result<bool, String> {
+    match nullite(e) {
+        Nullite::Nul => Ok(true),
+        Nullite::NonNul => Ok(false),
+        Nullite::Inconnu => Err(format!(
+            "{contexte} : nullité de {} indécidable (pivot non choisi)",
+            format_expr_pretty(e)
+        )),
+    }
+}
+
+fn liste_colonnes(pivots: &[usize]) -> String {
+    if pivots.is_empty() {
+        return "aucune".into();
+    }
+    pivots
+        .iter()
+        .map(|k| (k + 1).to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/* ------------------------ Opérations ------------------------ */
+
+impl Mat {
+    fn lignes(&self) -> usize {
+        self.l.len()
+    }
+
+    fn colonnes(&self) -> usize {
+        self.l.first().map_or(0, |l| l.len())
+    }
+
+    fn dimensions(&self) -> String {
+        format!("{}×{}", self.lignes(), self.colonnes())
+    }
+
+    fn identite(n: usize) -> Self {
+        Mat {
+            l: (0..n)
+                .map(|i| (0..n).map(|j| if i == j { un() } else { zero() }).collect())
+                .collect(),
+        }
+    }
+
+    fn carree(&self, nom: &str) -> Result<usize, String> {
+        if self.lignes() != self.colonnes() {
+            return Err(format!(
+                "{nom} : matrice carrée attendue (reçu {})",
+                self.dimensions()
+            ));
+        }
+        Ok(self.lignes())
+    }
+
+    fn map(&self, f: impl Fn(&Expr) -> Expr) -> Self {
+        Mat {
+            l: self.l.iter().map(|l| l.iter().map(&f).collect()).collect(),
+        }
+    }
+
+    fn terme_a_terme(
+        &self,
+        b: &Self,
+        op: &str,
+        f: fn(&Expr, &Expr) -> Expr,
+    ) -> Result<Self, String> {
+        if self.lignes() != b.lignes() || self.colonnes() != b.colonnes() {
+            return Err(format!(
+                "A {op} B : dimensions {} et {} différentes",
+                self.dimensions(),
+                b.dimensions()
+            ));
+        }
+        Ok(Mat {
+            l: self
+                .l
+                .iter()
+                .zip(&b.l)
+                .map(|(x, y)| x.iter().zip(y).map(|(a, b)| f(a, b)).collect())
+                .collect(),
+        })
+    }
+
+    fn scalaire(&self, k: &Expr) -> Self {
+        self.map(|x| produit(k, x))
+    }
+
+    fn produit(&self, b: &Self) -> Result<Self, String> {
+        if self.colonnes() != b.lignes() {
+            return Err(format!(
+                "A·B : dimensions {} et {} incompatibles",
+                self.dimensions(),
+                b.dimensions()
+            ));
+        }
+        let l = (0..self.lignes())
+            .map(|i| {
+                (0..b.colonnes())
+                    .map(|j| {
+                        reduit(somme_de((0..self.colonnes()).map(|k| {
+                            Expr::Mul(Box::new(self.l[i][k].clone()), Box::new(b.l[k][j].clone()))
+                        })))
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Mat { l })
+    }
+
+    fn transposee(&self) -> Self {
+        Mat {
+            l: (0..self.colonnes())
+                .map(|j| self.l.iter().map(|l| l[j].clone()).collect())
+                .collect(),
+        }
+    }
+
+    /// A^n par exponentiation rapide (n < 0 : inverse d’abord).
+    fn puissance(&self, n: i64, preuve: &mut String) -> Result<Self, String> {
+        let taille = self.carree("A^n")?;
+        if n.abs() > PUISSANCE_MAX {
+            return Err(format!("A^n : |n| ≤ {PUISSANCE_MAX}"));
+        }
+        let base = if n < 0 {
+            self.inverse(preuve)?
+        } else {
+            self.clone()
+        };
+        let mut k = n.unsigned_abs();
+        let mut carre = base;
+        let mut acc = Mat::identite(taille);
+        while k > 0 {
+            if k & 1 == 1 {
+                acc = acc.produit(&carre)?;
+            }
+            k >>= 1;
+            if k > 0 {
+                carre = carre.produit(&carre)?;
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Gauss–Jordan, pivots cherchés dans les `colonnes` premières colonnes :
+    /// (forme échelonnée réduite, colonnes pivots).
+    fn gauss_jordan(&self, colonnes: usize, nom: &str) -> Result<(Self, Vec<usize>), String> {
+        let (r, pivots, _) = self.elimine(colonnes, nom)?;
+        Ok((r, pivots))
+    }
+
+    /// Élimination + produit des pivots (signe des échanges compris).
+    fn elimine(&self, colonnes: usize, nom: &str) -> Result<(Self, Vec<usize>, Expr), String> {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        let mut facteur = un();
+        let mut r = 0;
+        for k in 0..colonnes {
+            if r == m.lignes() {
+                break;
+            }
+            let mut choisi = None;
+            for i in r..m.lignes() {
+                if !est_nul(&m.l[i][k], nom)? {
+                    choisi = Some(i);
+                    break;
+                }
+            }
+            let Some(i) = choisi else {
+                continue;
+            };
+            if i != r {
+                m.l.swap(i, r);
+                facteur = produit(&Expr::Rat(-BigRational::one()), &facteur);
+            }
+            let p = m.l[r][k].clone();
+            facteur = produit(&facteur, &p);
+            m.l[r] = m.l[r].iter().map(|x| quotient(x, &p)).collect();
+            for i in 0..m.lignes() {
+                if i == r || est_nul(&m.l[i][k], nom)? {
+                    continue;
+                }
+                let a = m.l[i][k].clone();
+                let pivot = m.l[r].clone();
+                m.l[i] = m.l[i]
+                    .iter()
+                    .zip(&pivot)
+                    .map(|(x, y)| difference(x, &produit(&a, y)))
+                    .collect();
+            }
+            pivots.push(k);
+            r += 1;
+        }
+        Ok((m, pivots, facteur))
+    }
+
+    fn det(&self, preuve: &mut String) -> Result<Expr, String> {
+        let n = self.carree("det")?;
+        match self.elimine(n, "det") {
+            Ok((_, pivots, facteur)) => {
+                let d = if pivots.len() == n { facteur } else { zero() };
+                preuve.push_str(&format!(
+                    "det : élimination de Gauss, {} pivot(s) sur {n} => det = {}\n",
+                    pivots.len(),
+                    format_expr_pretty(&d)
+                ));
+                Ok(d)
+            }
+            // coefficients symboliques : pas de pivot prouvé, développement de Laplace
+            Err(_) if n <= LAPLACE_MAX => {
+                let d = reduit(laplace(&self.l));
+                preuve.push_str(&format!(
+                    "det : développement de Laplace (première ligne) => det = {}\n",
+                    format_expr_pretty(&d)
+                ));
+                Ok(d)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn inverse(&self, preuve: &mut String) -> Result<Self, String> {
+        let n = self.carree("inv")?;
+        let augmentee = Mat {
+            l: self
+                .l
+                .iter()
+                .zip(Mat::identite(n).l)
+                .map(|(a, i)| a.iter().cloned().chain(i).collect())
+                .collect(),
+        };
+        let (r, pivots) = augmentee.gauss_jordan(n, "inv")?;
+        if pivots.len() < n {
+            return Err(format!(
+                "inv : matrice singulière (rang {} < {n}, det = 0)",
+                pivots.len()
+            ));
+        }
+        preuve.push_str("inv : Gauss–Jordan sur [A | I] => [I | A⁻¹]\n");
+        Ok(Mat {
+            l: r.l.into_iter().map(|l| l[n..].to_vec()).collect(),
+        })
+    }
+}
+
+/// det par développement selon la première ligne (sans réduction intermédiaire).
+fn laplace(l: &[Vec<Expr>]) -> Expr {
+    let n = l.len();
+    if n == 1 {
+        return l[0][0].clone();
+    }
+    let termes = (0..n).map(|j| {
+        let mineur: Vec<Vec<Expr>> = l[1..]
+            .iter()
+            .map(|ligne| {
+                ligne
+                    .iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k != j)
+                    .map(|(_, x)| x.clone())
+                    .collect()
+            })
+            .collect();
+        let signe = if j % 2 == 0 { 1 } else { -1 };
+        Expr::Mul(
+            Box::new(Expr::Mul(
+                Box::new(Expr::Rat(BigRational::from_integer(BigInt::from(signe)))),
+                Box::new(l[0][j].clone()),
+            )),
+            Box::new(laplace(&mineur)),
+        )
+    });
+    somme_de(termes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::noyau::eval_expression;
+
+    fn exact(s: &str) -> String {
+        eval_expression(s, 20)
+            .unwrap_or_else(|e| panic!("{s} : {e}"))
+            .0
+    }
+
+    #[test]
+    fn operations_de_base() {
+        assert_eq!(exact("[[1,2],[3,4]] + [[1,0],[0,1]]"), "⎡2  2⎤\n⎣3  5⎦");
+        assert_eq!(exact("[[1,2],[3,4]] * [[5],[6]]"), "⎡17⎤\n⎣39⎦");
+        assert_eq!(exact("2[1, -1/2]"), "[2  -1]");
+        assert_eq!(
+            exact("transpose([[1,2,3],[4,5,6]])"),
+            "⎡1  4⎤\n⎢2  5⎥\n⎣3  6⎦"
+        );
+        assert_eq!(exact("trace([[1,2],[3,4]])"), "5");
+        assert_eq!(exact("[[1,1],[1,0]]^10"), "⎡89  55⎤\n⎣55  34⎦");
+        assert_eq!(exact("[[1,2],[3,4]]^0"), "⎡1  0⎤\n⎣0  1⎦");
+
+        assert!(eval_expression("[[1,2],[3,4]] + [1,2]", 20).is_err());
+        assert!(eval_expression("[[1,2],[3,4]] * [1,2]", 20).is_err());
+        assert!(eval_expression("[[1,2],[3]]", 20).is_err());
+        assert!(eval_expression("sin([[1]])", 20).is_err());
+        assert!(eval_expression("[]", 20).is_err());
+    }
+
+    #[test]
+    fn det_inverse_rang() {
+        assert_eq!(exact("det([[1,2],[3,4]])"), "-2");
+        assert_eq!(exact("inv([[1,2],[3,4]])"), "⎡ -2     1⎤\n⎣3/2  -1/2⎦");
+        assert_eq!(exact("[[1,2],[3,4]]^-1 * [[1,2],[3,4]]"), "⎡1  0⎤\n⎣0  1⎦");
+        assert_eq!(exact("rank([[1,2,3],[2,4,6],[1,0,1]])"), "2");
+        assert_eq!(
+            exact("rref([[1,2,3],[2,4,6],[1,0,1]])"),
+            "⎡1  0  1⎤\n⎢0  1  1⎥\n⎣0  0  0⎦"
+        );
+        assert!(eval_expression("inv([[1,2],[2,4]])", 20).is_err());
+
+        // radicaux et variables
+        assert_eq!(exact("det([[sqrt(2), 1], [1, sqrt(2)]])"), "1");
+        assert_eq!(exact("det([[x, 1], [1, x]])"), exact("x^2 - 1"));
+        assert_eq!(exact("det([[1,2],[3,4]]) + 1/2"), "-3/2");
+
+        let (_, lecture, d) = eval_expression("inv([[2,0],[0,4]])", 20).unwrap();
+        assert!(lecture.is_none());
+        assert!(
+            d.preuve
+                .contains("\\begin{pmatrix} \\frac{1}{2} & 0 \\\\ 0 & \\frac{1}{4} \\end{pmatrix}"),
+            "{}",
+            d.preuve
+        );
+    }
+}
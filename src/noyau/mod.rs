@@ -7,17 +7,61 @@
 //! - rpn.rs      : shunting-yard + construction Expr
 //! - trig.rs     : angles spéciaux + indéfini
 //! - lecture.rs  : ΣLocal (décimal tronqué) + cache π
+//! - signe.rs    : signe / partie entière certifiés (abs, floor, ceil, ...)
+//! - combinatoire.rs : n!, n!!, C(n,k), P(n,k) exacts (garde de taille)
+//! - arith.rs    : pgcd/ppcm/mod, primalité (Miller–Rabin), factorisation (Pollard rho)
+//! - commandes.rs : commandes “hors expression” (factor(…), isprime(…))
+//! - modulaire.rs : contexte "mod n: expr" (Z/nZ, inverses, congruences linéaires)
+//! - radicaux.rs  : corps Q(√d1, …, √dk) (forme unique), rationalisation par conjugués
+//! - fractions_continues.rs : [a0; a1, …], réduites, meilleure approximation
+//! - polynome.rs : polynômes sur Q (division, pgcd, résultants, sans carré)
+//! - facteurs_polynome.rs : factorisation dans Z[x] (Zassenhaus, Hensel)
+//! - polynome_multi.rs : polynômes à plusieurs variables (forme développée, factorisation)
+//! - fractions_rationnelles.rs : P/Q simplifiés par le pgcd (points exclus tracés)
+//! - elements_simples.rs : décomposition en éléments simples (apart)
+//! - algebrique.rs : polynôme minimal d’un nombre algébrique (résultants + factorisation)
+//! - equations.rs : équations à une inconnue (solve : degré 1, 2, discriminant)
+//! - resolution_radicaux.rs : degrés 3 et 4 par radicaux (Cardano, Ferrari)
+//! - isolation.rs : racines réelles isolées (suite de Sturm), encadrements et chiffres certifiés
+//! - systemes.rs : systèmes linéaires {…; …} (élimination de Bareiss, familles paramétrées)
+//! - matrices.rs : matrices exactes [[1, 2], [3, 4]] (+, ·, A^n, det, inv, rank, rref, trace)
+//! - valeurs_propres.rs : charpoly, valeurs et vecteurs propres (Faddeev–LeVerrier, noyau de A - μI)
+//! - derivation.rs : dérivée symbolique diff(f, x) (linéarité, produit, quotient, composées)
+//! - integration.rs : primitives et intégrales définies exactes integrate(f, x[, a, b])
+//! - nullite.rs  : “x = 0 ?” exact (nul / non nul / inconnu)
 //! - eval.rs     : pipeline complet
 
+pub mod algebrique;
+pub mod arith;
 pub mod canon;
+pub mod combinatoire;
+pub mod commandes;
+pub mod derivation;
+pub mod elements_simples;
+pub mod equations;
 pub mod eval;
 pub mod expr;
+pub mod facteurs_polynome;
 pub mod format;
+pub mod fractions_continues;
+pub mod fractions_rationnelles;
 pub mod identites_trig;
+pub mod integration;
+pub mod isolation;
 pub mod jetons;
 pub mod lecture;
+pub mod matrices;
+pub mod modulaire;
+pub mod nullite;
+pub mod polynome;
+pub mod polynome_multi;
+pub mod radicaux;
+pub mod resolution_radicaux;
 pub mod rpn;
+pub mod signe;
+pub mod systemes;
 pub mod trig;
+pub mod valeurs_propres;
 
 #[cfg(test)]
 mod tests_scientifiques;
@@ -0,0 +1,400 @@
+// src/noyau/modulaire.rs
+//
+// Contexte modulaire : "mod n: expr"
+// ----------------------------------
+// - Tous les calculs se font dans Z/nZ (résidus dans [0, n)).
+// - Rationnel p/q : p·q⁻¹ (erreur si q n’est pas inversible modulo n).
+// - a^k : exponentiation rapide (k entier exact, k < 0 => inverse puis puissance).
+// - k! : produit modulo n (0 dès que k ≥ n).
+// - Congruence linéaire : "mod n: a*x + b = c" => x ≡ x0 (mod n/g), g = pgcd(a, n).
+//
+// On évalue directement la RPN (pas d’Expr) : un exposant comme 10^100 ne doit jamais
+// être développé. Chaque valeur est une forme affine a·x + b (au plus une variable),
+// avec sa valeur entière exacte tant qu’elle est connue et raisonnable (pour les exposants).
+//
+// IMPORTANT (SAFE):
+// - Pas de π, pas de racines, pas de trig : erreur explicite plutôt qu’un résidu inventé.
+// - Produit de deux termes en x : refusé (non linéaire).
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+use super::eval::{forme_exacte, DemarcheNoyau};
+use super::expr::Expr;
+use super::jetons::{format_tokens, tokenize, Tok};
+use super::rpn::{is_fonction_ident, to_rpn};
+
+type ResultatModulaire = Result<(String, Option<String>, DemarcheNoyau), String>;
+
+/// Taille maximale (bits) d’une valeur entière exacte suivie (au-delà : résidu seul).
+const BITS_EXACT_MAX: u64 = 4096;
+
+/// k! modulo n : k au-delà de cette borne (et k < n) est refusé.
+const LIMITE_FACTORIELLE_MOD: u64 = 100_000;
+
+/// Nombre maximal de solutions listées pour une congruence (g solutions modulo n).
+const SOLUTIONS_LISTEES_MAX: u64 = 20;
+
+/// Aiguillage : Some(résultat) si `s` commence par "mod <n>:" ; None sinon.
+pub(crate) fn evalue_modulaire(s: &str) -> Option<ResultatModulaire> {
+    let s = s.trim();
+    let tete = s.get(..3)?;
+    if !tete.eq_ignore_ascii_case("mod") {
+        return None;
+    }
+    let reste = &s[3..];
+    // "mod(17,5)" est la fonction, pas le contexte
+    if !reste.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let (module_txt, corps) = reste.split_once(':')?;
+    Some(evalue_contexte(module_txt.trim(), corps.trim()))
+}
+
+fn evalue_contexte(module_txt: &str, corps: &str) -> ResultatModulaire {
+    let n = match forme_exacte(module_txt)?.0 {
+        Expr::Rat(r) if r.is_integer() && r.to_integer() >= BigInt::from(2) => r.to_integer(),
+        _ => return Err("mod : module entier ≥ 2 attendu".into()),
+    };
+    if corps.is_empty() {
+        return Err("mod : expression manquante après ':'".into());
+    }
+
+    let mut ctx = Contexte {
+        n: n.clone(),
+        var: None,
+        preuve: String::new(),
+    };
+
+    let (exact, jetons_txt, rpn_txt) = match corps.split_once('=') {
+        Some((g, d)) => {
+            let (vg, jg, rg) = ctx.evalue_texte(g)?;
+            let (vd, jd, rd) = ctx.evalue_texte(d)?;
+            let diff = ctx.sub(&vg, &vd);
+            let exact = ctx.resout_lineaire(&diff)?;
+            (exact, format!("{jg} = {jd}"), format!("{rg} = {rd}"))
+        }
+        None => {
+            let (v, j, r) = ctx.evalue_texte(corps)?;
+            (ctx.format_affine(&v), j, r)
+        }
+    };
+
+    let d = DemarcheNoyau {
+        jetons: jetons_txt,
+        rpn: rpn_txt,
+        avant: format!("mod {n} : {corps}"),
+        apres: exact.clone(),
+        note: "Mode modulaire : RPN évaluée dans Z/nZ (inverses par Euclide étendu, exponentiation rapide).".into(),
+        preuve: ctx.preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ Valeurs affines modulo n ------------------------ */
+
+/// a·x + b (mod n), et la valeur entière exacte si constante et connue.
+#[derive(Clone, Debug)]
+struct Affine {
+    a: BigInt,
+    b: BigInt,
+    entier: Option<BigInt>,
+}
+
+struct Contexte {
+    n: BigInt,
+    var: Option<String>,
+    preuve: String,
+}
+
+impl Contexte {
+    fn reduit(&self, x: &BigInt) -> BigInt {
+        x.mod_floor(&self.n)
+    }
+
+    fn constante(&self, x: BigInt) -> Affine {
+        Affine {
+            a: BigInt::zero(),
+            b: self.reduit(&x),
+            entier: Some(x).filter(|v| v.bits() <= BITS_EXACT_MAX),
+        }
+    }
+
+    /// Inverse modulo n (Euclide étendu), avec trace dans la preuve.
+    fn inverse(&mut self, x: &BigInt) -> Result<BigInt, String> {
+        let x = self.reduit(x);
+        let e = x.extended_gcd(&self.n);
+        if !e.gcd.is_one() {
+            return Err(format!(
+                "{x} n’est pas inversible modulo {} (pgcd = {})",
+                self.n, e.gcd
+            ));
+        }
+        let inv = self.reduit(&e.x);
+        self.preuve.push_str(&format!(
+            "{x}⁻¹ ≡ {inv} (mod {}) car {x}·{inv} ≡ 1\n",
+            self.n
+        ));
+        Ok(inv)
+    }
+
+    fn evalue_texte(&mut self, txt: &str) -> Result<(Affine, String, String), String> {
+        let jetons = tokenize(txt)?;
+        let rpn = to_rpn(&jetons)?;
+        let v = self.evalue_rpn(&rpn)?;
+        Ok((v, format_tokens(&jetons), format_tokens(&rpn)))
+    }
+
+    fn evalue_rpn(&mut self, rpn: &[Tok]) -> Result<Affine, String> {
+        let mut st: Vec<Affine> = Vec::new();
+
+        for tok in rpn {
+            match tok {
+                Tok::Num(r) => {
+                    let v = if r.is_integer() {
+                        self.constante(r.to_integer())
+                    } else {
+                        let inv = self.inverse(r.denom())?;
+                        let b = self.reduit(&(r.numer() * inv));
+                        Affine {
+                            a: BigInt::zero(),
+                            b,
+                            entier: None,
+                        }
+                    };
+                    st.push(v);
+                }
+
+                Tok::Ident(name) => {
+                    if is_fonction_ident(name) {
+                        return Err(format!(
+                            "mod : fonction {name} indisponible en mode modulaire"
+                        ));
+                    }
+                    match &self.var {
+                        Some(v) if v != name => {
+                            return Err("mod : une seule variable à la fois".into())
+                        }
+                        _ => self.var = Some(name.clone()),
+                    }
+                    st.push(Affine {
+                        a: BigInt::one(),
+                        b: BigInt::zero(),
+                        entier: None,
+                    });
+                }
+
+                Tok::Neg => {
+                    let x = st.pop().ok_or("expression invalide")?;
+                    let zero = self.constante(BigInt::zero());
+                    st.push(self.sub(&zero, &x));
+                }
+
+                Tok::Plus | Tok::Minus | Tok::Star | Tok::Slash | Tok::Caret => {
+                    let y = st.pop().ok_or("expression invalide")?;
+                    let x = st.pop().ok_or("expression invalide")?;
+                    let v = match tok {
+                        Tok::Plus => self.add(&x, &y),
+                        Tok::Minus => self.sub(&x, &y),
+                        Tok::Star => self.mul(&x, &y)?,
+                        Tok::Slash => self.div(&x, &y)?,
+                        _ => self.pow(&x, &y)?,
+                    };
+                    st.push(v);
+                }
+
+                Tok::Bang => {
+                    let x = st.pop().ok_or("'!' sans argument")?;
+                    st.push(self.factorielle(&x)?);
+                }
+
+                Tok::Pi => return Err("mod : π n’a pas de sens modulo n".into()),
+                Tok::Egal => return Err("mod : équation non prise en charge".into()),
+                Tok::BangBang => return Err("mod : !! indisponible en mode modulaire".into()),
+                Tok::Liste(_) => return Err("mod : matrices non prises en charge".into()),
+                Tok::LPar | Tok::RPar | Tok::Comma | Tok::LCrochet | Tok::RCrochet => {
+                    return Err("parenthèse inattendue en RPN".into())
+                }
+            }
+        }
+
+        if st.len() != 1 {
+            return Err("expression invalide".into());
+        }
+        Ok(st.pop().unwrap())
+    }
+
+    fn add(&self, x: &Affine, y: &Affine) -> Affine {
+        Affine {
+            a: self.reduit(&(&x.a + &y.a)),
+            b: self.reduit(&(&x.b + &y.b)),
+            entier: exact_borne(&x.entier, &y.entier, |p, q| p + q),
+        }
+    }
+
+    fn sub(&self, x: &Affine, y: &Affine) -> Affine {
+        Affine {
+            a: self.reduit(&(&x.a - &y.a)),
+            b: self.reduit(&(&x.b - &y.b)),
+            entier: exact_borne(&x.entier, &y.entier, |p, q| p - q),
+        }
+    }
+
+    fn mul(&self, x: &Affine, y: &Affine) -> Result<Affine, String> {
+        if !x.a.is_zero() && !y.a.is_zero() {
+            return Err("mod : produit non linéaire en la variable".into());
+        }
+        Ok(Affine {
+            a: self.reduit(&(&x.a * &y.b + &y.a * &x.b)),
+            b: self.reduit(&(&x.b * &y.b)),
+            entier: exact_borne(&x.entier, &y.entier, |p, q| p * q),
+        })
+    }
+
+    fn div(&mut self, x: &Affine, y: &Affine) -> Result<Affine, String> {
+        if !y.a.is_zero() {
+            return Err("mod : division par une expression en la variable".into());
+        }
+        let inv = self.inverse(&y.b)?;
+        let entier = match (&x.entier, &y.entier) {
+            (Some(p), Some(q)) if !q.is_zero() && (p % q).is_zero() => Some(p / q),
+            _ => None,
+        };
+        Ok(Affine {
+            a: self.reduit(&(&x.a * &inv)),
+            b: self.reduit(&(&x.b * &inv)),
+            entier,
+        })
+    }
+
+    fn pow(&mut self, x: &Affine, k: &Affine) -> Result<Affine, String> {
+        let k = k
+            .entier
+            .clone()
+            .ok_or("mod : exposant entier exact attendu")?;
+        if !x.a.is_zero() {
+            return match k.to_i64() {
+                Some(0) => Ok(self.constante(BigInt::one())),
+                Some(1) => Ok(x.clone()),
+                _ => Err("mod : puissance non linéaire en la variable".into()),
+            };
+        }
+
+        let base = if k.is_negative() {
+            self.inverse(&x.b)?
+        } else {
+            x.b.clone()
+        };
+        let e = k.abs();
+        let r = base.modpow(&e, &self.n);
+        if e.bits() > 6 {
+            self.preuve.push_str(&format!(
+                "{base}^{e} ≡ {r} (mod {}) (exponentiation rapide, {} bits)\n",
+                self.n,
+                e.bits()
+            ));
+        }
+
+        let entier = match (&x.entier, k.to_u32()) {
+            (Some(b), Some(kk)) if b.bits() * u64::from(kk) <= BITS_EXACT_MAX => Some(b.pow(kk)),
+            _ => None,
+        };
+        Ok(Affine {
+            a: BigInt::zero(),
+            b: r,
+            entier,
+        })
+    }
+
+    fn factorielle(&self, x: &Affine) -> Result<Affine, String> {
+        let k = x
+            .entier
+            .as_ref()
+            .filter(|k| !k.is_negative())
+            .ok_or("mod : k! exige un entier exact k ≥ 0")?;
+        if k >= &self.n {
+            return Ok(self.constante(BigInt::zero()));
+        }
+        let k = k
+            .to_u64()
+            .filter(|k| *k <= LIMITE_FACTORIELLE_MOD)
+            .ok_or("mod : k! trop grand (garde de taille)")?;
+        let mut acc = BigInt::one();
+        for i in 2..=k {
+            acc = (acc * i) % &self.n;
+        }
+        Ok(Affine {
+            a: BigInt::zero(),
+            b: acc,
+            entier: None,
+        })
+    }
+
+    /// a·x + b ≡ 0 (mod n).
+    fn resout_lineaire(&mut self, v: &Affine) -> Result<String, String> {
+        let n = self.n.clone();
+        let x = self.var.clone().unwrap_or_else(|| "x".into());
+        let c = self.reduit(&-&v.b);
+
+        if v.a.is_zero() {
+            return Ok(if c.is_zero() {
+                format!("toujours vrai (mod {n})")
+            } else {
+                format!("aucune solution ({} ≢ 0 mod {n})", v.b)
+            });
+        }
+
+        let g = v.a.gcd(&n);
+        self.preuve.push_str(&format!(
+            "{}·{x} ≡ {c} (mod {n}), pgcd({}, {n}) = {g}\n",
+            v.a, v.a
+        ));
+        if !(&c % &g).is_zero() {
+            return Ok(format!("aucune solution ({g} ne divise pas {c})"));
+        }
+
+        let n2 = &n / &g;
+        let a2 = &v.a / &g;
+        let c2 = &c / &g;
+        let x0 = if n2.is_one() {
+            BigInt::zero()
+        } else {
+            let inv = a2.extended_gcd(&n2).x.mod_floor(&n2);
+            (c2 * inv).mod_floor(&n2)
+        };
+
+        let mut out = format!("{x} ≡ {x0} (mod {n2})");
+        if !g.is_one() {
+            match g.to_u64().filter(|g| *g <= SOLUTIONS_LISTEES_MAX) {
+                Some(gg) => {
+                    let sols: Vec<String> = (0..gg).map(|j| (&x0 + &n2 * j).to_string()).collect();
+                    out.push_str(&format!(", soit {x} ∈ {{{}}} (mod {n})", sols.join(", ")));
+                }
+                None => out.push_str(&format!(", soit {g} solutions modulo {n}")),
+            }
+        }
+        Ok(out)
+    }
+
+    fn format_affine(&self, v: &Affine) -> String {
+        let n = &self.n;
+        match (&self.var, v.a.is_zero()) {
+            (Some(x), false) if v.b.is_zero() => format!("{}·{x} (mod {n})", v.a),
+            (Some(x), false) => format!("{}·{x} + {} (mod {n})", v.a, v.b),
+            _ => format!("{} (mod {n})", v.b),
+        }
+    }
+}
+
+/// Combine deux valeurs exactes si elles existent et restent sous BITS_EXACT_MAX.
+fn exact_borne(
+    x: &Option<BigInt>,
+    y: &Option<BigInt>,
+    op: impl Fn(&BigInt, &BigInt) -> BigInt,
+) -> Option<BigInt> {
+    match (x, y) {
+        (Some(p), Some(q)) => Some(op(p, q)).filter(|v| v.bits() <= BITS_EXACT_MAX),
+        _ => None,
+    }
+}
@@ -0,0 +1,119 @@
+// src/noyau/nullite.rs
+//
+// Reconnaissance EXACTE de zéro
+// -----------------------------
+// Question : “x = 0 ?” avec trois réponses honnêtes (nul / non nul / inconnu).
+// 1) Rat : décision immédiate
+// 2) pré-filtre : encadrement certifié par intervalles (signe.rs) ; s’il exclut 0 => non nul
+//    (cas de loin le plus fréquent, et bon marché)
+// 3) procédure de décision : si x se lit dans un corps Q(√d1, …, √dk) (radicaux.rs),
+//    sa forme y est unique => nul ssi elle est vide (√6 - √2·√3 = 0)
+// 4) sinon, si x est algébrique (algebrique.rs) : nul ssi son polynôme minimal est x
+//    (cos(2π/7) + cos(4π/7) + cos(6π/7) + 1/2 = 0, ∛2·∛4 - 2 = 0)
+// 5) sinon : inconnu (π, variables… : on ne devine rien)
+//
+// IMPORTANT (SAFE):
+// - “Nul” n’est jamais déduit d’un encadrement, seulement de l’arithmétique exacte.
+// - “Non nul” vient de l’encadrement certifié ou de l’arithmétique exacte.
+
+use num_traits::Zero;
+use std::cmp::Ordering;
+
+use super::algebrique::polynome_minimal;
+use super::expr::Expr;
+use super::polynome::Polynome;
+use super::radicaux::SommeRadicaux;
+use super::signe::signe_encadrement;
+
+/// Réponse à “x = 0 ?”.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Nullite {
+    Nul,
+    NonNul,
+    Inconnu,
+}
+
+/// Décide si `e` est nul (forme quelconque ; trig déjà évaluée par le pipeline,
+/// cf. commande iszero(…) : sin(π/12) - (√6-√2)/4 => Nul).
+pub fn nullite(e: &Expr) -> Nullite {
+    match e {
+        Expr::Rat(r) => {
+            return if r.is_zero() {
+                Nullite::Nul
+            } else {
+                Nullite::NonNul
+            };
+        }
+        Expr::Indefini => return Nullite::Inconnu,
+        _ => {}
+    }
+
+    // 2) pré-filtre : encadrement certifié
+    if matches!(
+        signe_encadrement(e),
+        Some(Ordering::Less | Ordering::Greater)
+    ) {
+        return Nullite::NonNul;
+    }
+
+    // 3) décision exacte dans Q(√d1, …, √dk)
+    match nullite_exacte(e) {
+        Some(true) => Nullite::Nul,
+        Some(false) => Nullite::NonNul,
+        None => match polynome_minimal(e) {
+            // 4) polynôme minimal (irréductible) : α = 0 ssi c’est x
+            Ok(p) if p == Polynome::x() => Nullite::Nul,
+            Ok(_) => Nullite::NonNul,
+            Err(_) => Nullite::Inconnu,
+        },
+    }
+}
+
+/// Some(nul ?) si `e` se lit dans un corps multi-quadratique, None sinon.
+pub fn nullite_exacte(e: &Expr) -> Option<bool> {
+    SommeRadicaux::depuis_expr(e).map(|x| x.termes.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noyau::eval::forme_exacte;
+    use num_rational::BigRational;
+
+    fn q(s: &str) -> Nullite {
+        nullite(&forme_exacte(s).unwrap().0)
+    }
+
+    #[test]
+    fn zeros_algebriques() {
+        assert_eq!(q("sqrt(6) - sqrt(2)*sqrt(3)"), Nullite::Nul);
+        assert_eq!(q("sin(pi/12) - (sqrt(6)-sqrt(2))/4"), Nullite::Nul);
+        assert_eq!(q("sqrt(3+2*sqrt(2)) - 1 - sqrt(2)"), Nullite::Nul);
+        assert_eq!(q("sqrt(2) + sqrt(3) - sqrt(5+2*sqrt(6))"), Nullite::Nul);
+        assert_eq!(
+            q("cos(2*pi/7) + cos(4*pi/7) + cos(6*pi/7) + 1/2"),
+            Nullite::Nul
+        );
+    }
+
+    #[test]
+    fn non_nuls_et_inconnus() {
+        assert_eq!(q("sqrt(2) - 707/500"), Nullite::NonNul);
+        assert_eq!(q("pi - 355/113"), Nullite::NonNul);
+        assert_eq!(q("x - 1"), Nullite::Inconnu);
+
+        // forme non canon (appel interne depuis simplify) : décidée par le corps
+        let e = Expr::Sub(
+            Box::new(Expr::Sqrt(Box::new(Expr::Rat(BigRational::from_integer(
+                8.into(),
+            ))))),
+            Box::new(Expr::Mul(
+                Box::new(Expr::Rat(BigRational::from_integer(2.into()))),
+                Box::new(Expr::Sqrt(Box::new(Expr::Rat(BigRational::from_integer(
+                    2.into(),
+                ))))),
+            )),
+        );
+        assert_eq!(nullite(&e), Nullite::Nul);
+    }
+}
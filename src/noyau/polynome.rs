@@ -0,0 +1,454 @@
+// src/noyau/polynome.rs
+//
+// Polynômes à une indéterminée sur Q (coefficients BigRational, exacts)
+// ---------------------------------------------------------------------
+// - représentation dense, degrés croissants, jamais de zéro en tête
+// - +, -, ×, division euclidienne, pgcd (unitaire), dérivée, composition
+// - résultant (algorithme d’Euclide sur Q) et résultants “bivariés” par
+//   évaluation / interpolation (Res_y(p(y), q(x - y)) pour la somme de racines, …)
+// - partie primitive dans Z[x], décomposition sans carré (Yun)
+//
+// IMPORTANT (SAFE):
+// - Aucune approximation : toute l’arithmétique est rationnelle.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynome {
+    /// coeffs[i] = coefficient de x^i ; vide = polynôme nul.
+    pub coeffs: Vec<BigRational>,
+}
+
+fn q(n: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(n))
+}
+
+impl Polynome {
+    pub fn nouveau(mut coeffs: Vec<BigRational>) -> Self {
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        Polynome { coeffs }
+    }
+
+    pub fn zero() -> Self {
+        Polynome { coeffs: vec![] }
+    }
+
+    pub fn constante(c: BigRational) -> Self {
+        Self::nouveau(vec![c])
+    }
+
+    /// x
+    pub fn x() -> Self {
+        Self::nouveau(vec![BigRational::zero(), BigRational::one()])
+    }
+
+    /// Depuis des coefficients entiers (degrés croissants).
+    pub fn depuis_entiers(c: &[BigInt]) -> Self {
+        Self::nouveau(
+            c.iter()
+                .map(|n| BigRational::from_integer(n.clone()))
+                .collect(),
+        )
+    }
+
+    pub fn est_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Degré (0 pour le polynôme nul, à tester avec est_zero).
+    pub fn degre(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// Coefficient dominant (0 pour le polynôme nul).
+    pub fn dominant(&self) -> BigRational {
+        self.coeffs
+            .last()
+            .cloned()
+            .unwrap_or_else(BigRational::zero)
+    }
+
+    pub fn coeff(&self, i: usize) -> BigRational {
+        self.coeffs
+            .get(i)
+            .cloned()
+            .unwrap_or_else(BigRational::zero)
+    }
+
+    pub fn add(&self, autre: &Self) -> Self {
+        let n = self.coeffs.len().max(autre.coeffs.len());
+        Self::nouveau((0..n).map(|i| self.coeff(i) + autre.coeff(i)).collect())
+    }
+
+    pub fn sub(&self, autre: &Self) -> Self {
+        let n = self.coeffs.len().max(autre.coeffs.len());
+        Self::nouveau((0..n).map(|i| self.coeff(i) - autre.coeff(i)).collect())
+    }
+
+    pub fn mul(&self, autre: &Self) -> Self {
+        if self.est_zero() || autre.est_zero() {
+            return Self::zero();
+        }
+        let mut c = vec![BigRational::zero(); self.coeffs.len() + autre.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            if a.is_zero() {
+                continue;
+            }
+            for (j, b) in autre.coeffs.iter().enumerate() {
+                c[i + j] += a * b;
+            }
+        }
+        Self::nouveau(c)
+    }
+
+    pub fn mul_scalaire(&self, k: &BigRational) -> Self {
+        Self::nouveau(self.coeffs.iter().map(|c| c * k).collect())
+    }
+
+    /// Division euclidienne (diviseur non nul) : self = q·d + r, deg r < deg d.
+    pub fn divrem(&self, d: &Self) -> (Self, Self) {
+        assert!(!d.est_zero(), "division par le polynôme nul");
+        let mut r = self.coeffs.clone();
+        let dd = d.degre();
+        let lc = d.dominant();
+        if self.coeffs.len() < d.coeffs.len() {
+            return (Self::zero(), self.clone());
+        }
+        let mut quot = vec![BigRational::zero(); self.coeffs.len() - dd];
+        for i in (0..quot.len()).rev() {
+            let c = &r[i + dd] / &lc;
+            if !c.is_zero() {
+                for (j, dj) in d.coeffs.iter().enumerate() {
+                    r[i + j] -= &c * dj;
+                }
+            }
+            quot[i] = c;
+        }
+        r.truncate(dd);
+        (Self::nouveau(quot), Self::nouveau(r))
+    }
+
+    pub fn rem(&self, d: &Self) -> Self {
+        self.divrem(d).1
+    }
+
+    /// Unitaire (coefficient dominant 1) ; nul reste nul.
+    pub fn unitaire(&self) -> Self {
+        if self.est_zero() {
+            return Self::zero();
+        }
+        self.mul_scalaire(&self.dominant().recip())
+    }
+
+    /// pgcd unitaire (0 si les deux sont nuls).
+    pub fn pgcd(&self, autre: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = autre.clone();
+        while !b.est_zero() {
+            let r = a.rem(&b);
+            a = b;
+            b = r;
+        }
+        a.unitaire()
+    }
+
+    /// self^n
+    pub fn puissance(&self, n: u32) -> Self {
+        (0..n).fold(Self::constante(BigRational::one()), |acc, _| acc.mul(self))
+    }
+
+    /// Inverse de self modulo m (None si pgcd(self, m) ≠ 1) : Euclide étendu.
+    pub fn inverse_modulo(&self, m: &Self) -> Option<Self> {
+        let (mut r0, mut r1) = (m.clone(), self.rem(m));
+        let (mut u0, mut u1) = (Self::zero(), Self::constante(BigRational::one()));
+        while !r1.est_zero() {
+            let (qq, r) = r0.divrem(&r1);
+            let u = u0.sub(&qq.mul(&u1));
+            r0 = r1;
+            r1 = r;
+            u0 = u1;
+            u1 = u;
+        }
+        // r0 = u0·self (mod m), constante non nulle si premiers entre eux
+        (r0.degre() == 0 && !r0.est_zero()).then(|| u0.mul_scalaire(&r0.dominant().recip()).rem(m))
+    }
+
+    pub fn derivee(&self) -> Self {
+        Self::nouveau(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * q(i as i64))
+                .collect(),
+        )
+    }
+
+    /// self(x) en un rationnel (Horner).
+    pub fn evalue(&self, x: &BigRational) -> BigRational {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(BigRational::zero(), |acc, c| acc * x + c)
+    }
+
+    /// self(g(x)) (Horner).
+    pub fn compose(&self, g: &Self) -> Self {
+        self.coeffs.iter().rev().fold(Self::zero(), |acc, c| {
+            acc.mul(g).add(&Self::constante(c.clone()))
+        })
+    }
+
+    /// self(-x)
+    pub fn oppose_variable(&self) -> Self {
+        Self::nouveau(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .map(|(i, c)| if i % 2 == 1 { -c } else { c.clone() })
+                .collect(),
+        )
+    }
+
+    /// x^deg · self(1/x) (coefficients renversés).
+    pub fn reciproque(&self) -> Self {
+        let mut c = self.coeffs.clone();
+        c.reverse();
+        Self::nouveau(c)
+    }
+
+    /// self(x^k)
+    pub fn en_puissance(&self, k: usize) -> Self {
+        if self.est_zero() {
+            return Self::zero();
+        }
+        let mut c = vec![BigRational::zero(); self.degre() * k + 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            c[i * k] = a.clone();
+        }
+        Self::nouveau(c)
+    }
+
+    /// Partie primitive dans Z[x] (coefficient dominant > 0) ; contenu rationnel à part.
+    pub fn partie_primitive(&self) -> (BigRational, Vec<BigInt>) {
+        if self.est_zero() {
+            return (BigRational::zero(), vec![]);
+        }
+        let l = self
+            .coeffs
+            .iter()
+            .fold(BigInt::one(), |l, c| l.lcm(c.denom()));
+        let entiers: Vec<BigInt> = self
+            .coeffs
+            .iter()
+            .map(|c| (c * BigRational::from_integer(l.clone())).to_integer())
+            .collect();
+        let mut g = entiers.iter().fold(BigInt::zero(), |g, c| g.gcd(c));
+        if entiers.last().is_some_and(|c| c.is_negative()) {
+            g = -g;
+        }
+        let pp = entiers.iter().map(|c| c / &g).collect();
+        (BigRational::new(g, l), pp)
+    }
+
+    /// Partie primitive comme polynôme (coefficients entiers, dominant > 0).
+    pub fn primitif(&self) -> Self {
+        Self::depuis_entiers(&self.partie_primitive().1)
+    }
+
+    /// Décomposition sans carré (Yun) : self = c · ∏ a_i^i, a_i unitaires sans carré.
+    pub fn sans_carre(&self) -> Vec<(Polynome, u32)> {
+        let mut res = Vec::new();
+        if self.degre() == 0 {
+            return res;
+        }
+        let f = self.unitaire();
+        let df = f.derivee();
+        let mut a = f.pgcd(&df);
+        let mut b = f.divrem(&a).0;
+        let mut c = df.divrem(&a).0;
+        let mut d = c.sub(&b.derivee());
+        let mut i = 1;
+        while b.degre() > 0 {
+            a = b.pgcd(&d);
+            b = b.divrem(&a).0;
+            c = d.divrem(&a).0;
+            if a.degre() > 0 {
+                res.push((a.clone(), i));
+            }
+            d = c.sub(&b.derivee());
+            i += 1;
+        }
+        res
+    }
+
+    /// Affichage “8x^3+4x^2-4x-1” (variable au choix).
+    pub fn format(&self, var: &str) -> String {
+        if self.est_zero() {
+            return "0".into();
+        }
+        let mut s = String::new();
+        for (i, c) in self.coeffs.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            let neg = c.is_negative();
+            let a = c.abs();
+            if s.is_empty() {
+                if neg {
+                    s.push('-');
+                }
+            } else {
+                s.push(if neg { '-' } else { '+' });
+            }
+            let monome = match i {
+                0 => String::new(),
+                1 => var.to_string(),
+                _ => format!("{var}^{i}"),
+            };
+            if i == 0 {
+                s.push_str(&a.to_string());
+            } else if a.is_one() {
+                s.push_str(&monome);
+            } else if a.is_integer() {
+                s.push_str(&format!("{a}{monome}"));
+            } else {
+                s.push_str(&format!("({a}){monome}"));
+            }
+        }
+        s
+    }
+}
+
+/* ------------------------ Résultants ------------------------ */
+
+/// Res(a, b) sur Q (Euclide) ; 0 si facteur commun.
+pub fn resultant(a: &Polynome, b: &Polynome) -> BigRational {
+    if a.est_zero() || b.est_zero() {
+        return BigRational::zero();
+    }
+    let mut a = a.clone();
+    let mut b = b.clone();
+    let mut res = BigRational::one();
+    loop {
+        let da = a.degre();
+        let db = b.degre();
+        if db == 0 {
+            let lc = b.dominant();
+            return res * num_traits::pow(lc, da);
+        }
+        let r = a.rem(&b);
+        if r.est_zero() {
+            return BigRational::zero();
+        }
+        let dr = r.degre();
+        if (da * db) % 2 == 1 {
+            res = -res;
+        }
+        res *= num_traits::pow(b.dominant(), da - dr);
+        a = b;
+        b = r;
+    }
+}
+
+/// Interpolation de Lagrange (Newton) : l’unique polynôme de degré < n passant par (x_i, y_i).
+pub fn interpole(points: &[(BigRational, BigRational)]) -> Polynome {
+    let n = points.len();
+    // différences divisées
+    let mut dd: Vec<BigRational> = points.iter().map(|(_, y)| y.clone()).collect();
+    for j in 1..n {
+        for i in (j..n).rev() {
+            dd[i] = (&dd[i] - &dd[i - 1]) / (&points[i].0 - &points[i - j].0);
+        }
+    }
+    let mut p = Polynome::constante(dd[n - 1].clone());
+    for i in (0..n - 1).rev() {
+        let facteur = Polynome::nouveau(vec![-points[i].0.clone(), BigRational::one()]);
+        p = p.mul(&facteur).add(&Polynome::constante(dd[i].clone()));
+    }
+    p
+}
+
+/// R(x) = Res_y(a(y), b_x(y)) avec b_x construit pour chaque x (degré de R ≤ `degre`) :
+/// évaluation en degre + 1 points entiers puis interpolation.
+pub fn resultant_en_x(
+    a: &Polynome,
+    degre: usize,
+    b_x: impl Fn(&BigRational) -> Polynome,
+) -> Polynome {
+    let points: Vec<(BigRational, BigRational)> = (0..=degre as i64)
+        .map(|i| {
+            let x = q(i);
+            let r = resultant(a, &b_x(&x));
+            (x, r)
+        })
+        .collect();
+    interpole(&points)
+}
+
+/// Polynôme annulant α + β (pa(α) = 0, pb(β) = 0) : Res_y(pa(y), pb(x - y)).
+pub fn annulateur_somme(pa: &Polynome, pb: &Polynome) -> Polynome {
+    resultant_en_x(pa, pa.degre() * pb.degre(), |x| {
+        // pb(x - y) comme polynôme en y
+        pb.compose(&Polynome::nouveau(vec![x.clone(), -BigRational::one()]))
+    })
+}
+
+/// Polynôme annulant α·β : Res_y(pa(y), y^m·pb(x/y)), m = deg pb.
+pub fn annulateur_produit(pa: &Polynome, pb: &Polynome) -> Polynome {
+    let m = pb.degre();
+    resultant_en_x(pa, pa.degre() * m, |x| {
+        let mut c = vec![BigRational::zero(); m + 1];
+        let mut xj = BigRational::one();
+        for j in 0..=m {
+            c[m - j] = pb.coeff(j) * &xj;
+            xj *= x;
+        }
+        Polynome::nouveau(c)
+    })
+}
+
+/// Polynôme annulant α^n (n ≥ 1) : Res_y(pa(y), x - y^n).
+pub fn annulateur_puissance(pa: &Polynome, n: usize) -> Polynome {
+    resultant_en_x(pa, pa.degre(), |x| {
+        let mut c = vec![BigRational::zero(); n + 1];
+        c[0] = x.clone();
+        c[n] = -BigRational::one();
+        Polynome::nouveau(c)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(c: &[i64]) -> Polynome {
+        Polynome::nouveau(c.iter().map(|&n| q(n)).collect())
+    }
+
+    #[test]
+    fn division_et_pgcd() {
+        // (x^2 - 1) = (x - 1)(x + 1)
+        let (qq, r) = p(&[-1, 0, 1]).divrem(&p(&[-1, 1]));
+        assert_eq!(qq, p(&[1, 1]));
+        assert!(r.est_zero());
+        assert_eq!(p(&[-1, 0, 1]).pgcd(&p(&[1, 2, 1])), p(&[1, 1]));
+        assert_eq!(p(&[-1, 0, 0, 4, 8]).format("x"), "8x^4+4x^3-1");
+    }
+
+    #[test]
+    fn resultants_somme_produit() {
+        // √2 + √3 : x^4 - 10x^2 + 1
+        let s = annulateur_somme(&p(&[-2, 0, 1]), &p(&[-3, 0, 1]));
+        assert_eq!(s.primitif(), p(&[1, 0, -10, 0, 1]));
+        // √2·√3 : (x^2 - 6)^2
+        let m = annulateur_produit(&p(&[-2, 0, 1]), &p(&[-3, 0, 1]));
+        assert_eq!(m.primitif(), p(&[36, 0, -12, 0, 1]));
+        assert_eq!(m.sans_carre(), vec![(p(&[-6, 0, 1]), 2)]);
+    }
+}
@@ -0,0 +1,700 @@
+// src/noyau/polynome_multi.rs
+//
+// Polynômes à plusieurs variables sur Q (forme normale développée)
+// ----------------------------------------------------------------
+// Représentation creuse : monôme (variables triées, exposants > 0) => coefficient rationnel.
+// Deux polynômes égaux ont la MÊME représentation : (x+1)^2 - x^2 => 2x+1,
+// x·y - y·x => 0.
+//
+// Conversion depuis Expr : feuilles rationnelles ou variables, +, -, ×, ÷ rationnel,
+// puissance entière ≥ 0 ; tout le reste => None (√x, 1/x, sin(x) ne sont pas polynomiaux).
+//
+// Factorisation sur Q (factor(…)) :
+// 1) contenu rationnel et monôme commun (x²y + xy => xy·(x+1))
+// 2) substitution de Kronecker x1 = t, x2 = t^D1, x3 = t^(D1·D2)… (Di > degré en xi) :
+//    polynôme en t, factorisé dans Z[t] (Zassenhaus, facteurs_polynome.rs)
+// 3) recombinaison : produits de facteurs en t (taille croissante) ramenés en x1, x2, …,
+//    gardés s’ils divisent exactement (division multivariée, ordre lexicographique)
+//
+// pgcd : facteurs irréductibles communs (exposant minimal) des deux factorisations.
+//
+// IMPORTANT (SAFE):
+// - Nombre de termes, exposants et degré de Kronecker bornés (=> None / Err, jamais de blocage).
+// - Un facteur n’est rendu que s’il divise exactement ; irréductibilité par la recombinaison
+//   exhaustive (taille croissante), sinon Err si le budget est dépassé.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::expr::Expr;
+use super::facteurs_polynome::{combinaison_suivante, factorise_polynome};
+use super::polynome::Polynome;
+
+/// Monôme : (variable, exposant > 0), trié par variable ; vide = 1.
+pub type Monome = Vec<(String, u32)>;
+
+/// Nombre maximal de termes d’un polynôme intermédiaire.
+pub const TERMES_MAX: usize = 2000;
+
+/// Exposant maximal accepté dans une puissance (x+1)^n.
+pub const EXPOSANT_MAX: i64 = 64;
+
+/// Refus générique de la lecture polynomiale (les appelants le remplacent par leur
+/// propre message ; les refus de borne, eux, sont transmis tels quels).
+pub const NON_POLYNOMIALE: &str = "expression polynomiale attendue";
+
+/// Refus d’une puissance au-delà de EXPOSANT_MAX.
+pub fn degre_trop_grand(n: i64) -> String {
+    format!("degré trop grand : exposant au plus {EXPOSANT_MAX} (reçu {n})")
+}
+
+/// Message d’une commande : `attendu` pour un refus générique, sinon le refus de borne
+/// (degré, termes) préfixé du nom de la commande.
+pub fn message_refus(nom: &str, err: String, attendu: impl FnOnce() -> String) -> String {
+    if err == NON_POLYNOMIALE {
+        attendu()
+    } else {
+        format!("{nom} : {err}")
+    }
+}
+
+fn trop_de_termes() -> String {
+    format!("polynôme trop gros (au plus {TERMES_MAX} termes)")
+}
+
+/// Degré maximal du polynôme de Kronecker (factorisation).
+const DEGRE_KRONECKER_MAX: usize = 120;
+
+/// Budget de produits testés à la recombinaison.
+const BUDGET_RECOMBINAISON: u64 = 20_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolyMulti {
+    pub termes: BTreeMap<Monome, BigRational>,
+}
+
+/// Produit de deux monômes (fusion des listes triées).
+fn mul_monomes(a: &Monome, b: &Monome) -> Monome {
+    let mut m: BTreeMap<String, u32> = a.iter().cloned().collect();
+    for (v, e) in b {
+        *m.entry(v.clone()).or_insert(0) += e;
+    }
+    m.into_iter().collect()
+}
+
+/// a / b si b divise a.
+fn div_monomes(a: &Monome, b: &Monome) -> Option<Monome> {
+    let mut m: BTreeMap<String, u32> = a.iter().cloned().collect();
+    for (v, e) in b {
+        let ea = m.get_mut(v)?;
+        *ea = ea.checked_sub(*e)?;
+        if *ea == 0 {
+            m.remove(v);
+        }
+    }
+    Some(m.into_iter().collect())
+}
+
+fn exposant(m: &Monome, v: &str) -> u32 {
+    m.iter().find(|(w, _)| w == v).map_or(0, |(_, e)| *e)
+}
+
+fn degre_monome(m: &Monome) -> u32 {
+    m.iter().map(|(_, e)| e).sum()
+}
+
+/// Ordre lexicographique (variables dans l’ordre alphabétique, x > y > z).
+fn cmp_lex(a: &Monome, b: &Monome, vars: &[String]) -> Ordering {
+    vars.iter()
+        .map(|v| exposant(a, v).cmp(&exposant(b, v)))
+        .find(|o| o.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Ordre d’affichage : degré total décroissant, puis lexicographique décroissant.
+fn cmp_affichage(a: &Monome, b: &Monome, vars: &[String]) -> Ordering {
+    degre_monome(b)
+        .cmp(&degre_monome(a))
+        .then_with(|| cmp_lex(b, a, vars))
+}
+
+impl PolyMulti {
+    pub fn zero() -> Self {
+        PolyMulti {
+            termes: BTreeMap::new(),
+        }
+    }
+
+    pub fn constante(c: BigRational) -> Self {
+        let mut termes = BTreeMap::new();
+        if !c.is_zero() {
+            termes.insert(vec![], c);
+        }
+        PolyMulti { termes }
+    }
+
+    pub fn variable(v: &str) -> Self {
+        let mut termes = BTreeMap::new();
+        termes.insert(vec![(v.to_string(), 1)], BigRational::one());
+        PolyMulti { termes }
+    }
+
+    pub fn est_zero(&self) -> bool {
+        self.termes.is_empty()
+    }
+
+    /// Some(c) si le polynôme est constant.
+    pub fn constante_seule(&self) -> Option<BigRational> {
+        match self.termes.len() {
+            0 => Some(BigRational::zero()),
+            1 => self.termes.get(&vec![]).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Coefficient du monôme m (0 s’il est absent).
+    pub fn coefficient(&self, m: &[(String, u32)]) -> BigRational {
+        self.termes
+            .get(m)
+            .cloned()
+            .unwrap_or_else(BigRational::zero)
+    }
+
+    /// Variables présentes (ordre alphabétique).
+    pub fn variables(&self) -> Vec<String> {
+        let s: BTreeSet<&String> = self.termes.keys().flatten().map(|(v, _)| v).collect();
+        s.into_iter().cloned().collect()
+    }
+
+    pub fn degre_total(&self) -> u32 {
+        self.termes.keys().map(degre_monome).max().unwrap_or(0)
+    }
+
+    pub fn degre_en(&self, v: &str) -> u32 {
+        self.termes
+            .keys()
+            .map(|m| exposant(m, v))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn ajoute_terme(&mut self, m: Monome, c: BigRational) {
+        let total = self.termes.remove(&m).unwrap_or_else(BigRational::zero) + c;
+        if !total.is_zero() {
+            self.termes.insert(m, total);
+        }
+    }
+
+    pub fn add(&self, autre: &Self) -> Self {
+        let mut r = self.clone();
+        for (m, c) in &autre.termes {
+            r.ajoute_terme(m.clone(), c.clone());
+        }
+        r
+    }
+
+    pub fn neg(&self) -> Self {
+        PolyMulti {
+            termes: self.termes.iter().map(|(m, c)| (m.clone(), -c)).collect(),
+        }
+    }
+
+    pub fn sub(&self, autre: &Self) -> Self {
+        self.add(&autre.neg())
+    }
+
+    pub fn mul_scalaire(&self, k: &BigRational) -> Self {
+        if k.is_zero() {
+            return Self::zero();
+        }
+        PolyMulti {
+            termes: self
+                .termes
+                .iter()
+                .map(|(m, c)| (m.clone(), c * k))
+                .collect(),
+        }
+    }
+
+    pub fn mul(&self, autre: &Self) -> Self {
+        let mut r = Self::zero();
+        for (ma, ca) in &self.termes {
+            for (mb, cb) in &autre.termes {
+                r.ajoute_terme(mul_monomes(ma, mb), ca * cb);
+            }
+        }
+        r
+    }
+
+    /// self^n ; None si le nombre de termes dépasse TERMES_MAX.
+    pub fn puissance(&self, n: u32) -> Option<Self> {
+        let mut r = Self::constante(BigRational::one());
+        for _ in 0..n {
+            r = r.mul(self);
+            if r.termes.len() > TERMES_MAX {
+                return None;
+            }
+        }
+        Some(r)
+    }
+
+    /* ---- Conversion Expr ---- */
+
+    /// Lecture polynomiale d’une expression. Err(NON_POLYNOMIALE) si e n’est pas
+    /// polynomiale ; message précis si une borne est atteinte (degré, termes).
+    pub fn depuis_expr(e: &Expr) -> Result<Self, String> {
+        let p = match e {
+            Expr::Rat(r) => Self::constante(r.clone()),
+            Expr::Var(v) => Self::variable(v),
+            Expr::Add(a, b) => Self::depuis_expr(a)?.add(&Self::depuis_expr(b)?),
+            Expr::Sub(a, b) => Self::depuis_expr(a)?.sub(&Self::depuis_expr(b)?),
+            Expr::Mul(a, b) => Self::depuis_expr(a)?.mul(&Self::depuis_expr(b)?),
+            Expr::Div(a, b) => match b.as_ref() {
+                Expr::Rat(r) if !r.is_zero() => Self::depuis_expr(a)?.mul_scalaire(&r.recip()),
+                _ => return Err(NON_POLYNOMIALE.into()),
+            },
+            Expr::PowInt(a, n) if (0..=EXPOSANT_MAX).contains(n) => Self::depuis_expr(a)?
+                .puissance(*n as u32)
+                .ok_or_else(trop_de_termes)?,
+            Expr::PowInt(a, n) if *n > EXPOSANT_MAX => {
+                // base polynomiale : c’est la borne qui refuse, on le dit
+                Self::depuis_expr(a)?;
+                return Err(degre_trop_grand(*n));
+            }
+            _ => return Err(NON_POLYNOMIALE.into()),
+        };
+        if p.termes.len() > TERMES_MAX {
+            return Err(trop_de_termes());
+        }
+        Ok(p)
+    }
+
+    /// Monômes dans l’ordre d’affichage.
+    fn termes_ordonnes(&self) -> Vec<(&Monome, &BigRational)> {
+        let vars = self.variables();
+        let mut t: Vec<_> = self.termes.iter().collect();
+        t.sort_by(|(a, _), (b, _)| cmp_affichage(a, b, &vars));
+        t
+    }
+
+    /// Forme développée en Expr : ((x^2+(2*x))+1), termes négatifs en Sub.
+    pub fn vers_expr(&self) -> Expr {
+        let mut acc: Option<Expr> = None;
+        for (m, c) in self.termes_ordonnes() {
+            let terme = monome_vers_expr(m, &c.abs());
+            acc = Some(match acc {
+                None if c.is_negative() => match terme {
+                    Expr::Rat(r) => Expr::Rat(-r),
+                    t => Expr::Sub(Box::new(Expr::Rat(BigRational::zero())), Box::new(t)),
+                },
+                None => terme,
+                Some(a) if c.is_negative() => Expr::Sub(Box::new(a), Box::new(terme)),
+                Some(a) => Expr::Add(Box::new(a), Box::new(terme)),
+            });
+        }
+        acc.unwrap_or_else(|| Expr::Rat(BigRational::zero()))
+    }
+
+    /// Affichage compact “x^2+2x*y+y^2” (même style que Polynome::format).
+    pub fn format(&self) -> String {
+        if self.est_zero() {
+            return "0".into();
+        }
+        let mut s = String::new();
+        for (m, c) in self.termes_ordonnes() {
+            let neg = c.is_negative();
+            let a = c.abs();
+            if s.is_empty() {
+                if neg {
+                    s.push('-');
+                }
+            } else {
+                s.push(if neg { '-' } else { '+' });
+            }
+            let monome = m
+                .iter()
+                .map(|(v, e)| {
+                    if *e == 1 {
+                        v.clone()
+                    } else {
+                        format!("{v}^{e}")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("*");
+            if m.is_empty() {
+                s.push_str(&a.to_string());
+            } else if a.is_one() {
+                s.push_str(&monome);
+            } else if a.is_integer() {
+                s.push_str(&format!("{a}{monome}"));
+            } else {
+                s.push_str(&format!("({a}){monome}"));
+            }
+        }
+        s
+    }
+
+    /* ---- Contenu, division exacte ---- */
+
+    /// self = contenu · P, P à coefficients entiers premiers entre eux,
+    /// terme dominant (ordre d’affichage) positif.
+    pub fn partie_primitive(&self) -> (BigRational, PolyMulti) {
+        let Some((_, dominant)) = self
+            .termes_ordonnes()
+            .first()
+            .map(|(m, c)| (*m, (*c).clone()))
+        else {
+            return (BigRational::zero(), Self::zero());
+        };
+        let l = self
+            .termes
+            .values()
+            .fold(BigInt::one(), |l, c| l.lcm(c.denom()));
+        let g = self.termes.values().fold(BigInt::zero(), |g, c| {
+            g.gcd(&(c * BigRational::from_integer(l.clone())).to_integer())
+        });
+        let mut contenu = BigRational::new(g, l);
+        if dominant.is_negative() {
+            contenu = -contenu;
+        }
+        (contenu.clone(), self.mul_scalaire(&contenu.recip()))
+    }
+
+    /// Terme dominant pour l’ordre lexicographique sur `vars`.
+    fn dominant_lex(&self, vars: &[String]) -> Option<(Monome, BigRational)> {
+        self.termes
+            .iter()
+            .max_by(|(a, _), (b, _)| cmp_lex(a, b, vars))
+            .map(|(m, c)| (m.clone(), c.clone()))
+    }
+
+    /// Quotient exact self / d (None si d ne divise pas self).
+    pub fn divise(&self, d: &Self) -> Option<Self> {
+        let mut vars: BTreeSet<String> = self.variables().into_iter().collect();
+        vars.extend(d.variables());
+        let vars: Vec<String> = vars.into_iter().collect();
+
+        let (md, cd) = d.dominant_lex(&vars)?;
+        let mut quotient = Self::zero();
+        let mut reste = self.clone();
+        while let Some((mr, cr)) = reste.dominant_lex(&vars) {
+            let m = div_monomes(&mr, &md)?;
+            let mut t = Self::zero();
+            t.termes.insert(m, cr / &cd);
+            reste = reste.sub(&t.mul(d));
+            quotient = quotient.add(&t);
+        }
+        Some(quotient)
+    }
+
+    /* ---- Substitution de Kronecker ---- */
+
+    /// x1 = t, x2 = t^D1, … (poids[i] = D1·…·D(i-1)).
+    fn kronecker(&self, vars: &[String], poids: &[usize]) -> Polynome {
+        let degre = self
+            .termes
+            .keys()
+            .map(|m| poids_monome(m, vars, poids))
+            .max()
+            .unwrap_or(0);
+        let mut c = vec![BigRational::zero(); degre + 1];
+        for (m, a) in &self.termes {
+            c[poids_monome(m, vars, poids)] += a;
+        }
+        Polynome::nouveau(c)
+    }
+
+    /// Inverse de la substitution (écriture de l’exposant en base mixte D1, D2, …).
+    fn depuis_kronecker(p: &Polynome, vars: &[String], degres: &[usize]) -> Self {
+        let mut r = Self::zero();
+        for (i, c) in p.coeffs.iter().enumerate() {
+            if c.is_zero() {
+                continue;
+            }
+            let mut reste = i;
+            let mut m = Monome::new();
+            for (v, d) in vars.iter().zip(degres) {
+                let e = reste % d;
+                reste /= d;
+                if e > 0 {
+                    m.push((v.clone(), e as u32));
+                }
+            }
+            r.ajoute_terme(m, c.clone());
+        }
+        r
+    }
+}
+
+fn poids_monome(m: &Monome, vars: &[String], poids: &[usize]) -> usize {
+    vars.iter()
+        .zip(poids)
+        .map(|(v, w)| exposant(m, v) as usize * w)
+        .sum()
+}
+
+/// Monôme |c|·x^a·y^b en Expr (produit à gauche, rationnel en tête).
+fn monome_vers_expr(m: &Monome, c: &BigRational) -> Expr {
+    let mut facteurs: Vec<Expr> = Vec::new();
+    if !c.is_one() || m.is_empty() {
+        facteurs.push(Expr::Rat(c.clone()));
+    }
+    for (v, e) in m {
+        let x = Expr::Var(v.clone());
+        facteurs.push(if *e == 1 {
+            x
+        } else {
+            Expr::PowInt(Box::new(x), i64::from(*e))
+        });
+    }
+    let mut it = facteurs.into_iter();
+    let premier = it.next().unwrap_or_else(|| Expr::Rat(BigRational::one()));
+    it.fold(premier, |acc, f| Expr::Mul(Box::new(acc), Box::new(f)))
+}
+
+/* ------------------------ Factorisation ------------------------ */
+
+/// f = contenu · ∏ g_i^e_i, g_i irréductibles sur Q (coefficients entiers, dominant > 0),
+/// triés par degré total, nombre de termes, puis monômes.
+pub fn factorise_multi(f: &PolyMulti) -> Result<(BigRational, Vec<(PolyMulti, u32)>), String> {
+    if f.est_zero() {
+        return Err("factorisation : polynôme nul".into());
+    }
+    let (mut contenu, mut reste) = f.partie_primitive();
+    let mut facteurs: Vec<PolyMulti> = Vec::new();
+
+    // monôme commun
+    let vars = reste.variables();
+    for v in &vars {
+        let e = reste
+            .termes
+            .keys()
+            .map(|m| exposant(m, v))
+            .min()
+            .unwrap_or(0);
+        if e > 0 {
+            let x = PolyMulti::variable(v);
+            for _ in 0..e {
+                reste = reste
+                    .divise(&x)
+                    .ok_or("factorisation : monôme commun incohérent")?;
+                facteurs.push(x.clone());
+            }
+        }
+    }
+
+    if reste.constante_seule().is_none() {
+        let (c, irreductibles) = factorise_primitif(&reste)?;
+        contenu *= c;
+        facteurs.extend(irreductibles);
+    } else if let Some(c) = reste.constante_seule() {
+        contenu *= c;
+    }
+
+    // regroupement des facteurs égaux
+    facteurs.sort_by_cached_key(cle_tri);
+    let mut groupes: Vec<(PolyMulti, u32)> = Vec::new();
+    for g in facteurs {
+        match groupes.last_mut() {
+            Some((h, e)) if *h == g => *e += 1,
+            _ => groupes.push((g, 1)),
+        }
+    }
+    Ok((contenu, groupes))
+}
+
+/// pgcd(a, b) (primitif, dominant > 0) et ses facteurs irréductibles distincts,
+/// par comparaison des factorisations (a, b non nuls).
+pub fn pgcd_multi(a: &PolyMulti, b: &PolyMulti) -> Result<(PolyMulti, Vec<PolyMulti>), String> {
+    let (_, fa) = factorise_multi(a)?;
+    let (_, fb) = factorise_multi(b)?;
+    let mut pgcd = PolyMulti::constante(BigRational::one());
+    let mut communs = Vec::new();
+    for (g, ea) in fa {
+        if let Some((_, eb)) = fb.iter().find(|(h, _)| *h == g) {
+            for _ in 0..ea.min(*eb) {
+                pgcd = pgcd.mul(&g);
+            }
+            communs.push(g);
+        }
+    }
+    Ok((pgcd, communs))
+}
+
+/// Tri des facteurs : degré total, nombre de termes, puis monômes (ordre d’affichage)
+/// et coefficients (x-1 avant x+1).
+fn cle_tri(g: &PolyMulti) -> (u32, usize, Vec<(Monome, BigRational)>) {
+    let t = g
+        .termes_ordonnes()
+        .into_iter()
+        .map(|(m, c)| (m.clone(), c.clone()))
+        .collect();
+    (g.degre_total(), g.termes.len(), t)
+}
+
+/// Facteurs irréductibles (avec répétition) de f primitif sans monôme commun.
+fn factorise_primitif(f: &PolyMulti) -> Result<(BigRational, Vec<PolyMulti>), String> {
+    let vars = f.variables();
+    let degres: Vec<usize> = vars.iter().map(|v| f.degre_en(v) as usize + 1).collect();
+    let mut poids = Vec::with_capacity(vars.len());
+    let mut w = 1usize;
+    for d in &degres {
+        poids.push(w);
+        w = w
+            .checked_mul(*d)
+            .filter(|w| *w <= 4 * DEGRE_KRONECKER_MAX)
+            .ok_or("factorisation : degrés trop élevés (substitution de Kronecker)")?;
+    }
+    let image = f.kronecker(&vars, &poids);
+    if image.degre() > DEGRE_KRONECKER_MAX {
+        return Err(format!(
+            "factorisation : degré de Kronecker {} > {DEGRE_KRONECKER_MAX}",
+            image.degre()
+        ));
+    }
+
+    let (_, facteurs_t) = factorise_polynome(&image)?;
+    let mut candidats: Vec<Polynome> = Vec::new();
+    for (g, e) in facteurs_t {
+        for _ in 0..e {
+            candidats.push(g.clone());
+        }
+    }
+
+    let mut reste = f.clone();
+    let mut trouves = Vec::new();
+    let mut budget = BUDGET_RECOMBINAISON;
+    let mut taille = 1;
+    'tailles: while 2 * taille <= candidats.len() {
+        let mut choix: Vec<usize> = (0..taille).collect();
+        loop {
+            if budget == 0 {
+                return Err("factorisation : budget de recombinaison dépassé".into());
+            }
+            budget -= 1;
+
+            let produit = choix
+                .iter()
+                .fold(Polynome::constante(BigRational::one()), |acc, &i| {
+                    acc.mul(&candidats[i])
+                });
+            let h = PolyMulti::depuis_kronecker(&produit, &vars, &degres)
+                .partie_primitive()
+                .1;
+            if h.constante_seule().is_none() {
+                if let Some(qq) = reste.divise(&h) {
+                    reste = qq;
+                    trouves.push(h);
+                    for &i in choix.iter().rev() {
+                        candidats.remove(i);
+                    }
+                    continue 'tailles;
+                }
+            }
+            if !combinaison_suivante(&mut choix, candidats.len()) {
+                break;
+            }
+        }
+        taille += 1;
+    }
+
+    let (c, dernier) = reste.partie_primitive();
+    if dernier.constante_seule().is_none() {
+        trouves.push(dernier);
+    }
+    Ok((c, trouves))
+}
+
+/// “2·(x-1)·(x+1)^2” ; facteurs à un seul terme sans parenthèses.
+pub fn format_factorisation_multi(contenu: &BigRational, facteurs: &[(PolyMulti, u32)]) -> String {
+    let mut morceaux: Vec<String> = Vec::new();
+    let mut signe = "";
+    if facteurs.is_empty() || !contenu.abs().is_one() {
+        morceaux.push(contenu.abs().to_string());
+    }
+    if contenu.is_negative() {
+        signe = "-";
+    }
+    // facteur unique, sans contenu ni exposant : pas de parenthèses (x^2+1)
+    let seul = morceaux.is_empty() && signe.is_empty() && matches!(facteurs, [(_, 1)]);
+    for (g, e) in facteurs {
+        let s = g.format();
+        let s = if g.termes.len() == 1 || seul {
+            s
+        } else {
+            format!("({s})")
+        };
+        morceaux.push(if *e == 1 { s } else { format!("{s}^{e}") });
+    }
+    format!("{signe}{}", morceaux.join("·"))
+}
+
+/// Conversion univariée si `f` ne dépend que de `v` (ou est constant).
+pub fn vers_univarie(f: &PolyMulti, v: &str) -> Option<Polynome> {
+    let mut c = vec![BigRational::zero(); f.degre_en(v) as usize + 1];
+    for (m, a) in &f.termes {
+        if m.iter().any(|(w, _)| w != v) {
+            return None;
+        }
+        c[exposant(m, v) as usize] = a.clone();
+    }
+    Some(Polynome::nouveau(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noyau::eval::forme_exacte;
+
+    fn p(s: &str) -> PolyMulti {
+        PolyMulti::depuis_expr(&forme_exacte(s).unwrap().0).unwrap()
+    }
+
+    fn f(s: &str) -> String {
+        let (c, g) = factorise_multi(&p(s)).unwrap();
+        format_factorisation_multi(&c, &g)
+    }
+
+    #[test]
+    fn forme_normale() {
+        assert_eq!(p("(x+1)^2").format(), "x^2+2x+1");
+        assert_eq!(p("(x+y)^3").format(), "x^3+3x^2*y+3x*y^2+y^3");
+        assert_eq!(p("(x+1)*(x-1) - x^2").format(), "-1");
+        assert_eq!(p("(a-b)*(a+b)/2").format(), "(1/2)a^2-(1/2)b^2");
+        assert!(PolyMulti::depuis_expr(&Expr::Sqrt(Box::new(Expr::Var("x".into())))).is_err());
+    }
+
+    #[test]
+    fn factorisation() {
+        assert_eq!(f("x^2-1"), "(x-1)·(x+1)");
+        assert_eq!(f("2*x^3-2*x"), "2·x·(x-1)·(x+1)");
+        assert_eq!(f("x^2+2*x*y+y^2"), "(x+y)^2");
+        assert_eq!(f("x^2-y^2"), "(x-y)·(x+y)");
+        assert_eq!(f("x^4+4"), "(x^2-2x+2)·(x^2+2x+2)");
+        assert_eq!(f("x^2*y + x*y"), "x·y·(x+1)");
+        assert_eq!(f("x^2+1"), "x^2+1");
+        assert_eq!(f("x^3*y^2 - x*y^4 + x^2*y - y^3"), "y·(x-y)·(x+y)·(x*y+1)");
+    }
+
+    #[test]
+    fn pgcd() {
+        let (g, communs) = pgcd_multi(&p("x^3 - x*y^2"), &p("x^2 + 2*x*y + y^2")).unwrap();
+        assert_eq!(g.format(), "x+y");
+        assert_eq!(communs.len(), 1);
+        let (g, _) = pgcd_multi(&p("x^2 + 1"), &p("x - 1")).unwrap();
+        assert_eq!(g.format(), "1");
+    }
+
+    #[test]
+    fn division_exacte() {
+        let a = p("x^3 - y^3");
+        let b = p("x - y");
+        assert_eq!(a.divise(&b).unwrap().format(), "x^2+x*y+y^2");
+        assert!(p("x^2 + 1").divise(&b).is_none());
+    }
+}
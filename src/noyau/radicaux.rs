@@ -0,0 +1,639 @@
+// src/noyau/radicaux.rs
+//
+// Corps multi-quadratiques Q(√d1, …, √dk)
+// ---------------------------------------
+// Un élément est une somme c0 + c1·√m1 + … (ci rationnels, mi sans facteur carré,
+// produits des di) : c’est une forme UNIQUE, donc deux expressions égales dans le corps
+// ont la même forme canon ((√2+√3)² - 2√6 => 5, (1+√2)^5 => 41 + 29√2).
+// Opérations exactes : +, -, ×, ÷ (inverse par conjugués), puissance entière.
+//
+// Dénouement : √(a + b√c) = √((a+d)/2) + sgn(b)·√((a-d)/2) si a > 0 et
+// a² - b²c = d² (d rationnel) ; √(3+2√2) = 1 + √2, √((2-√3)/4) = (√6-√2)/4.
+//
+// Rationalisation des dénominateurs par conjugués :
+//   1/(1+√2)      : × (1-√2)                 => √2 - 1
+//   1/(√2+√3)     : × (√2-√3)                => √3 - √2
+//   1/(1+√2+√3)   : × (1+√2-√3), puis × (…-√2…) (un premier à la fois)
+//
+// Conjugué par rapport à un premier p : on change le signe des termes dont le radicande
+// est divisible par p. Si D = A + B·√p (A, B sans √p), D·conj = A² - p·B² ne contient
+// plus √p ; on recommence jusqu’à un dénominateur rationnel.
+//
+// IMPORTANT (SAFE):
+// - Radicandes négatifs refusés (pas de √ de négatif ici).
+// - Nombre de premiers distincts et de termes bornés (2^k termes au pire).
+// - Dénominateur devenu 0 : on laisse l’expression telle quelle (division par zéro).
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::collections::BTreeMap;
+
+use super::arith::factorise;
+use super::canon::extrait_puissance_parfaite;
+use super::expr::{map_matrice, rational_sqrt_exact, Expr};
+use super::format::{entre_parentheses as parentheses, format_expr_pretty};
+
+/// Premiers distincts autorisés dans un dénominateur à rationaliser.
+const PREMIERS_MAX: usize = 6;
+
+/// Taille maximale (bits) d’un coefficient pendant (somme)^n : au-delà, la puissance
+/// reste symbolique (tracé dans la démarche).
+const COEFF_BITS_MAX: u64 = 1 << 13;
+
+/// Nombre maximal de termes d’un élément (au-delà : on laisse l’expression telle quelle).
+const TERMES_MAX: usize = 256;
+
+/// Σ coeff·√radicande ; radicande 1 = partie rationnelle. Jamais de coefficient nul.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SommeRadicaux {
+    pub termes: BTreeMap<BigInt, BigRational>,
+}
+
+impl SommeRadicaux {
+    pub fn rationnel(r: BigRational) -> Self {
+        let mut termes = BTreeMap::new();
+        if !r.is_zero() {
+            termes.insert(BigInt::one(), r);
+        }
+        SommeRadicaux { termes }
+    }
+
+    /// √r, r rationnel ≥ 0 : √(p/q) = √(p·q)/q, puis extraction des carrés.
+    pub fn racine(r: &BigRational) -> Option<Self> {
+        if r.is_negative() {
+            return None;
+        }
+        if r.is_zero() {
+            return Some(Self::rationnel(BigRational::zero()));
+        }
+        let q = r.denom().clone();
+        let (s, t) = extrait_puissance_parfaite(&(r.numer() * &q), 2);
+        let mut termes = BTreeMap::new();
+        termes.insert(t, BigRational::new(s, q));
+        Some(SommeRadicaux { termes })
+    }
+
+    pub fn est_rationnel(&self) -> bool {
+        self.termes.keys().all(|d| d.is_one())
+    }
+
+    /// Partie rationnelle (si pas de radical).
+    pub fn valeur_rationnelle(&self) -> Option<BigRational> {
+        if !self.est_rationnel() {
+            return None;
+        }
+        Some(
+            self.termes
+                .values()
+                .next()
+                .cloned()
+                .unwrap_or_else(BigRational::zero),
+        )
+    }
+
+    fn ajoute_terme(&mut self, d: BigInt, c: BigRational) {
+        let somme = self
+            .termes
+            .get(&d)
+            .cloned()
+            .unwrap_or_else(BigRational::zero)
+            + c;
+        if somme.is_zero() {
+            self.termes.remove(&d);
+        } else {
+            self.termes.insert(d, somme);
+        }
+    }
+
+    pub fn add(&self, autre: &Self) -> Self {
+        let mut r = self.clone();
+        for (d, c) in &autre.termes {
+            r.ajoute_terme(d.clone(), c.clone());
+        }
+        r
+    }
+
+    pub fn neg(&self) -> Self {
+        SommeRadicaux {
+            termes: self.termes.iter().map(|(d, c)| (d.clone(), -c)).collect(),
+        }
+    }
+
+    pub fn sub(&self, autre: &Self) -> Self {
+        self.add(&autre.neg())
+    }
+
+    /// √a·√b = g·√(ab/g²), g = pgcd(a, b) (a, b sans facteur carré).
+    pub fn mul(&self, autre: &Self) -> Self {
+        let mut r = SommeRadicaux {
+            termes: BTreeMap::new(),
+        };
+        for (a, ca) in &self.termes {
+            for (b, cb) in &autre.termes {
+                let g = a.gcd(b);
+                let d = (a / &g) * (b / &g);
+                r.ajoute_terme(d, ca * cb * BigRational::from_integer(g));
+            }
+        }
+        r
+    }
+
+    pub fn mul_rationnel(&self, k: &BigRational) -> Self {
+        self.mul(&Self::rationnel(k.clone()))
+    }
+
+    /// self^n (n ≥ 0), exponentiation rapide.
+    /// self^n ; None dès qu’un coefficient dépasse COEFF_BITS_MAX bits.
+    pub fn puissance(&self, n: u64) -> Option<Self> {
+        let mut r = Self::rationnel(BigRational::one());
+        let mut b = self.clone();
+        let mut n = n;
+        // taille d’un produit ≈ somme des tailles : refus AVANT de multiplier
+        let trop_grand = |u: &Self, v: &Self| u.bits_max() + v.bits_max() > COEFF_BITS_MAX;
+        while n > 0 {
+            if n & 1 == 1 {
+                if trop_grand(&r, &b) {
+                    return None;
+                }
+                r = r.mul(&b);
+            }
+            n >>= 1;
+            if n > 0 {
+                if trop_grand(&b, &b) {
+                    return None;
+                }
+                b = b.mul(&b);
+            }
+        }
+        Some(r)
+    }
+
+    /// Taille (bits) du plus grand numérateur / dénominateur des coefficients.
+    fn bits_max(&self) -> u64 {
+        self.termes
+            .values()
+            .map(|c| c.numer().bits().max(c.denom().bits()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Conjugué par rapport au premier p : √d ↦ -√d si p | d.
+    pub fn conjugue(&self, p: &BigInt) -> Self {
+        SommeRadicaux {
+            termes: self
+                .termes
+                .iter()
+                .map(|(d, c)| {
+                    if (d % p).is_zero() {
+                        (d.clone(), -c)
+                    } else {
+                        (d.clone(), c.clone())
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Premiers distincts apparaissant dans les radicandes (triés).
+    pub fn premiers(&self) -> Vec<BigInt> {
+        let mut ps: Vec<BigInt> = Vec::new();
+        for d in self.termes.keys().filter(|d| !d.is_one()) {
+            let f = factorise(d);
+            for (p, _) in f.premiers {
+                if !ps.contains(&p) {
+                    ps.push(p);
+                }
+            }
+            for m in f.non_resolus {
+                if !ps.contains(&m) {
+                    ps.push(m);
+                }
+            }
+        }
+        ps.sort();
+        ps
+    }
+
+    /// Inverse exact (conjugués successifs) ; None pour 0 ou trop de premiers.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut trace = String::new();
+        let (facteur, n) = conjugues_successifs(self, &mut trace)?;
+        Some(facteur.mul_rationnel(&n.recip()))
+    }
+
+    pub fn div(&self, autre: &Self) -> Option<Self> {
+        Some(self.mul(&autre.inverse()?))
+    }
+
+    /// Lecture depuis Expr (Rat, √rationnel, +, -, ×, ÷, puissance entière bornée).
+    pub fn depuis_expr(e: &Expr) -> Option<Self> {
+        use Expr::*;
+        let r = match e {
+            Rat(r) => Self::rationnel(r.clone()),
+            Sqrt(x) => match x.as_ref() {
+                Rat(r) => Self::racine(r)?,
+                _ => return None,
+            },
+            Add(a, b) => Self::depuis_expr(a)?.add(&Self::depuis_expr(b)?),
+            Sub(a, b) => Self::depuis_expr(a)?.sub(&Self::depuis_expr(b)?),
+            Mul(a, b) => Self::depuis_expr(a)?.mul(&Self::depuis_expr(b)?),
+            Div(a, b) => Self::depuis_expr(a)?.div(&Self::depuis_expr(b)?)?,
+            PowInt(x, n) if *n >= 0 => Self::depuis_expr(x)?.puissance(n.unsigned_abs())?,
+            PowInt(x, n) => Self::depuis_expr(x)?
+                .inverse()?
+                .puissance(n.unsigned_abs())?,
+            _ => return None,
+        };
+        (r.termes.len() <= TERMES_MAX).then_some(r)
+    }
+
+    /// Retour vers Expr (forme canon) : un seul terme => c·√m ; sinon
+    /// dénominateur commun (1 + √5)/2 plutôt que 1/2 + √5/2.
+    pub fn vers_expr(&self) -> Expr {
+        use Expr::*;
+        let l = self
+            .termes
+            .values()
+            .fold(BigInt::one(), |l, c| l.lcm(c.denom()));
+        let commun = self.termes.len() > 1 && !l.is_one();
+        let facteur = BigRational::from_integer(if commun { l.clone() } else { BigInt::one() });
+
+        let mut acc: Option<Expr> = None;
+        for (d, c) in &self.termes {
+            let c = c * &facteur;
+            let t = if d.is_one() {
+                Rat(c)
+            } else {
+                Mul(
+                    Box::new(Rat(c)),
+                    Box::new(Sqrt(Box::new(Rat(BigRational::from_integer(d.clone()))))),
+                )
+            };
+            acc = Some(match acc {
+                None => t,
+                Some(a) => Add(Box::new(a), Box::new(t)),
+            });
+        }
+        let somme = acc.unwrap_or_else(|| Rat(BigRational::zero()));
+        if commun {
+            Div(Box::new(somme), Box::new(Rat(BigRational::from_integer(l)))).canon()
+        } else {
+            somme.canon()
+        }
+    }
+}
+
+/* ------------------------ Forme canon dans le corps ------------------------ */
+
+/// Remplace chaque sous-arbre maximal lisible dans Q(√d1, …) par sa forme unique.
+/// Une puissance d’élément du corps laissée symbolique (coefficients trop grands) est
+/// tracée dans la preuve.
+pub fn normalise_radicaux(e: Expr, preuve: &mut String) -> Expr {
+    use Expr::*;
+    if let Some(x) = SommeRadicaux::depuis_expr(&e) {
+        return x.vers_expr();
+    }
+    if let PowInt(x, _) = &e {
+        if SommeRadicaux::depuis_expr(x).is_some() {
+            pousse_ligne(
+                preuve,
+                &format!(
+                    "{} non développé : coefficients au-delà de {COEFF_BITS_MAX} bits",
+                    format_expr_pretty(&e)
+                ),
+            );
+        }
+    }
+    let r = |x: Box<Expr>, preuve: &mut String| Box::new(normalise_radicaux(*x, preuve));
+
+    match e {
+        Add(a, b) => Add(r(a, preuve), r(b, preuve)),
+        Sub(a, b) => Sub(r(a, preuve), r(b, preuve)),
+        Mul(a, b) => Mul(r(a, preuve), r(b, preuve)),
+        Div(a, b) => Div(r(a, preuve), r(b, preuve)),
+
+        Sqrt(x) => Sqrt(r(x, preuve)),
+        Root(x, k) => Root(r(x, preuve), k),
+        PowInt(x, n) => PowInt(r(x, preuve), n),
+        Pow(x, y) => Pow(r(x, preuve), r(y, preuve)),
+
+        Sin(x) => Sin(r(x, preuve)),
+        Cos(x) => Cos(r(x, preuve)),
+        Tan(x) => Tan(r(x, preuve)),
+
+        Abs(x) => Abs(r(x, preuve)),
+        Sign(x) => Sign(r(x, preuve)),
+        Floor(x) => Floor(r(x, preuve)),
+        Ceil(x) => Ceil(r(x, preuve)),
+        Round(x) => Round(r(x, preuve)),
+        Frac(x) => Frac(r(x, preuve)),
+
+        Fact(x) => Fact(r(x, preuve)),
+        FactDouble(x) => FactDouble(r(x, preuve)),
+        Binom(n, k) => Binom(r(n, preuve), r(k, preuve)),
+        Arrang(n, k) => Arrang(r(n, preuve), r(k, preuve)),
+
+        Pgcd(a, b) => Pgcd(r(a, preuve), r(b, preuve)),
+        Ppcm(a, b) => Ppcm(r(a, preuve), r(b, preuve)),
+        Modulo(a, m) => Modulo(r(a, preuve), r(m, preuve)),
+        Egal(a, b) => Egal(r(a, preuve), r(b, preuve)),
+        Matrice(l) => map_matrice(l, |x| normalise_radicaux(x, preuve)),
+        Matriciel(op, x) => Matriciel(op, r(x, preuve)),
+
+        Rat(_) | Pi | Indefini | Var(_) => e,
+    }
+}
+
+/* ------------------------ Dénouement ------------------------ */
+
+/// √x pour x = a + b√c : Some((forme dénouée, identité)) si a > 0 et a² - b²c = d².
+pub fn denoue(x: &SommeRadicaux) -> Option<(SommeRadicaux, String)> {
+    if x.termes.len() != 2 {
+        return None;
+    }
+    let a = x.termes.get(&BigInt::one())?;
+    let (c, b) = x.termes.iter().find(|(d, _)| !d.is_one())?;
+    if !a.is_positive() {
+        return None;
+    }
+
+    let cr = BigRational::from_integer(c.clone());
+    let disc = a * a - b * b * &cr;
+    let d = rational_sqrt_exact(&disc)?;
+
+    let deux = BigRational::from_integer(BigInt::from(2));
+    let p = SommeRadicaux::racine(&((a + &d) / &deux))?;
+    let q = SommeRadicaux::racine(&((a - &d) / &deux))?;
+    let r = if b.is_positive() {
+        p.add(&q)
+    } else {
+        p.sub(&q)
+    };
+
+    let carre = |q: &BigRational| {
+        if q.is_integer() && !q.is_negative() {
+            format!("{q}²")
+        } else {
+            format!("({q})²")
+        }
+    };
+    let ligne = format!(
+        "√{} = {} car {} - {}·{c} = {}",
+        entre_parentheses(&x.vers_expr()),
+        format_expr_pretty(&r.vers_expr()),
+        carre(a),
+        carre(b),
+        carre(&d)
+    );
+    Some((r, ligne))
+}
+
+/// √x pour x dans le corps (non rationnel) : dénouement, sinon sortie du plus grand
+/// carré rationnel (√((2-√2)/4) = √(2-√2)/2). None si rien à faire.
+pub fn racine_canon(x: &SommeRadicaux) -> Option<Expr> {
+    use Expr::*;
+    if let Some((r, _)) = denoue(x) {
+        return Some(r.vers_expr());
+    }
+
+    // x = q·y, y à coefficients entiers premiers entre eux, q > 0
+    let num = x
+        .termes
+        .values()
+        .fold(BigInt::zero(), |g, c| g.gcd(c.numer()));
+    let den = x
+        .termes
+        .values()
+        .fold(BigInt::one(), |l, c| l.lcm(c.denom()));
+    let q = BigRational::new(num, den);
+
+    // q = (s/u)²·(t/v) = (s/(u·v))²·(t·v) ; √x = s/(u·v) · √(x·(t·v)/q)
+    let (s, t) = extrait_puissance_parfaite(q.numer(), 2);
+    let (u, v) = extrait_puissance_parfaite(q.denom(), 2);
+    let coeff = BigRational::new(s, u * &v);
+    if coeff.is_one() {
+        return None;
+    }
+    let y = x.mul_rationnel(&(BigRational::from_integer(t * v) / &q));
+    Some(
+        Mul(
+            Box::new(Rat(coeff)),
+            Box::new(Sqrt(Box::new(y.vers_expr()))),
+        )
+        .canon(),
+    )
+}
+
+/// Preuve : une ligne par √(a + b√c) dénouable rencontrée dans l’arbre.
+pub fn trace_denouements(e: &Expr, preuve: &mut String) {
+    if let Expr::Sqrt(x) = e {
+        if let Some((_, ligne)) = SommeRadicaux::depuis_expr(x).as_ref().and_then(denoue) {
+            pousse_ligne(preuve, &ligne);
+        }
+    }
+    for f in e.enfants() {
+        trace_denouements(f, preuve);
+    }
+}
+
+/* ------------------------ Rationalisation ------------------------ */
+
+/// Multiplie num/den par les conjugués successifs de den jusqu’à un dénominateur rationnel.
+/// Renvoie (facteur total, dénominateur rationnel) ; None si den nul ou trop de premiers.
+fn conjugues_successifs(
+    den: &SommeRadicaux,
+    preuve: &mut String,
+) -> Option<(SommeRadicaux, BigRational)> {
+    let premiers = den.premiers();
+    if premiers.len() > PREMIERS_MAX {
+        return None;
+    }
+
+    let mut facteur = SommeRadicaux::rationnel(BigRational::one());
+    let mut d = den.clone();
+    for p in premiers {
+        let conj = d.conjugue(&p);
+        if conj == d {
+            continue; // √p déjà éliminé par une étape précédente
+        }
+        let produit = d.mul(&conj);
+        pousse_ligne(
+            preuve,
+            &format!(
+                "{}·{} = {}",
+                entre_parentheses(&d.vers_expr()),
+                entre_parentheses(&conj.vers_expr()),
+                format_expr_pretty(&produit.vers_expr())
+            ),
+        );
+        facteur = facteur.mul(&conj);
+        d = produit;
+    }
+
+    match d.valeur_rationnelle() {
+        Some(r) if !r.is_zero() => Some((facteur, r)),
+        _ => None,
+    }
+}
+
+/// Parenthèses pour un facteur de la preuve (les sommes en ont déjà).
+fn entre_parentheses(e: &Expr) -> String {
+    parentheses(format_expr_pretty(e))
+}
+
+fn pousse_ligne(preuve: &mut String, ligne: &str) {
+    if !preuve.is_empty() {
+        preuve.push('\n');
+    }
+    preuve.push_str(ligne);
+}
+
+/// a / b avec b somme de radicaux (non rationnelle) : a·conj / N.
+fn rationalise_quotient(a: &Expr, b: &Expr, preuve: &mut String) -> Option<Expr> {
+    use Expr::*;
+    let den = SommeRadicaux::depuis_expr(b)?;
+    if den.est_rationnel() {
+        return None;
+    }
+
+    let mut trace = String::new();
+    let (facteur, n) = conjugues_successifs(&den, &mut trace)?;
+    let inv_n = n.recip();
+
+    let resultat = match SommeRadicaux::depuis_expr(a) {
+        Some(num) => num.mul(&facteur).mul_rationnel(&inv_n).vers_expr(),
+        None => Mul(
+            Box::new(a.clone()),
+            Box::new(facteur.mul_rationnel(&inv_n).vers_expr()),
+        )
+        .canon(),
+    };
+
+    let conj = entre_parentheses(&facteur.vers_expr());
+    pousse_ligne(
+        preuve,
+        &format!(
+            "Rationalisation : {}/{} × {conj}/{conj}",
+            format_expr_pretty(a),
+            entre_parentheses(b),
+        ),
+    );
+    preuve.push('\n');
+    preuve.push_str(&trace);
+    pousse_ligne(preuve, &format!("=> {}", format_expr_pretty(&resultat)));
+    Some(resultat)
+}
+
+/// Descend dans l’arbre et rationalise chaque dénominateur somme de √rationnels
+/// (a/b et b^(-n)). Les autres noeuds sont recopiés.
+pub fn rationalise_denominateurs(e: Expr, preuve: &mut String) -> Expr {
+    use Expr::*;
+    let r = |x: Box<Expr>, preuve: &mut String| Box::new(rationalise_denominateurs(*x, preuve));
+
+    match e {
+        Div(a, b) => {
+            let a = rationalise_denominateurs(*a, preuve);
+            let b = rationalise_denominateurs(*b, preuve);
+            match rationalise_quotient(&a, &b, preuve) {
+                Some(q) => q,
+                None => Div(Box::new(a), Box::new(b)),
+            }
+        }
+        PowInt(x, n) if n < 0 => {
+            let x = rationalise_denominateurs(*x, preuve);
+            let b = PowInt(Box::new(x.clone()), -n);
+            let un = Rat(BigRational::one());
+            match rationalise_quotient(&un, &b, preuve) {
+                Some(q) => q,
+                None => PowInt(Box::new(x), n),
+            }
+        }
+
+        Add(a, b) => Add(r(a, preuve), r(b, preuve)),
+        Sub(a, b) => Sub(r(a, preuve), r(b, preuve)),
+        Mul(a, b) => Mul(r(a, preuve), r(b, preuve)),
+
+        Sqrt(x) => Sqrt(r(x, preuve)),
+        Root(x, k) => Root(r(x, preuve), k),
+        PowInt(x, n) => PowInt(r(x, preuve), n),
+        Pow(x, y) => Pow(r(x, preuve), r(y, preuve)),
+
+        Sin(x) => Sin(r(x, preuve)),
+        Cos(x) => Cos(r(x, preuve)),
+        Tan(x) => Tan(r(x, preuve)),
+
+        Abs(x) => Abs(r(x, preuve)),
+        Sign(x) => Sign(r(x, preuve)),
+        Floor(x) => Floor(r(x, preuve)),
+        Ceil(x) => Ceil(r(x, preuve)),
+        Round(x) => Round(r(x, preuve)),
+        Frac(x) => Frac(r(x, preuve)),
+
+        Fact(x) => Fact(r(x, preuve)),
+        FactDouble(x) => FactDouble(r(x, preuve)),
+        Binom(n, k) => Binom(r(n, preuve), r(k, preuve)),
+        Arrang(n, k) => Arrang(r(n, preuve), r(k, preuve)),
+
+        Pgcd(a, b) => Pgcd(r(a, preuve), r(b, preuve)),
+        Ppcm(a, b) => Ppcm(r(a, preuve), r(b, preuve)),
+        Modulo(a, m) => Modulo(r(a, preuve), r(m, preuve)),
+        Egal(a, b) => Egal(r(a, preuve), r(b, preuve)),
+        Matrice(l) => map_matrice(l, |x| rationalise_denominateurs(x, preuve)),
+        Matriciel(op, x) => Matriciel(op, r(x, preuve)),
+
+        Rat(_) | Pi | Indefini | Var(_) => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(n: i64) -> BigRational {
+        BigRational::from_integer(BigInt::from(n))
+    }
+
+    #[test]
+    fn produit_de_racines() {
+        // √2·√6 = 2√3
+        let a = SommeRadicaux::racine(&r(2)).unwrap();
+        let b = SommeRadicaux::racine(&r(6)).unwrap();
+        let p = a.mul(&b);
+        assert_eq!(p.termes.get(&BigInt::from(3)), Some(&r(2)));
+        assert_eq!(p.termes.len(), 1);
+    }
+
+    #[test]
+    fn conjugues_jusqu_au_rationnel() {
+        // 1 + √2 + √3 : deux conjugaisons (p = 2 puis p = 3)
+        let d = SommeRadicaux::rationnel(r(1))
+            .add(&SommeRadicaux::racine(&r(2)).unwrap())
+            .add(&SommeRadicaux::racine(&r(3)).unwrap());
+        let mut trace = String::new();
+        let (facteur, n) = conjugues_successifs(&d, &mut trace).unwrap();
+        assert_eq!(d.mul(&facteur).valeur_rationnelle(), Some(n));
+        assert_eq!(trace.lines().count(), 2);
+
+        // √2 - √2 = 0 : pas de rationalisation
+        let z = SommeRadicaux::racine(&r(2)).unwrap().sub(
+            &SommeRadicaux::racine(&r(8))
+                .unwrap()
+                .mul_rationnel(&BigRational::new(1.into(), 2.into())),
+        );
+        assert!(z.termes.is_empty());
+    }
+
+    #[test]
+    fn inverse_dans_le_corps() {
+        // (√2 + √3 + √5)·(√2 + √3 + √5)⁻¹ = 1
+        let x = SommeRadicaux::racine(&r(2))
+            .unwrap()
+            .add(&SommeRadicaux::racine(&r(3)).unwrap())
+            .add(&SommeRadicaux::racine(&r(5)).unwrap());
+        let inv = x.inverse().unwrap();
+        assert_eq!(x.mul(&inv).valeur_rationnelle(), Some(r(1)));
+        assert!(SommeRadicaux::rationnel(r(0)).inverse().is_none());
+    }
+}
@@ -0,0 +1,276 @@
+// src/noyau/resolution_radicaux.rs
+//
+// Degrés 3 et 4 par radicaux (Cardano, Ferrari)
+// ---------------------------------------------
+// Cubique unitaire, x = t - a/3 : t³ + p·t + q = 0, D = (q/2)² + (p/3)³
+// - D > 0 : Cardano, u = ∛(-q/2 + √D), v = ∛(-q/2 - √D)
+//     t = u + v (réelle), t = -(u+v)/2 ± i·(√3/2)(u - v) (complexes conjuguées)
+// - D ≤ 0 : trois racines réelles (casus irreducibilis) : aucune expression par
+//   radicaux réels => None (l’appelant isole les racines, isolation.rs)
+//
+// Quartique unitaire, x = y - a/4 : y⁴ + p·y² + q·y + r = 0
+// - q = 0 (bicarrée) : z = y², z² + p·z + r = 0, puis y = ±√z
+//   (Δ < 0 : √(α ± iβ) = ±(√((|z|+α)/2) ± i√((|z|-α)/2)), |z| = √r)
+// - Ferrari : m racine rationnelle > 0 de la résolvante 8m³ + 8p·m² + (2p² - 8r)·m - q²,
+//   s = √(2m) : (y² + p/2 + m)² = (s·y - q/(2s))², deux trinômes
+//     y = (s ± √δ₁)/2, y = (-s ± √δ₂)/2, δ₁,₂ = -2p - 2m ∓ (q/m)·√(2m)
+// - résolvante sans racine rationnelle > 0 : None (isolation)
+//
+// IMPORTANT (SAFE):
+// - Signes de δ décidés par signe_certifie (Q(√d) exact) ; indécis => None, jamais deviné.
+// - Racines renvoyées toutes (réelles et complexes) ; le tri ℝ / ℂ est fait par l’appelant.
+// - Chaque partie réelle / imaginaire passe par forme_normale (eval.rs) : forme unique.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::cmp::Ordering;
+
+use super::equations::{racines_rationnelles_seules, Racine};
+use super::eval::forme_normale;
+use super::expr::Expr;
+use super::polynome::Polynome;
+use super::signe::signe_certifie;
+
+fn q(n: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(n))
+}
+
+fn rat(r: BigRational) -> Expr {
+    Expr::Rat(r)
+}
+
+fn racine_carree(e: Expr) -> Expr {
+    Expr::Sqrt(Box::new(e))
+}
+
+fn somme(a: Expr, b: Expr) -> Expr {
+    Expr::Add(Box::new(a), Box::new(b))
+}
+
+fn produit(a: Expr, b: Expr) -> Expr {
+    Expr::Mul(Box::new(a), Box::new(b))
+}
+
+fn oppose(a: Expr) -> Expr {
+    Expr::Sub(Box::new(rat(BigRational::zero())), Box::new(a))
+}
+
+/// Forme normale complète (canon, Q(√d), racines fusionnées) : √(1/2·(4+2√2)) => √(2+√2),
+/// √(√2) => ∜2.
+fn finalise(e: Expr) -> Expr {
+    forme_normale(e, &mut String::new())
+}
+
+fn reelle(re: Expr) -> Racine {
+    Racine::Exacte {
+        re: finalise(re),
+        im: rat(BigRational::zero()),
+    }
+}
+
+fn complexe(re: Expr, im: Expr) -> Racine {
+    Racine::Exacte {
+        re: finalise(re),
+        im: finalise(im),
+    }
+}
+
+/// g(x + c), unitaire.
+fn translate(g: &Polynome, c: &BigRational) -> Polynome {
+    g.unitaire()
+        .compose(&Polynome::nouveau(vec![c.clone(), BigRational::one()]))
+}
+
+/// “t - 1/4”, “t” : changement de variable x = t + c.
+fn changement(t: &str, c: &BigRational) -> String {
+    match c.cmp(&BigRational::zero()) {
+        Ordering::Equal => t.to_string(),
+        Ordering::Greater => format!("{t} + {c}"),
+        Ordering::Less => format!("{t} - {}", -c),
+    }
+}
+
+/* ------------------------ Cardano ------------------------ */
+
+/// Racines d’une cubique sans racine rationnelle (Cardano), None si casus irreducibilis.
+pub fn cubique(g: &Polynome, var: &str, preuve: &mut String) -> Option<Vec<Racine>> {
+    let decalage = -g.unitaire().coeff(2) / q(3);
+    let h = translate(g, &decalage);
+    let (p, qq) = (h.coeff(1), h.coeff(0));
+    let d = (&qq / q(2)).pow(2) + (&p / q(3)).pow(3);
+    preuve.push_str(&format!(
+        "{} = 0, {var} = {} : t³ + pt + q = 0, p = {p}, q = {qq}, D = (q/2)² + (p/3)³ = {d}\n",
+        g.format(var),
+        changement("t", &decalage)
+    ));
+
+    if !d.is_positive() {
+        preuve.push_str(
+            "D ≤ 0 : trois racines réelles (casus irreducibilis), pas d’expression par radicaux réels\n",
+        );
+        return None;
+    }
+    preuve.push_str(
+        "D > 0 : Cardano, t = ∛(-q/2 + √D) + ∛(-q/2 - √D) (une racine réelle, deux complexes conjuguées)\n",
+    );
+
+    let moitie = rat(-&qq / q(2));
+    let rd = || racine_carree(rat(d.clone()));
+    let u = finalise(Expr::Root(Box::new(somme(moitie.clone(), rd())), 3));
+    let v = finalise(Expr::Root(
+        Box::new(Expr::Sub(Box::new(moitie), Box::new(rd()))),
+        3,
+    ));
+    let u_plus_v = somme(u.clone(), v.clone());
+    let re = somme(
+        produit(rat(q(-1) / q(2)), u_plus_v.clone()),
+        rat(decalage.clone()),
+    );
+    let im = produit(
+        produit(rat(q(1) / q(2)), racine_carree(rat(q(3)))),
+        Expr::Sub(Box::new(u), Box::new(v)),
+    );
+
+    Some(vec![
+        reelle(somme(u_plus_v, rat(decalage))),
+        complexe(re.clone(), oppose(im.clone())),
+        complexe(re, im),
+    ])
+}
+
+/* ------------------------ Ferrari ------------------------ */
+
+/// Racines d’une quartique sans racine rationnelle (bicarrée ou Ferrari), None sinon.
+pub fn quartique(g: &Polynome, var: &str, preuve: &mut String) -> Option<Vec<Racine>> {
+    let decalage = -g.unitaire().coeff(3) / q(4);
+    let h = translate(g, &decalage);
+    let (p, qq, r) = (h.coeff(2), h.coeff(1), h.coeff(0));
+    preuve.push_str(&format!(
+        "{} = 0, {var} = {} : y⁴ + py² + qy + r = 0, p = {p}, q = {qq}, r = {r}\n",
+        g.format(var),
+        changement("y", &decalage)
+    ));
+
+    if qq.is_zero() {
+        return bicarree(&p, &r, &decalage, preuve);
+    }
+
+    // résolvante 8m³ + 8p·m² + (2p² - 8r)·m - q²
+    let resolvante = Polynome::nouveau(vec![
+        -(&qq * &qq),
+        q(2) * &p * &p - q(8) * &r,
+        q(8) * &p,
+        q(8),
+    ]);
+    let m = racines_rationnelles_seules(&resolvante)
+        .into_iter()
+        .find(|m| m.is_positive());
+    let Some(m) = m else {
+        preuve.push_str(&format!(
+            "résolvante {} = 0 sans racine rationnelle > 0 : pas de forme par radicaux réels retenue\n",
+            resolvante.format("m")
+        ));
+        return None;
+    };
+    let deux_m = q(2) * &m;
+    preuve.push_str(&format!(
+        "Ferrari : résolvante {} = 0, m = {m} ; (y² + p/2 + m)² = (√(2m)·y - q/(2√(2m)))²\n",
+        resolvante.format("m")
+    ));
+
+    let s = || racine_carree(rat(deux_m.clone()));
+    let base = -(q(2) * &p) - &deux_m;
+    let pente = &qq / &m;
+    let mut out = Vec::new();
+    // δ₁ avec (s, -pente), δ₂ avec (-s, +pente)
+    for signe in [1, -1] {
+        let sy = produit(rat(q(signe)), s());
+        let delta = finalise(somme(
+            rat(base.clone()),
+            produit(rat(-&pente * q(signe)), s()),
+        ));
+        let centre = somme(produit(rat(q(1) / q(2)), sy), rat(decalage.clone()));
+        match signe_certifie(&delta)? {
+            Ordering::Greater => {
+                for e in [-1, 1] {
+                    let demi = produit(rat(q(e) / q(2)), racine_carree(delta.clone()));
+                    out.push(reelle(somme(centre.clone(), demi)));
+                }
+            }
+            Ordering::Less => {
+                let module = produit(rat(q(1) / q(2)), racine_carree(finalise(oppose(delta))));
+                out.push(complexe(centre.clone(), oppose(module.clone())));
+                out.push(complexe(centre, module));
+            }
+            Ordering::Equal => return None,
+        }
+    }
+    Some(out)
+}
+
+/// y⁴ + p·y² + r = 0 (z = y²).
+fn bicarree(
+    p: &BigRational,
+    r: &BigRational,
+    decalage: &BigRational,
+    preuve: &mut String,
+) -> Option<Vec<Racine>> {
+    let delta = p * p - q(4) * r;
+    preuve.push_str(&format!(
+        "bicarrée : z = y², z² + pz + r = 0, Δ = p² - 4r = {delta}\n"
+    ));
+    let translate = |e: Expr| somme(e, rat(decalage.clone()));
+    let mut out = Vec::new();
+
+    if delta.is_negative() {
+        // z = α ± iβ, |z| = √r : √z = ±(√((√r + α)/2) ± i√((√r - α)/2))
+        let alpha = -p / q(2);
+        let module_z = racine_carree(rat(r.clone()));
+        let partie = |signe: i64| {
+            finalise(racine_carree(produit(
+                rat(q(1) / q(2)),
+                somme(module_z.clone(), rat(&alpha * q(signe))),
+            )))
+        };
+        let (re, im) = (partie(1), partie(-1));
+        for (a, b) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+            out.push(complexe(
+                translate(produit(rat(q(a)), re.clone())),
+                produit(rat(q(b)), im.clone()),
+            ));
+        }
+        return Some(out);
+    }
+
+    for e in [-1, 1] {
+        let z = finalise(produit(
+            rat(q(1) / q(2)),
+            somme(
+                rat(-p.clone()),
+                produit(rat(q(e)), racine_carree(rat(delta.clone()))),
+            ),
+        ));
+        match signe_certifie(&z)? {
+            Ordering::Greater => {
+                for f in [-1, 1] {
+                    out.push(reelle(translate(produit(
+                        rat(q(f)),
+                        racine_carree(z.clone()),
+                    ))));
+                }
+            }
+            Ordering::Less => {
+                let module = finalise(racine_carree(finalise(oppose(z))));
+                for f in [-1, 1] {
+                    out.push(complexe(
+                        translate(rat(BigRational::zero())),
+                        produit(rat(q(f)), module.clone()),
+                    ));
+                }
+            }
+            Ordering::Equal => return None,
+        }
+    }
+    Some(out)
+}
@@ -7,27 +7,47 @@
 //
 // Règles:
 // - Ident(name):
-//    - si name ∈ {sin, cos, tan, sqrt} => fonction unaire (postfixée en RPN)
+//    - si name ∈ {sin, cos, tan, sqrt, cbrt, abs, sign, floor, ceil, round, frac, ...}
+//      => fonction unaire (postfixée en RPN)
+//    - si name ∈ {binom, perm, gcd, lcm, mod} => fonction à 2 arguments séparés par ','
+//      (pgcd / ppcm acceptés comme synonymes de gcd / lcm)
+//      (alias : C(n,k), nCr(n,k) => binom ; P(n,k), nPr(n,k) => perm,
+//       seulement quand l’identifiant est suivi de '(' : "c" seul reste une variable)
 //    - sinon => variable/atome (Expr::Var)
+// - Caret : exposant rationnel p/q => (ᵠ√a)^p (racine n-ième, √ si q = 2),
+//           exposant symbolique => Pow(a, b)
+// - Multiplication implicite (avant le shunting-yard) : valeur suivie d’une valeur
+//   ouvrante => '*' inséré : "3x" => 3*x, "(x+1)(x+2)" => (x+1)*(x+2), "2sin(x)", "2pi".
+//   Jamais entre un identifiant et '(' (fonction, ou nom inconnu => erreur :
+//   "sqr(4)" n’est pas sqr·4) ni devant un nombre.
+// - Crochets : [a, b, c] => "a b c liste3" ; [[1, 2], [3, 4]] => matrice 2×2 (matrices.rs),
+//   det / inv / transpose / rank / rref / trace => fonctions matricielles unaires
+// - Égal '=' : précédence la plus faible, une seule égalité au niveau racine
+//   ("x^2-1 = 0" => Egal(x^2-1, 0), résolu par solve(…) / equations.rs).
+// - Postfixés ! et !! : sortis directement (ils lient plus fort que tout opérateur
+//   binaire : 2^3! = 2^(3!), -3! = -(3!))
 // - Moins unaire:
 //    - si '-' arrive quand on n’attend PAS une valeur, on injecte 0 : "-x" => "0 x -"
+//    - juste après un opérateur binaire, on émet Neg (préfixe, lie plus fort que * mais
+//      moins que ^) : "2^-1" => "2 1 neg ^", "2*-3^2" => "2 3 2 ^ neg *"
 //
 // NOTE:
 // - Les fonctions sont traitées comme des opérateurs “collés” à leur argument
 //   et sont sorties après la parenthèse fermante.
 
-use num_bigint::BigInt;
 use num_rational::BigRational;
-use num_traits::{One, Zero};
+use num_traits::Zero;
 
-use super::expr::Expr;
+use super::expr::{puissance_rationnelle, Expr};
 use super::jetons::Tok;
+use super::matrices::{depuis_liste, OpMatrice};
 
 fn precedence(t: &Tok) -> i32 {
     match t {
         Tok::Plus | Tok::Minus => 1,
         Tok::Star | Tok::Slash => 2,
-        Tok::Caret => 3,
+        Tok::Caret | Tok::Neg => 3,
+        Tok::Egal => 0,
         _ => 0,
     }
 }
@@ -37,8 +57,74 @@ fn is_right_associative(t: &Tok) -> bool {
 }
 
 /// Identificateurs reconnus comme fonctions (unaire).
-fn is_fonction_ident(name: &str) -> bool {
-    matches!(name, "sin" | "cos" | "tan" | "sqrt")
+pub(crate) fn is_fonction_ident(name: &str) -> bool {
+    matches!(
+        name,
+        "sin"
+            | "cos"
+            | "tan"
+            | "sqrt"
+            | "cbrt"
+            | "root4"
+            | "abs"
+            | "sign"
+            | "sgn"
+            | "floor"
+            | "ceil"
+            | "round"
+            | "frac"
+            | "binom"
+            | "perm"
+            | "gcd"
+            | "pgcd"
+            | "lcm"
+            | "ppcm"
+            | "mod"
+    ) || OpMatrice::depuis_nom(name).is_some()
+}
+
+/// Nombre d’arguments d’une fonction reconnue.
+fn arite_fonction(name: &str) -> usize {
+    match name {
+        "binom" | "perm" | "gcd" | "pgcd" | "lcm" | "ppcm" | "mod" => 2,
+        _ => 1,
+    }
+}
+
+/// Alias des fonctions combinatoires, reconnus seulement devant '('.
+fn alias_fonction(name: &str, suivi_de_parenthese: bool) -> Option<&'static str> {
+    if !suivi_de_parenthese {
+        return None;
+    }
+    match name {
+        "c" | "ncr" => Some("binom"),
+        "p" | "npr" => Some("perm"),
+        _ => None,
+    }
+}
+
+/// Insère les '*' implicites : fin de valeur (nombre, π, variable, ')', ']', !) suivie
+/// d’un début de valeur (π, identifiant, '(', '['). Un identifiant suivi de '(' est
+/// un appel : jamais de '*' (nom inconnu signalé par to_rpn).
+fn multiplications_implicites(tokens: &[Tok]) -> Vec<Tok> {
+    let mut out: Vec<Tok> = Vec::with_capacity(tokens.len());
+    for tok in tokens {
+        if let Some(prec) = out.last() {
+            let fin_valeur = match prec {
+                Tok::Num(_) | Tok::Pi | Tok::RPar | Tok::RCrochet | Tok::Bang | Tok::BangBang => {
+                    true
+                }
+                Tok::Ident(_) => !matches!(tok, Tok::LPar),
+                _ => false,
+            };
+            let debut_valeur = matches!(tok, Tok::Pi | Tok::Ident(_) | Tok::LPar | Tok::LCrochet);
+            if fin_valeur && debut_valeur {
+                out.push(Tok::Star);
+            }
+        }
+        out.push(tok.clone());
+    }
+    out
 }
 
 /// Convertit une suite de jetons en RPN (notation polonaise inversée).
@@ -47,14 +133,17 @@ fn is_fonction_ident(name: &str) -> bool {
 ///   tokens: [Ident("sin"), LPar, Pi, Slash, Num(2), RPar]
 ///   rpn:    [Pi, Num(2), Slash, Ident("sin")]
 pub fn to_rpn(tokens: &[Tok]) -> Result<Vec<Tok>, String> {
+    let tokens = &multiplications_implicites(tokens);
     let mut out: Vec<Tok> = Vec::new();
     let mut ops: Vec<Tok> = Vec::new();
+    // nombre d’éléments de chaque '[' ouvert (virgules + 1)
+    let mut elements: Vec<usize> = Vec::new();
 
     // “valeur” = un atome ou une expression fermée.
     // Sert à détecter le moins unaire.
     let mut prev_was_value = false;
 
-    for tok in tokens.iter().cloned() {
+    for (i, tok) in tokens.iter().cloned().enumerate() {
         match tok {
             Tok::Num(_) | Tok::Pi => {
                 out.push(tok);
@@ -62,10 +151,18 @@ pub fn to_rpn(tokens: &[Tok]) -> Result<Vec<Tok>, String> {
             }
 
             Tok::Ident(name) => {
+                let suivi = matches!(tokens.get(i + 1), Some(Tok::LPar));
+                let name = alias_fonction(&name, suivi)
+                    .map(str::to_string)
+                    .unwrap_or(name);
+
                 if is_fonction_ident(&name) {
                     // fonction : on la garde sur la pile (elle sortira après son argument)
                     ops.push(Tok::Ident(name));
                     prev_was_value = false;
+                } else if suivi {
+                    // appel d’un nom inconnu (sqr(4), diff(x^2)+1) : pas un produit
+                    return Err(format!("fonction inconnue : {name}"));
                 } else {
                     // variable/atome : sortie directe
                     out.push(Tok::Ident(name));
@@ -81,10 +178,11 @@ pub fn to_rpn(tokens: &[Tok]) -> Result<Vec<Tok>, String> {
             Tok::RPar => {
                 // dépile jusqu’à '('
                 while let Some(top) = ops.pop() {
-                    if matches!(top, Tok::LPar) {
-                        break;
+                    match top {
+                        Tok::LPar => break,
+                        Tok::LCrochet => return Err("'[' fermé par ')'".into()),
+                        _ => out.push(top),
                     }
-                    out.push(top);
                 }
 
                 // si une fonction est au sommet, on la sort aussi
@@ -98,13 +196,65 @@ pub fn to_rpn(tokens: &[Tok]) -> Result<Vec<Tok>, String> {
                 prev_was_value = true;
             }
 
-            Tok::Plus | Tok::Star | Tok::Slash | Tok::Caret => {
+            Tok::LCrochet => {
+                ops.push(tok);
+                elements.push(1);
+                prev_was_value = false;
+            }
+
+            Tok::RCrochet => {
+                if matches!(tokens.get(i.wrapping_sub(1)), Some(Tok::LCrochet)) {
+                    return Err("matrice vide".into());
+                }
+                // dépile jusqu’à '[' puis sort la liste de ses éléments
+                loop {
+                    match ops.pop() {
+                        Some(Tok::LCrochet) => break,
+                        Some(Tok::LPar) => return Err("'(' fermé par ']'".into()),
+                        Some(top) => out.push(top),
+                        None => return Err("']' sans '[' correspondant".into()),
+                    }
+                }
+                out.push(Tok::Liste(elements.pop().unwrap_or(1)));
+                prev_was_value = true;
+            }
+
+            Tok::Neg | Tok::Liste(_) => return Err("jeton interne inattendu".into()),
+
+            Tok::Comma => {
+                // sépare les arguments : dépile jusqu’à la '(' de l’appel (sans la retirer),
+                // ou les éléments d’une liste : jusqu’au '[' (élément suivant compté)
+                loop {
+                    match ops.last() {
+                        Some(Tok::LPar) => break,
+                        Some(Tok::LCrochet) => {
+                            if let Some(n) = elements.last_mut() {
+                                *n += 1;
+                            }
+                            break;
+                        }
+                        Some(_) => out.push(ops.pop().unwrap()),
+                        None => return Err("virgule hors d’un appel de fonction".into()),
+                    }
+                }
+                prev_was_value = false;
+            }
+
+            Tok::Bang | Tok::BangBang => {
+                // postfixé : s’applique à la valeur qui précède, immédiatement
+                if !prev_was_value {
+                    return Err("'!' sans argument".into());
+                }
+                out.push(tok);
+            }
+
+            Tok::Plus | Tok::Star | Tok::Slash | Tok::Caret | Tok::Egal => {
                 // dépile tant que:
                 // - on n'est pas bloqué par '('
                 // - et on ne traverse pas une fonction (fonction reste collée à son argument)
                 // - et la précédence/associativité exige de sortir l'opérateur du haut
                 while let Some(top) = ops.last() {
-                    if matches!(top, Tok::LPar) {
+                    if matches!(top, Tok::LPar | Tok::LCrochet) {
                         break;
                     }
                     if let Tok::Ident(name) = top {
@@ -134,13 +284,24 @@ pub fn to_rpn(tokens: &[Tok]) -> Result<Vec<Tok>, String> {
             }
 
             Tok::Minus => {
+                // moins unaire juste après un opérateur : préfixe Neg (sans dépiler)
+                let apres_operateur = i > 0
+                    && matches!(
+                        tokens[i - 1],
+                        Tok::Plus | Tok::Minus | Tok::Star | Tok::Slash | Tok::Caret | Tok::Egal
+                    );
+                if !prev_was_value && apres_operateur {
+                    ops.push(Tok::Neg);
+                    continue;
+                }
+
                 // moins unaire : si pas de valeur avant, injecte 0
                 if !prev_was_value {
                     out.push(Tok::Num(BigRational::zero()));
                 }
 
                 while let Some(top) = ops.last() {
-                    if matches!(top, Tok::LPar) {
+                    if matches!(top, Tok::LPar | Tok::LCrochet) {
                         break;
                     }
                     if let Tok::Ident(name) = top {
@@ -166,6 +327,9 @@ pub fn to_rpn(tokens: &[Tok]) -> Result<Vec<Tok>, String> {
         if matches!(op, Tok::LPar) {
             return Err("parenthèses non fermées".into());
         }
+        if matches!(op, Tok::LCrochet) {
+            return Err("crochets non fermés".into());
+        }
         out.push(op);
     }
 
@@ -175,7 +339,10 @@ pub fn to_rpn(tokens: &[Tok]) -> Result<Vec<Tok>, String> {
 /// Construit une Expr à partir d’une RPN.
 ///
 /// - Ident(name):
-///     - si name ∈ {sin,cos,tan,sqrt} => fonction unaire
+///     - si name est une fonction (sin, cos, tan, sqrt, abs, ...) => fonction unaire
+///     - binom / perm / gcd / lcm / mod => fonction à 2 arguments
+///     - det / inv / transpose / rank / rref / trace => Expr::Matriciel
+/// - Liste(n) : n éléments => ligne ou matrice (Expr::Matrice)
 ///     - sinon => variable : Expr::Var(name)
 pub fn from_rpn(rpn: &[Tok]) -> Result<Expr, String> {
     let mut st: Vec<Expr> = Vec::new();
@@ -185,6 +352,12 @@ pub fn from_rpn(rpn: &[Tok]) -> Result<Expr, String> {
             Tok::Num(r) => st.push(Expr::Rat(r)),
             Tok::Pi => st.push(Expr::Pi),
 
+            Tok::Egal => {
+                let b = st.pop().ok_or("'=' : membre de droite manquant")?;
+                let a = st.pop().ok_or("'=' : membre de gauche manquant")?;
+                st.push(Expr::Egal(Box::new(a), Box::new(b)));
+            }
+
             Tok::Plus | Tok::Minus | Tok::Star | Tok::Slash | Tok::Caret => {
                 let b = st.pop().ok_or("expression invalide")?;
                 let a = st.pop().ok_or("expression invalide")?;
@@ -195,17 +368,12 @@ pub fn from_rpn(rpn: &[Tok]) -> Result<Expr, String> {
                     Tok::Star => Expr::Mul(Box::new(a), Box::new(b)),
                     Tok::Slash => Expr::Div(Box::new(a), Box::new(b)),
                     Tok::Caret => {
-                        // exposant entier seulement
-                        let n = match b {
-                            Expr::Rat(r) => {
-                                if !r.denom().is_one() {
-                                    return Err("exposant doit être entier".into());
-                                }
-                                big_to_i64(r.numer()).ok_or("exposant trop grand")?
-                            }
-                            _ => return Err("exposant doit être entier".into()),
-                        };
-                        Expr::PowInt(Box::new(a), n)
+                        // exposant rationnel (après simplification : 2^(-1), 8^(1/3), ...)
+                        // sinon puissance symbolique (2^x, a^(b+c))
+                        match b.simplify() {
+                            Expr::Rat(r) => puissance_rationnelle(a, &r)?,
+                            b => Expr::Pow(Box::new(a), Box::new(b)),
+                        }
                     }
                     _ => unreachable!(),
                 };
@@ -213,15 +381,63 @@ pub fn from_rpn(rpn: &[Tok]) -> Result<Expr, String> {
                 st.push(e);
             }
 
+            Tok::Neg => {
+                let x = st.pop().ok_or("expression invalide")?;
+                st.push(Expr::Sub(
+                    Box::new(Expr::Rat(BigRational::zero())),
+                    Box::new(x),
+                ));
+            }
+
+            Tok::Liste(n) => {
+                if st.len() < n {
+                    return Err("matrice : élément manquant".into());
+                }
+                let elements = st.split_off(st.len() - n);
+                st.push(depuis_liste(elements)?);
+            }
+
+            Tok::Bang | Tok::BangBang => {
+                let x = st.pop().ok_or("'!' sans argument")?;
+                st.push(match tok {
+                    Tok::Bang => Expr::Fact(Box::new(x)),
+                    _ => Expr::FactDouble(Box::new(x)),
+                });
+            }
+
+            Tok::Ident(name) if arite_fonction(name.as_str()) == 2 => {
+                let k = st.pop().ok_or("fonction : 2 arguments attendus")?;
+                let n = st.pop().ok_or("fonction : 2 arguments attendus")?;
+                st.push(match name.as_str() {
+                    "binom" => Expr::Binom(Box::new(n), Box::new(k)),
+                    "perm" => Expr::Arrang(Box::new(n), Box::new(k)),
+                    "gcd" | "pgcd" => Expr::Pgcd(Box::new(n), Box::new(k)),
+                    "lcm" | "ppcm" => Expr::Ppcm(Box::new(n), Box::new(k)),
+                    "mod" => Expr::Modulo(Box::new(n), Box::new(k)),
+                    _ => unreachable!(),
+                });
+            }
+
             Tok::Ident(name) => {
                 if is_fonction_ident(name.as_str()) {
                     let x = st.pop().ok_or("fonction sans argument")?;
                     let e = match name.as_str() {
                         "sqrt" => Expr::Sqrt(Box::new(x)),
+                        "cbrt" => Expr::Root(Box::new(x), 3),
+                        "root4" => Expr::Root(Box::new(x), 4),
                         "sin" => Expr::Sin(Box::new(x)),
                         "cos" => Expr::Cos(Box::new(x)),
                         "tan" => Expr::Tan(Box::new(x)),
-                        _ => unreachable!(),
+                        "abs" => Expr::Abs(Box::new(x)),
+                        "sign" | "sgn" => Expr::Sign(Box::new(x)),
+                        "floor" => Expr::Floor(Box::new(x)),
+                        "ceil" => Expr::Ceil(Box::new(x)),
+                        "round" => Expr::Round(Box::new(x)),
+                        "frac" => Expr::Frac(Box::new(x)),
+                        nom => match OpMatrice::depuis_nom(nom) {
+                            Some(op) => Expr::Matriciel(op, Box::new(x)),
+                            None => unreachable!(),
+                        },
                     };
                     st.push(e);
                 } else {
@@ -229,18 +445,23 @@ pub fn from_rpn(rpn: &[Tok]) -> Result<Expr, String> {
                 }
             }
 
-            Tok::LPar | Tok::RPar => return Err("parenthèse inattendue en RPN".into()),
+            Tok::LPar | Tok::RPar | Tok::Comma | Tok::LCrochet | Tok::RCrochet => {
+                return Err("parenthèse inattendue en RPN".into())
+            }
         }
     }
 
     if st.len() != 1 {
         return Err("expression invalide".into());
     }
-    Ok(st.pop().unwrap())
-}
-
-/// Conversion SAFE vers i64.
-/// (MVP: exposant doit rentrer dans i64, sinon on refuse)
-fn big_to_i64(x: &BigInt) -> Option<i64> {
-    x.to_string().parse::<i64>().ok()
+    let e = st.pop().unwrap();
+    // '=' : une seule égalité, au niveau racine (pas de (x=1)+2 ni de a=b=c)
+    let imbriquee = match &e {
+        Expr::Egal(a, b) => a.contient_egal() || b.contient_egal(),
+        _ => e.contient_egal(),
+    };
+    if imbriquee {
+        return Err("'=' : une seule égalité, au niveau racine".into());
+    }
+    Ok(e)
 }
@@ -0,0 +1,403 @@
+// src/noyau/signe.rs
+//
+// Signe et partie entière “certifiés” (sans flottants)
+// ----------------------------------------------------
+// - Rat : décision exacte
+// - élément de Q(√d1, …) (radicaux.rs) : signe EXACT, x = A + B·√p avec A, B sans √p :
+//   sgn A = sgn B => ce signe, sinon sgn A · sgn(A² - p·B²) (récursion sur les premiers)
+// - sinon : arithmétique d’intervalles à bornes rationnelles, arrondies vers l’extérieur
+//   (précision croissante) ; on n’accepte une décision que si l’intervalle exclut la frontière
+// - Var / Indefini / non encadrable : None (on ne devine rien)
+// - encadrement_rationnel : même intervalle, à précision choisie (fractions continues)
+//
+// IMPORTANT (SAFE):
+// - None veut dire “non décidé”, jamais “faux”.
+// - Chaque nœud arrondit son intervalle vers l’extérieur : l’erreur est prise en compte
+//   quelle que soit la taille des opérandes (10^40·√2 - a/10^30 est encadré juste).
+// - L’intervalle ne prouve jamais 0 : Equal vient seulement de l’arithmétique exacte.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::cmp::Ordering;
+
+use super::expr::{int_root_floor, mod_rationnel, Expr};
+use super::lecture::pi_scaled_cached;
+use super::radicaux::SommeRadicaux;
+
+/// Précisions successives (chiffres après la virgule) de l’encadrement.
+const PRECISIONS: [usize; 3] = [60, 120, 240];
+
+/// Chiffres de garde des calculs intermédiaires (intervalles).
+const GARDE: usize = 10;
+
+/// Exposant entier maximal encadré (au-delà : None).
+const EXPOSANT_MAX: u64 = 10_000;
+
+/* ------------------------ API ------------------------ */
+
+/// Encadrement rationnel bas ≤ x ≤ haut à `digits` chiffres (digits ≥ 60).
+/// Sert aux fractions continues (termes certifiés tant que bas et haut s’accordent).
+pub fn encadrement_rationnel(e: &Expr, digits: usize) -> Option<(BigRational, BigRational)> {
+    let iv = encadre(e, digits.max(PRECISIONS[0]))?;
+    Some((iv.bas, iv.haut))
+}
+
+/// Signe certifié : Some(Less | Equal | Greater) si décidé, None sinon.
+///
+/// Equal n’est renvoyé que sur preuve exacte (rationnel nul, ou zéro dans Q(√d1, …)) :
+/// l’encadrement ne prouve jamais 0.
+pub fn signe_certifie(e: &Expr) -> Option<Ordering> {
+    if let Expr::Rat(r) = e {
+        return Some(r.cmp(&BigRational::zero()));
+    }
+    if let Some(x) = SommeRadicaux::depuis_expr(e) {
+        return Some(signe_radicaux(&x));
+    }
+    signe_encadrement(e)
+}
+
+/// Signe par encadrement seul (Less | Greater) ; None si l’encadrement touche 0.
+pub fn signe_encadrement(e: &Expr) -> Option<Ordering> {
+    for digits in PRECISIONS {
+        let Some(iv) = encadre(e, digits) else {
+            continue;
+        };
+        if iv.bas.is_positive() {
+            return Some(Ordering::Greater);
+        }
+        if iv.haut.is_negative() {
+            return Some(Ordering::Less);
+        }
+    }
+    None
+}
+
+/// ⌊x⌋ certifié : Some(n) si l’encadrement ne contient aucun entier en son intérieur,
+/// ou si x est dans Q(√d1, …) (comparaison exacte à l’entier frontière).
+pub fn plancher_certifie(e: &Expr) -> Option<BigInt> {
+    if let Expr::Rat(r) = e {
+        return Some(r.floor().to_integer());
+    }
+
+    let radicaux = SommeRadicaux::depuis_expr(e);
+    for digits in PRECISIONS {
+        let Some(iv) = encadre(e, digits) else {
+            continue;
+        };
+        let fb = iv.bas.floor().to_integer();
+        let fh = iv.haut.floor().to_integer();
+        if fb == fh {
+            return Some(fb);
+        }
+
+        // Encadrement à cheval sur l’entier fh : x ≥ fh ? (décision dans le corps)
+        if let Some(x) = radicaux.as_ref().filter(|_| &fb + 1 == fh) {
+            let ecart = x.sub(&SommeRadicaux::rationnel(BigRational::from_integer(
+                fh.clone(),
+            )));
+            return Some(match signe_radicaux(&ecart) {
+                Ordering::Less => fb,
+                _ => fh,
+            });
+        }
+    }
+    None
+}
+
+/// Signe exact d’un élément de Q(√d1, …, √dk).
+fn signe_radicaux(x: &SommeRadicaux) -> Ordering {
+    let Some(p) = x.premiers().pop() else {
+        return x
+            .valeur_rationnelle()
+            .unwrap_or_else(BigRational::zero)
+            .cmp(&BigRational::zero());
+    };
+
+    // x = A + B·√p, A et B sans √p
+    let mut a = SommeRadicaux::rationnel(BigRational::zero());
+    let mut b = a.clone();
+    for (d, c) in &x.termes {
+        if d.is_multiple_of(&p) {
+            b.termes.insert(d / &p, c.clone());
+        } else {
+            a.termes.insert(d.clone(), c.clone());
+        }
+    }
+
+    let (sa, sb) = (signe_radicaux(&a), signe_radicaux(&b));
+    if sa == sb || sb == Ordering::Equal {
+        return sa;
+    }
+    if sa == Ordering::Equal {
+        return sb;
+    }
+    // signes opposés : |A| ≷ |B|·√p selon A² - p·B²
+    let p = BigRational::from_integer(p);
+    let ecart = a.mul(&a).sub(&b.mul(&b).mul_rationnel(&p));
+    match sa {
+        Ordering::Greater => signe_radicaux(&ecart),
+        _ => signe_radicaux(&ecart).reverse(),
+    }
+}
+
+/* ------------------------ Intervalles certifiés ------------------------ */
+
+#[derive(Clone, Debug)]
+pub(crate) struct Intervalle {
+    pub(crate) bas: BigRational,
+    pub(crate) haut: BigRational,
+}
+
+impl Intervalle {
+    pub(crate) fn point(r: BigRational) -> Self {
+        Intervalle {
+            bas: r.clone(),
+            haut: r,
+        }
+    }
+
+    pub(crate) fn contient_zero(&self) -> bool {
+        !self.bas.is_positive() && !self.haut.is_negative()
+    }
+
+    pub(crate) fn add(&self, o: &Self) -> Self {
+        Intervalle {
+            bas: &self.bas + &o.bas,
+            haut: &self.haut + &o.haut,
+        }
+    }
+
+    pub(crate) fn neg(&self) -> Self {
+        Intervalle {
+            bas: -self.haut.clone(),
+            haut: -self.bas.clone(),
+        }
+    }
+
+    pub(crate) fn mul(&self, o: &Self) -> Self {
+        let p = [
+            &self.bas * &o.bas,
+            &self.bas * &o.haut,
+            &self.haut * &o.bas,
+            &self.haut * &o.haut,
+        ];
+        Intervalle {
+            bas: p.iter().min().cloned().unwrap_or_else(BigRational::zero),
+            haut: p.iter().max().cloned().unwrap_or_else(BigRational::zero),
+        }
+    }
+
+    /// 1/self ; None si 0 est dans l’intervalle.
+    pub(crate) fn inverse(&self) -> Option<Self> {
+        if self.contient_zero() {
+            return None;
+        }
+        Some(Intervalle {
+            bas: self.haut.recip(),
+            haut: self.bas.recip(),
+        })
+    }
+
+    /// |self|
+    fn abs(&self) -> Self {
+        if !self.bas.is_negative() {
+            self.clone()
+        } else if !self.haut.is_positive() {
+            self.neg()
+        } else {
+            Intervalle {
+                bas: BigRational::zero(),
+                haut: self.haut.clone().max(-self.bas.clone()),
+            }
+        }
+    }
+
+    /// Arrondi vers l’extérieur sur la grille 10^-digits (taille des bornes maîtrisée).
+    fn arrondi(&self, digits: usize) -> Self {
+        let s = BigRational::from_integer(pow10(digits));
+        Intervalle {
+            bas: (&self.bas * &s).floor() / &s,
+            haut: (&self.haut * &s).ceil() / &s,
+        }
+    }
+}
+
+fn pow10(n: usize) -> BigInt {
+    BigInt::from(10).pow(n as u32)
+}
+
+/// Encadrement certifié de `e` (largeur ~10^-digits pour des opérandes modérés) ;
+/// None si hors domaine ou non encadrable (variable, exposant symbolique…).
+pub(crate) fn encadre(e: &Expr, digits: usize) -> Option<Intervalle> {
+    let d = digits + GARDE;
+    let iv = match e {
+        Expr::Rat(r) => return Some(Intervalle::point(r.clone())),
+        Expr::Pi => {
+            let p = pi_scaled_cached(d);
+            let s = pow10(d);
+            Intervalle {
+                bas: BigRational::new(&p - 2, s.clone()),
+                haut: BigRational::new(p + 2, s),
+            }
+        }
+        Expr::Add(a, b) => encadre(a, digits)?.add(&encadre(b, digits)?),
+        Expr::Sub(a, b) => encadre(a, digits)?.add(&encadre(b, digits)?.neg()),
+        Expr::Mul(a, b) => encadre(a, digits)?.mul(&encadre(b, digits)?),
+        Expr::Div(a, b) => encadre(a, digits)?.mul(&encadre(b, digits)?.inverse()?),
+        Expr::PowInt(a, n) => {
+            if n.unsigned_abs() > EXPOSANT_MAX {
+                return None;
+            }
+            let base = encadre(a, digits)?;
+            let mut base = if *n < 0 { base.inverse()? } else { base };
+            // exponentiation rapide, arrondie à chaque étape
+            let mut r = Intervalle::point(BigRational::one());
+            let mut k = n.unsigned_abs();
+            while k > 0 {
+                if k & 1 == 1 {
+                    r = r.mul(&base).arrondi(d);
+                }
+                k >>= 1;
+                if k > 0 {
+                    base = base.mul(&base).arrondi(d);
+                }
+            }
+            r
+        }
+        Expr::Abs(a) => encadre(a, digits)?.abs(),
+        Expr::Sqrt(a) => racine_intervalle(&encadre(a, digits)?, 2, d)?,
+        Expr::Root(a, k) => racine_intervalle(&encadre(a, digits)?, *k, d)?,
+        Expr::Cos(x) => cos_intervalle(x, false, d)?,
+        Expr::Sin(x) => cos_intervalle(x, true, d)?,
+        Expr::Tan(x) => cos_intervalle(x, true, d)?.mul(&cos_intervalle(x, false, d)?.inverse()?),
+        _ => return None,
+    };
+    Some(iv.arrondi(d))
+}
+
+/// ᵏ√ monotone, bornes entières scalées (k impair : négatifs acceptés).
+fn racine_intervalle(x: &Intervalle, k: u32, d: usize) -> Option<Intervalle> {
+    if k.is_multiple_of(2) && x.haut.is_negative() {
+        return None;
+    }
+    let s = BigRational::from_integer(pow10(d));
+    let sk = BigRational::from_integer(pow10(d * k as usize));
+
+    // ᵏ√y arrondi vers le bas (vers_haut = false) ou le haut, y quelconque (k impair)
+    let racine = |y: &BigRational, vers_haut: bool| -> BigRational {
+        let neg = y.is_negative();
+        let t = y.abs() * &sk;
+        // |y| arrondi dans le sens qui élargit le résultat final
+        let elargit = vers_haut != neg;
+        let t = if elargit { t.ceil() } else { t.floor() }.to_integer();
+        let mut r = int_root_floor(&t, k);
+        if elargit && r.pow(k) != t {
+            r += 1;
+        }
+        let v = BigRational::from_integer(r) / &s;
+        if neg {
+            -v
+        } else {
+            v
+        }
+    };
+
+    let bas = if k.is_multiple_of(2) && x.bas.is_negative() {
+        BigRational::zero()
+    } else {
+        racine(&x.bas, false)
+    };
+    Some(Intervalle {
+        bas,
+        haut: racine(&x.haut, true),
+    })
+}
+
+/// cos(qπ) (ou sin) : Taylor en virgule fixe au milieu de l’intervalle de θ,
+/// élargi par la demi-largeur (|cos'| ≤ 1) et l’erreur de troncature.
+fn cos_intervalle(x: &Expr, sinus: bool, d: usize) -> Option<Intervalle> {
+    let mut q = x.as_coeff_pi_ext()?;
+    if sinus {
+        q = BigRational::new(1.into(), 2.into()) - q;
+    }
+    let q = mod_rationnel(&q, 2);
+
+    let p = pi_scaled_cached(d);
+    let s = pow10(d);
+    let theta_bas = &q * BigRational::new(&p - 2, s.clone());
+    let theta_haut = &q * BigRational::new(p + 2, s.clone());
+    let milieu = (&theta_bas + &theta_haut) / BigRational::from_integer(2.into());
+    let rayon = (&theta_haut - &theta_bas) / BigRational::from_integer(2.into());
+
+    // virgule fixe avec GARDE chiffres supplémentaires
+    let sg = pow10(d + GARDE);
+    let xs = (&milieu * BigRational::from_integer(sg.clone()))
+        .floor()
+        .to_integer();
+    let x2 = &xs * &xs / &sg;
+    let mut terme = sg.clone();
+    let mut somme = sg.clone();
+    let mut k: u64 = 1;
+    loop {
+        terme = -(&terme * &x2) / (&sg * BigInt::from((2 * k - 1) * (2 * k)));
+        somme += &terme;
+        if terme.is_zero() && k >= 4 {
+            break;
+        }
+        k += 1;
+    }
+
+    // erreurs d’arrondi (≪ 10^GARDE ulps) + troncature de θ : 10^-d suffit largement
+    let c = BigRational::new(somme, sg);
+    let erreur = BigRational::new(BigInt::one(), s) + rayon;
+    Some(Intervalle {
+        bas: &c - &erreur,
+        haut: &c + &erreur,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noyau::eval::forme_exacte;
+
+    fn brut(s: &str) -> Expr {
+        crate::noyau::eval::lecture_brute(s).unwrap()
+    }
+
+    #[test]
+    fn grands_operandes_sans_faux_signe() {
+        // 10^40·√2 - a/10^30 ∈ (0, 10^-30) : la marge doit suivre la taille des opérandes
+        let v = "10^40*sqrt(2) - 14142135623730950488016887242096980785696718753769480731766797379907324/(10^30)";
+        assert_eq!(signe_certifie(&brut(v)), Some(Ordering::Greater));
+        assert_eq!(plancher_certifie(&brut(v)), Some(BigInt::zero()));
+        let (bas, haut) = encadrement_rationnel(&brut(v), 60).unwrap();
+        assert!(bas <= haut && !haut.is_negative());
+        assert_eq!(
+            signe_encadrement(&brut(&format!("pi*({v})"))),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn signe_exact_dans_le_corps() {
+        let e = forme_exacte("sqrt(2) + sqrt(3) - sqrt(5+2*sqrt(6))")
+            .unwrap()
+            .0;
+        assert_eq!(signe_certifie(&e), Some(Ordering::Equal));
+        assert_eq!(
+            signe_certifie(&brut("1351/780 - sqrt(3)")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            signe_certifie(&brut("sqrt(2) + sqrt(3) - sqrt(10)")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            plancher_certifie(&brut("sqrt(8) - 2*sqrt(2) + 3")),
+            Some(3.into())
+        );
+    }
+}
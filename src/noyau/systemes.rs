@@ -0,0 +1,368 @@
+// src/noyau/systemes.rs
+//
+// Systèmes linéaires : "{2x+y=3; x-y=0}"
+// --------------------------------------
+// Chaque équation (séparées par ';') est lue comme lhs - rhs = 0, linéaire à coefficients
+// rationnels. Les lignes sont ramenées à des entiers (ppcm des dénominateurs), puis :
+// 1) élimination sans fractions (Bareiss) sur la matrice augmentée [A | b] :
+//      L_i ← (p·L_i - a·L_r) / p_prec   (division exacte : les coefficients sont des mineurs)
+// 2) ligne “0 = c” avec c ≠ 0 : système incompatible
+// 3) remontée sur Q : inconnues pivots exprimées en fonction des inconnues libres
+//    (rang < nombre d’inconnues : famille paramétrée par les inconnues libres)
+//
+// IMPORTANT (SAFE):
+// - Arithmétique exacte (BigInt / BigRational), aucune solution approchée.
+// - Équation non linéaire (x·y, x², 1/x) : erreur explicite, jamais linéarisée.
+// - Taille bornée (TAILLE_MAX équations et inconnues).
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+use super::eval::{lecture_brute, DemarcheNoyau};
+use super::expr::Expr;
+use super::fractions_rationnelles::lit_fraction;
+use super::jetons::{format_tokens, tokenize};
+use super::polynome_multi::{message_refus, PolyMulti};
+use super::rpn::to_rpn;
+
+type ResultatSysteme = Result<(String, Option<String>, DemarcheNoyau), String>;
+
+/// Nombre maximal d’équations et d’inconnues.
+const TAILLE_MAX: usize = 16;
+
+/// Aiguillage : Some(résultat) si `s` est entre accolades "{…}" ; None sinon.
+pub(crate) fn evalue_systeme(s: &str) -> Option<ResultatSysteme> {
+    let corps = s.trim().strip_prefix('{')?.strip_suffix('}')?;
+    Some(resous_systeme(corps))
+}
+
+fn resous_systeme(corps: &str) -> ResultatSysteme {
+    let equations: Vec<&str> = corps.split(';').map(str::trim).collect();
+    if equations.iter().any(|e| e.is_empty()) {
+        return Err("système : équation vide (séparateur ';')".into());
+    }
+    if equations.len() > TAILLE_MAX {
+        return Err(format!("système : au plus {TAILLE_MAX} équations"));
+    }
+
+    let mut jetons = Vec::new();
+    let mut rpn = Vec::new();
+    let mut lignes = Vec::new();
+    for eq in &equations {
+        let t = tokenize(eq)?;
+        rpn.push(format_tokens(&to_rpn(&t)?));
+        jetons.push(format_tokens(&t));
+        lignes.push(lit_ligne(&lecture_brute(eq)?)?);
+    }
+
+    let mut vars: Vec<String> = lignes.iter().flat_map(|l| l.variables()).collect();
+    vars.sort();
+    vars.dedup();
+    if vars.is_empty() {
+        return Err("système : aucune inconnue".into());
+    }
+    if vars.len() > TAILLE_MAX {
+        return Err(format!("système : au plus {TAILLE_MAX} inconnues"));
+    }
+
+    let mut m: Vec<Vec<BigInt>> = lignes.iter().map(|l| ligne_entiere(l, &vars)).collect();
+    let mut preuve = format!("matrice augmentée [A | b] :\n{}", format_matrice(&m));
+    let pivots = elimine(&mut m, vars.len(), &vars, &mut preuve);
+    let exact = conclut(&m, &pivots, &vars, &mut preuve);
+
+    let d = DemarcheNoyau {
+        jetons: jetons.join(" ; "),
+        rpn: rpn.join(" ; "),
+        avant: format!("{{{}}}", equations.join(" ; ")),
+        apres: exact.clone(),
+        note: "Système linéaire : matrice augmentée entière, élimination de Bareiss (sans fractions, divisions exactes), puis remontée sur Q.".into(),
+        preuve,
+    };
+    Ok((exact, None, d))
+}
+
+/* ------------------------ Lecture ------------------------ */
+
+/// lhs - rhs en polynôme de degré ≤ 1 (dénominateur constant).
+fn lit_ligne(e: &Expr) -> Result<PolyMulti, String> {
+    let f = match e {
+        Expr::Egal(a, b) => Expr::Sub(a.clone(), b.clone()),
+        autre => autre.clone(),
+    };
+    let (num, den) = lit_fraction(&f).map_err(|err| {
+        message_refus("système", err, || {
+            "système : équation linéaire à coefficients rationnels attendue".into()
+        })
+    })?;
+    let c = den
+        .constante_seule()
+        .filter(|c| !c.is_zero())
+        .ok_or("système : inconnue au dénominateur (non linéaire)")?;
+    if num.degre_total() > 1 {
+        return Err(format!(
+            "système : équation non linéaire ({} = 0)",
+            num.format()
+        ));
+    }
+    Ok(num.mul_scalaire(&c.recip()))
+}
+
+/// Ligne [a_1 … a_n | b] (a·x = b), entière et primitive.
+fn ligne_entiere(l: &PolyMulti, vars: &[String]) -> Vec<BigInt> {
+    let mut r: Vec<BigRational> = vars
+        .iter()
+        .map(|v| l.coefficient(&[(v.clone(), 1)]))
+        .collect();
+    r.push(-l.coefficient(&[]));
+    let ppcm = r.iter().fold(BigInt::one(), |acc, c| acc.lcm(c.denom()));
+    let entiers: Vec<BigInt> = r.iter().map(|c| c.numer() * (&ppcm / c.denom())).collect();
+    let pgcd = entiers.iter().fold(BigInt::zero(), |acc, c| acc.gcd(c));
+    if pgcd.is_zero() || pgcd.is_one() {
+        return entiers;
+    }
+    entiers.into_iter().map(|c| c / &pgcd).collect()
+}
+
+/* ------------------------ Élimination de Bareiss ------------------------ */
+
+/// Forme échelonnée sans fractions ; renvoie les colonnes pivots.
+fn elimine(m: &mut [Vec<BigInt>], n: usize, vars: &[String], preuve: &mut String) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    let mut prec = BigInt::one();
+    let mut r = 0;
+    for k in 0..n {
+        if r == m.len() {
+            break;
+        }
+        let Some(i) = (r..m.len()).find(|&i| !m[i][k].is_zero()) else {
+            continue;
+        };
+        if i != r {
+            m.swap(i, r);
+            preuve.push_str(&format!("L{} ↔ L{}\n", r + 1, i + 1));
+        }
+        let p = m[r][k].clone();
+        preuve.push_str(&format!("pivot {p} (colonne {}) :", vars[k]));
+        let mut operations = Vec::new();
+        for i in r + 1..m.len() {
+            let a = m[i][k].clone();
+            if a.is_zero() && p == prec {
+                continue;
+            }
+            let (tete, queue) = m.split_at_mut(i);
+            for j in k..=n {
+                queue[0][j] = (&p * &queue[0][j] - &a * &tete[r][j]) / &prec;
+            }
+            operations.push(format!(
+                " L{} ← ({p}·L{} - {}·L{}) / {prec}",
+                i + 1,
+                i + 1,
+                entre_parentheses_si_negatif(&a),
+                r + 1
+            ));
+        }
+        if operations.is_empty() {
+            preuve.push_str(" rien à éliminer\n");
+        } else {
+            preuve.push_str(&operations.join(","));
+            preuve.push('\n');
+            preuve.push_str(&format_matrice(m));
+        }
+        prec = p;
+        pivots.push(k);
+        r += 1;
+    }
+    pivots
+}
+
+/// Incompatibilité, solution unique ou famille paramétrée (remontée sur Q).
+fn conclut(m: &[Vec<BigInt>], pivots: &[usize], vars: &[String], preuve: &mut String) -> String {
+    let n = vars.len();
+    let rang = pivots.len();
+    let tuple = if n == 1 {
+        vars[0].clone()
+    } else {
+        format!("({})", vars.join(", "))
+    };
+
+    if let Some(i) = (rang..m.len()).find(|&i| !m[i][n].is_zero()) {
+        preuve.push_str(&format!(
+            "L{} : 0 = {} : système incompatible\n",
+            i + 1,
+            m[i][n]
+        ));
+        return format!("{tuple} ∈ ∅");
+    }
+
+    let libres: Vec<&String> = (0..n)
+        .filter(|k| !pivots.contains(k))
+        .map(|k| &vars[k])
+        .collect();
+    if libres.is_empty() {
+        preuve.push_str(&format!(
+            "rang {rang} = nombre d’inconnues : solution unique\n"
+        ));
+    } else {
+        preuve.push_str(&format!(
+            "rang {rang} < {n} inconnues : {} libre(s)\n",
+            libres
+                .iter()
+                .map(|v| v.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    // remontée : x_k = (b - Σ_{j>k} a_j·x_j) / a_k
+    let mut valeurs: Vec<PolyMulti> = vars.iter().map(|v| PolyMulti::variable(v)).collect();
+    for (r, &k) in pivots.iter().enumerate().rev() {
+        let q = |c: &BigInt| BigRational::from_integer(c.clone());
+        let mut s = PolyMulti::constante(q(&m[r][n]));
+        for j in k + 1..n {
+            s = s.sub(&valeurs[j].mul_scalaire(&q(&m[r][j])));
+        }
+        valeurs[k] = s.mul_scalaire(&q(&m[r][k]).recip());
+        preuve.push_str(&format!(
+            "L{} : {} = {}\n",
+            r + 1,
+            vars[k],
+            format_valeur(&valeurs[k], vars)
+        ));
+    }
+
+    let v: Vec<String> = valeurs.iter().map(|p| format_valeur(p, vars)).collect();
+    let valeurs_txt = if n == 1 {
+        v[0].clone()
+    } else {
+        format!("({})", v.join(", "))
+    };
+    if libres.is_empty() {
+        format!("{tuple} = {valeurs_txt}")
+    } else {
+        let noms: Vec<&str> = libres.iter().map(|v| v.as_str()).collect();
+        format!("{tuple} = {valeurs_txt}, {} ∈ ℝ", noms.join(", "))
+    }
+}
+
+/* ------------------------ Affichage ------------------------ */
+
+/// Valeur affine sur dénominateur commun, constante en tête : “(1-z)/2”, “3-2y+z”, “6/5”.
+fn format_valeur(p: &PolyMulti, vars: &[String]) -> String {
+    let d = p
+        .termes
+        .values()
+        .fold(BigInt::one(), |acc, c| acc.lcm(c.denom()));
+    let mut termes: Vec<(String, BigInt)> = vec![(String::new(), p.coefficient(&[]))]
+        .into_iter()
+        .chain(
+            vars.iter()
+                .map(|v| (v.clone(), p.coefficient(&[(v.clone(), 1)]))),
+        )
+        .filter(|(_, c)| !c.is_zero())
+        .map(|(v, c)| (v, c.numer() * (&d / c.denom())))
+        .collect();
+    if termes.is_empty() {
+        return "0".into();
+    }
+    if let [(v, c)] = termes.as_slice() {
+        if v.is_empty() {
+            return BigRational::new(c.clone(), d).to_string();
+        }
+    }
+    let mut s = String::new();
+    for (v, c) in termes.drain(..) {
+        if c.is_negative() {
+            s.push('-');
+        } else if !s.is_empty() {
+            s.push('+');
+        }
+        let a = c.abs();
+        match (v.is_empty(), a.is_one()) {
+            (false, true) => s.push_str(&v),
+            _ => s.push_str(&format!("{a}{v}")),
+        }
+    }
+    if d.is_one() {
+        s
+    } else if p.termes.len() == 1 {
+        format!("{s}/{d}")
+    } else {
+        format!("({s})/{d}")
+    }
+}
+
+fn entre_parentheses_si_negatif(a: &BigInt) -> String {
+    if a.is_negative() {
+        format!("({a})")
+    } else {
+        a.to_string()
+    }
+}
+
+/// “[2  1 | 3]” ligne par ligne, colonnes alignées à droite.
+fn format_matrice(m: &[Vec<BigInt>]) -> String {
+    let n = m.first().map_or(0, |l| l.len());
+    let largeurs: Vec<usize> = (0..n)
+        .map(|j| m.iter().map(|l| l[j].to_string().len()).max().unwrap_or(1))
+        .collect();
+    let mut s = String::new();
+    for l in m {
+        let cases: Vec<String> = l
+            .iter()
+            .zip(&largeurs)
+            .map(|(c, w)| format!("{c:>w$}"))
+            .collect();
+        s.push_str(&format!(
+            "[{} | {}]\n",
+            cases[..n - 1].join(" "),
+            cases[n - 1]
+        ));
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact(s: &str) -> String {
+        evalue_systeme(s).expect("système attendu").unwrap().0
+    }
+
+    #[test]
+    fn solution_unique_et_bareiss() {
+        assert_eq!(exact("{2x+y=3; x-y=0}"), "(x, y) = (1, 1)");
+        assert_eq!(
+            exact("{x + y + z = 6; 2y + 5z = -4; 2x + 5y - z = 27}"),
+            "(x, y, z) = (5, 3, -2)"
+        );
+        // coefficients rationnels, équation sans '=' (… = 0)
+        assert_eq!(exact("{x/2 + y/3 = 1; x - y}"), "(x, y) = (6/5, 6/5)");
+
+        let (_, _, d) = evalue_systeme("{2x+y=3; x-y=0}").unwrap().unwrap();
+        assert!(d.preuve.contains("L2 ← (2·L2 - 1·L1) / 1"), "{}", d.preuve);
+        assert!(d.preuve.contains("[2  1 | 3]"), "{}", d.preuve);
+    }
+
+    #[test]
+    fn parametre_et_incompatible() {
+        assert_eq!(
+            exact("{x + y + z = 1; x - y = 0}"),
+            "(x, y, z) = ((1-z)/2, (1-z)/2, z), z ∈ ℝ"
+        );
+        assert_eq!(exact("{x + y = 1; 2x + 2y = 3}"), "(x, y) ∈ ∅");
+        let (_, _, d) = evalue_systeme("{x + y = 1; 2x + 2y = 3}").unwrap().unwrap();
+        assert!(
+            d.preuve.contains("0 = 1 : système incompatible"),
+            "{}",
+            d.preuve
+        );
+
+        assert!(evalue_systeme("{x*y = 1; x = 2}").unwrap().is_err());
+        assert!(evalue_systeme("{1/x = 1}").unwrap().is_err());
+        assert!(evalue_systeme("{x = 1;}").unwrap().is_err());
+        assert!(evalue_systeme("x + 1").is_none());
+    }
+}
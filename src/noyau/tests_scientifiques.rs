@@ -9,9 +9,9 @@
 //! - Trig spéciale reconnaît des angles “spéciaux” sous forme coeff*π (via as_coeff_pi).
 //!   Donc: "pi/4 + 2*pi" n’est pas reconnu (Add), à moins d’une réduction modulo dans trig.
 //!   On teste donc la périodicité via des angles équivalents directement reconnus (9π/4, 7π/3, 7π/6).
-//! - Zéro algébrique : le noyau simplifie bien les rationnels,
-//!   mais ne fait pas encore l’“annulation structurelle” A - A => 0 pour expressions non-Rat.
-//!   Donc on teste des zéros qui passent par la simplification rationnelle / racines exactes déjà supportées.
+//! - Zéro algébrique : le noyau simplifie les rationnels et regroupe les termes semblables
+//!   (canon : A - A => 0, 2√2 + √2 => 3√2). Les zéros qui demandent une vraie
+//!   algèbre (dénesting, identités non triviales) restent hors de ces tests.
 //! - Stress : on évite les expressions qui causent profondeur récursive énorme (risque stack overflow).
 //!   On reste sur des bornes petites + budgets courts.
 
@@ -107,10 +107,10 @@ fn sci_zero_algebrique() {
     assert_exact_eq("sqrt(2)*sqrt(2) - 2", "0");
 
     // 1/sqrt(3) = √3/3 (dans notre noyau, la rationalisation renvoie √3/3)
-    // donc √3/3 - √3/3 = 0 serait une annulation structurelle (pas encore),
-    // on teste plutôt l’égalité en comparant les deux côtés séparément.
+    // et √3/3 - √3/3 = 0 par regroupement des termes semblables.
     assert_exact_eq("1/sqrt(3)", "√3/3");
     assert_exact_eq("sqrt(3)/3", "√3/3");
+    assert_exact_eq("sqrt(3)/3 - sqrt(3)/3", "0");
 }
 
 /* ------------------------ Stress contrôlé (sans brûler) ------------------------ */
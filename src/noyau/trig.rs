@@ -5,10 +5,12 @@
 // - Extraction coeff·π via as_coeff_pi_ext()
 // - Réduction modulo période via mod_rationnel() (sin/cos: 2 ; tan: 1)
 // - Table angles spéciaux sur n ∈ {1,2,3,4,6}
+// - n ∈ {8,12} : demi-angle à partir de la table (cos 2θ), racines dénouées par canon
+//   (sin(π/12) = √((2-√3)/4) = (√6-√2)/4)
 
 use num_bigint::BigInt;
 use num_rational::BigRational;
-use num_traits::ToPrimitive;
+use num_traits::{ToPrimitive, Zero};
 
 use super::expr::{mod_rationnel, Expr};
 
@@ -32,8 +34,11 @@ pub enum TrigOutcome {
 /// - Some(Indefini(preuve)) si indéfini (tan(π/2), tan(3π/2))
 /// - None si non reconnu
 pub fn trig_special(x: &Expr, f: TrigFn) -> Option<TrigOutcome> {
-    // 1) extraire coeff·π sur domaine étendu (Add/Sub/Mul/Div rationnels)
-    let coeff = x.as_coeff_pi_ext()?;
+    // 1) extraire coeff·π sur domaine étendu (Add/Sub/Mul/Div rationnels) ; 0 = 0·π
+    let coeff = match x {
+        Expr::Rat(r) if r.is_zero() => BigRational::zero(),
+        _ => x.as_coeff_pi_ext()?,
+    };
 
     // 2) réduire modulo période
     let coeff_reduit = match f {
@@ -47,6 +52,11 @@ pub fn trig_special(x: &Expr, f: TrigFn) -> Option<TrigOutcome> {
     // 4) réduction modulo 2π : k mod (2n) (tables sin/cos/tan codées sur [0,2π))
     let k_mod = k.rem_euclid(2 * n);
 
+    // 4b) π/8, π/12 (k impair) : demi-angle
+    if n == 8 || n == 12 {
+        return demi_angle(k_mod, n, f);
+    }
+
     // Constructeurs
     let rat = |a: i64, b: i64| Expr::Rat(BigRational::new(BigInt::from(a), BigInt::from(b)));
     let sub0 = |e: Expr| Expr::Sub(Box::new(rat(0, 1)), Box::new(e));
@@ -171,6 +181,72 @@ pub fn trig_special(x: &Expr, f: TrigFn) -> Option<TrigOutcome> {
     Some(out)
 }
 
+/* ------------------------ Demi-angle ------------------------ */
+
+/// θ = kπ/n (n = 8 ou 12, k impair dans [0, 2n)) à partir de cos 2θ, sin 2θ (table) :
+/// sin θ = ±√((1 - cos 2θ)/2), cos θ = ±√((1 + cos 2θ)/2), tan θ = (1 - cos 2θ)/sin 2θ.
+/// Signe selon le quadrant de θ.
+fn demi_angle(k: i64, n: i64, f: TrigFn) -> Option<TrigOutcome> {
+    let rat = |a: i64, b: i64| Expr::Rat(BigRational::new(BigInt::from(a), BigInt::from(b)));
+    let double = Expr::Mul(Box::new(rat(2 * k, n)), Box::new(Expr::Pi));
+    let valeur = |g: TrigFn| match trig_special(&double, g) {
+        Some(TrigOutcome::Valeur(v, p)) => Some((v, p)),
+        _ => None,
+    };
+
+    let (c2, preuve_c2) = valeur(TrigFn::Cos)?;
+    let angle_txt = format_angle_kn_pi(k, n);
+    let un = rat(1, 1);
+
+    let (nom, v, formule) = match f {
+        TrigFn::Sin | TrigFn::Cos => {
+            let (nom, radicande, positif, formule) = match f {
+                TrigFn::Sin => (
+                    "sin",
+                    Expr::Sub(Box::new(un), Box::new(c2)),
+                    k < n,
+                    "√((1 - cos 2θ)/2)",
+                ),
+                _ => (
+                    "cos",
+                    Expr::Add(Box::new(un), Box::new(c2)),
+                    2 * k < n || 2 * k > 3 * n,
+                    "√((1 + cos 2θ)/2)",
+                ),
+            };
+            let racine = Expr::Sqrt(Box::new(Expr::Div(
+                Box::new(radicande),
+                Box::new(rat(2, 1)),
+            )));
+            let v = if positif {
+                racine
+            } else {
+                Expr::Sub(Box::new(rat(0, 1)), Box::new(racine))
+            };
+            let signe = if positif { "" } else { "-" };
+            (nom, v, format!("{signe}{formule}"))
+        }
+        TrigFn::Tan => {
+            let (s2, preuve_s2) = valeur(TrigFn::Sin)?;
+            let v = Expr::Div(
+                Box::new(Expr::Sub(Box::new(un), Box::new(c2))),
+                Box::new(s2),
+            );
+            return Some(TrigOutcome::Valeur(
+                v,
+                format!(
+                    "{preuve_c2}\n{preuve_s2}\ntan({angle_txt}) = (1 - cos 2θ)/sin 2θ (demi-angle)"
+                ),
+            ));
+        }
+    };
+
+    Some(TrigOutcome::Valeur(
+        v,
+        format!("{preuve_c2}\n{nom}({angle_txt}) = {formule} (demi-angle)"),
+    ))
+}
+
 /* ------------------------ Outils ------------------------ */
 
 fn format_angle_kn_pi(k: i64, n: i64) -> String {
@@ -190,7 +266,7 @@ fn format_angle_kn_pi(k: i64, n: i64) -> String {
 }
 
 /// Convertit un rationnel en (k,n) i64 réduit.
-/// Accepte seulement n ∈ {1,2,3,4,6}.
+/// Accepte seulement n ∈ {1,2,3,4,6,8,12}.
 fn rational_to_small_kn(r: &BigRational) -> Option<(i64, i64)> {
     let denom = r.denom().to_i64()?;
     let numer = r.numer().to_i64()?;
@@ -199,7 +275,7 @@ fn rational_to_small_kn(r: &BigRational) -> Option<(i64, i64)> {
     let k = numer / g;
     let n = denom / g;
 
-    if [1, 2, 3, 4, 6].contains(&n) {
+    if [1, 2, 3, 4, 6, 8, 12].contains(&n) {
         Some((k, n))
     } else {
         None
@@ -0,0 +1,334 @@
+// src/noyau/valeurs_propres.rs
+//
+// Polynôme caractéristique, valeurs et vecteurs propres : charpoly([[1, 2], [3, 4]])
+// ---------------------------------------------------------------------------------
+// Matrice carrée A à coefficients rationnels :
+// 1) χ_A(λ) = det(λI - A) par Faddeev–LeVerrier (arithmétique exacte sur Q) :
+//      M_1 = I, c_1 = -tr(A·M_1) ; M_k = A·M_{k-1} + c_{k-1}·I, c_k = -tr(A·M_k)/k
+//      χ_A(λ) = λ^n + c_1·λ^(n-1) + … + c_n
+// 2) valeurs propres : racines de χ_A avec multiplicité (partie sans carré, puis résolution
+//    dans ℂ : discriminant, Cardano, Ferrari ; sinon encadrements certifiés des réelles)
+// 3) vecteurs propres : base du noyau de A - μI (Gauss–Jordan exact, matrices.rs) pour
+//    chaque valeur propre réelle exacte μ, radicaux compris (μ = (5+√33)/2…)
+//
+// IMPORTANT (SAFE):
+// - Coefficient non rationnel (√2, x…) : erreur explicite, pas de χ_A symbolique.
+// - Valeur propre non réelle ou seulement encadrée : vecteur propre non calculé, signalé.
+// - Noyau de A - μI réduit à {0} (μ mal prouvée) : erreur, jamais un vecteur deviné.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+use super::equations::{format_racine, racines_multiples, Racine};
+use super::eval::forme_normale;
+use super::expr::Expr;
+use super::format::format_expr_pretty;
+use super::isolation::encadrement_decimal;
+use super::matrices::noyau;
+use super::polynome::Polynome;
+
+type MatQ = Vec<Vec<BigRational>>;
+
+/// Valeur propre, multiplicité algébrique et base de l’espace propre (None : non calculée).
+pub struct EspacePropre {
+    pub valeur: Racine,
+    pub multiplicite: u32,
+    pub base: Option<Vec<Vec<Expr>>>,
+}
+
+/// Lecture d’une matrice carrée à coefficients rationnels (déjà passée par le pipeline).
+pub fn matrice_rationnelle(e: &Expr, nom: &str) -> Result<MatQ, String> {
+    let Expr::Matrice(l) = e else {
+        return Err(format!(
+            "{nom} : matrice attendue (reçu {})",
+            format_expr_pretty(e)
+        ));
+    };
+    if l.iter().any(|ligne| ligne.len() != l.len()) {
+        return Err(format!(
+            "{nom} : matrice carrée attendue ({}×{})",
+            l.len(),
+            l[0].len()
+        ));
+    }
+    l.iter()
+        .map(|ligne| {
+            ligne
+                .iter()
+                .map(|x| match x {
+                    Expr::Rat(r) => Ok(r.clone()),
+                    autre => Err(format!(
+                        "{nom} : coefficients rationnels attendus (reçu {})",
+                        format_expr_pretty(autre)
+                    )),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/* ------------------------ χ_A ------------------------ */
+
+/// χ_A(λ) = det(λI - A), unitaire de degré n (Faddeev–LeVerrier).
+pub fn polynome_caracteristique(a: &MatQ, var: &str, preuve: &mut String) -> Polynome {
+    let n = a.len();
+    // coefficients du plus haut degré au plus bas : c_0 = 1, c_1, …, c_n
+    let mut c = vec![BigRational::one()];
+    let mut m = identite(n);
+    for k in 1..=n {
+        if k > 1 {
+            m = produit(a, &m);
+            for (i, ligne) in m.iter_mut().enumerate() {
+                ligne[i] += &c[k - 1];
+            }
+        }
+        let am = produit(a, &m);
+        let trace: BigRational = (0..n).map(|i| am[i][i].clone()).sum();
+        let ck = -trace / BigRational::from_integer(BigInt::from(k));
+        preuve.push_str(&format!(
+            "Faddeev–LeVerrier k = {k} : c{k} = -tr(A·M{k})/{k} = {ck}\n"
+        ));
+        c.push(ck);
+    }
+    c.reverse();
+    let p = Polynome::nouveau(c);
+    preuve.push_str(&format!(
+        "χ_A({var}) = det({var}I - A) = {}\n",
+        p.format(var)
+    ));
+    p
+}
+
+fn identite(n: usize) -> MatQ {
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    if i == j {
+                        BigRational::one()
+                    } else {
+                        BigRational::zero()
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn produit(a: &MatQ, b: &MatQ) -> MatQ {
+    let n = a.len();
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| (0..n).map(|k| &a[i][k] * &b[k][j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+/* ------------------------ Valeurs et vecteurs propres ------------------------ */
+
+/// Racines de χ_A dans ℂ avec multiplicité (ordre croissant des parties réelles).
+pub fn valeurs_propres(
+    a: &MatQ,
+    var: &str,
+    preuve: &mut String,
+) -> Result<Vec<(Racine, u32)>, String> {
+    let p = polynome_caracteristique(a, var, preuve);
+    racines_multiples(&p, var, preuve)
+}
+
+/// Espaces propres : noyau de A - μI pour chaque valeur propre réelle exacte μ
+/// (valeurs seulement encadrées : intervalles affinés à `digits` chiffres).
+pub fn espaces_propres(
+    a: &MatQ,
+    var: &str,
+    digits: usize,
+    preuve: &mut String,
+) -> Result<Vec<EspacePropre>, String> {
+    let mut out = Vec::new();
+    for (valeur, multiplicite) in valeurs_propres(a, var, preuve)? {
+        let base = match &valeur {
+            Racine::Exacte { re, .. } if valeur.est_reelle() => {
+                let mu = format_racine(re, &Expr::Rat(BigRational::zero()));
+                let base = base_propre(a, re, &mu)?;
+                if base.len() < multiplicite as usize {
+                    preuve.push_str(&format!(
+                        "{var} = {mu} : dim ker(A - {mu}·I) = {} < {multiplicite} (A non diagonalisable)\n",
+                        base.len()
+                    ));
+                }
+                Some(base)
+            }
+            _ => {
+                preuve.push_str(&format!(
+                    "{} : valeur propre non réelle ou non exprimable, vecteur propre non calculé\n",
+                    nom_valeur(var, &valeur, digits)
+                ));
+                None
+            }
+        };
+        out.push(EspacePropre {
+            valeur,
+            multiplicite,
+            base,
+        });
+    }
+    Ok(out)
+}
+
+/// Base de ker(A - μI), vecteurs rationnels ramenés à des entiers premiers entre eux.
+fn base_propre(a: &MatQ, mu: &Expr, nom_mu: &str) -> Result<Vec<Vec<Expr>>, String> {
+    let l = a
+        .iter()
+        .enumerate()
+        .map(|(i, ligne)| {
+            ligne
+                .iter()
+                .enumerate()
+                .map(|(j, x)| {
+                    let x = Expr::Rat(x.clone());
+                    if i == j {
+                        let d = Expr::Sub(Box::new(x), Box::new(mu.clone()));
+                        forme_normale(d, &mut String::new())
+                    } else {
+                        x
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let base = noyau(l, "eigenvects")?;
+    if base.is_empty() {
+        return Err(format!(
+            "eigenvects : ker(A - {nom_mu}·I) = {{0}}, valeur propre non confirmée"
+        ));
+    }
+    Ok(base.into_iter().map(entiers).collect())
+}
+
+/// (1/2, 1) => (1, 2) si toutes les composantes sont rationnelles ; sinon inchangé.
+fn entiers(v: Vec<Expr>) -> Vec<Expr> {
+    let Some(q): Option<Vec<BigRational>> = v
+        .iter()
+        .map(|x| match x {
+            Expr::Rat(r) => Some(r.clone()),
+            _ => None,
+        })
+        .collect()
+    else {
+        return v;
+    };
+    let ppcm = q.iter().fold(BigInt::one(), |m, r| m.lcm(r.denom()));
+    let entiers: Vec<BigInt> = q.iter().map(|r| (r * &ppcm).to_integer()).collect();
+    let pgcd = entiers.iter().fold(BigInt::zero(), |g, x| g.gcd(x));
+    if pgcd.is_zero() {
+        return v;
+    }
+    entiers
+        .into_iter()
+        .map(|x| Expr::Rat(BigRational::from_integer(x / &pgcd)))
+        .collect()
+}
+
+/// “λ = 2+√2”, ou “λ ∈ ]1.532088, 1.532089[” pour une racine isolée : encadrement affiné
+/// à `digits` chiffres (isolation.rs, comme solve / realroots).
+fn nom_valeur(var: &str, r: &Racine, digits: usize) -> String {
+    let zero = Expr::Rat(BigRational::zero());
+    match r {
+        Racine::Exacte { re, im } => format!("{var} = {}", format_racine(re, im)),
+        Racine::Isolee { encadrement, .. } if encadrement.bas == encadrement.haut => {
+            format!(
+                "{var} = {}",
+                format_racine(&Expr::Rat(encadrement.bas.clone()), &zero)
+            )
+        }
+        Racine::Isolee {
+            polynome,
+            encadrement,
+        } => {
+            let (bas, haut) = encadrement_decimal(polynome, encadrement, digits);
+            format!("{var} ∈ ]{bas}, {haut}[")
+        }
+    }
+}
+
+/// “λ = 1 : (-1, 1)” ; une ligne par valeur propre, multiplicité > 1 signalée.
+pub fn format_espaces_propres(var: &str, espaces: &[EspacePropre], digits: usize) -> String {
+    espaces
+        .iter()
+        .map(|e| {
+            let valeur = nom_valeur(var, &e.valeur, digits);
+            let multiplicite = match e.multiplicite {
+                1 => String::new(),
+                2 => " (double)".into(),
+                3 => " (triple)".into(),
+                k => format!(" (×{k})"),
+            };
+            let base = match &e.base {
+                Some(b) => b
+                    .iter()
+                    .map(|v| {
+                        let c: Vec<String> = v
+                            .iter()
+                            .map(|x| format_racine(x, &Expr::Rat(BigRational::zero())))
+                            .collect();
+                        format!("({})", c.join(", "))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                None => "vecteur propre non calculé".into(),
+            };
+            format!("{valeur}{multiplicite} : {base}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noyau::eval::forme_exacte;
+
+    fn matrice(s: &str) -> MatQ {
+        let (e, _) = forme_exacte(s).unwrap();
+        matrice_rationnelle(&e, "test").unwrap()
+    }
+
+    #[test]
+    fn faddeev_leverrier() {
+        let a = matrice("[[1, 2], [3, 4]]");
+        let p = polynome_caracteristique(&a, "λ", &mut String::new());
+        assert_eq!(p.format("λ"), "λ^2-5λ-2");
+
+        // χ_A(A) = 0 sur un 3×3 (Cayley–Hamilton) : trace et det aux bonnes places
+        let a = matrice("[[2, 0, 1], [1, 3, 0], [0, 1, 4]]");
+        let p = polynome_caracteristique(&a, "λ", &mut String::new());
+        assert_eq!(p.coeff(2), BigRational::from_integer((-9).into()));
+        assert_eq!(p.coeff(0), BigRational::from_integer((-25).into()));
+    }
+
+    #[test]
+    fn espaces_propres_diagonalisables_ou_non() {
+        let a = matrice("[[2, 1], [1, 2]]");
+        let e = espaces_propres(&a, "λ", 10, &mut String::new()).unwrap();
+        assert_eq!(
+            format_espaces_propres("λ", &e, 10),
+            "λ = 1 : (-1, 1)\nλ = 3 : (1, 1)"
+        );
+
+        // bloc de Jordan : valeur double, espace propre de dimension 1
+        let a = matrice("[[1, 1], [0, 1]]");
+        let mut preuve = String::new();
+        let e = espaces_propres(&a, "λ", 10, &mut preuve).unwrap();
+        assert_eq!(
+            format_espaces_propres("λ", &e, 10),
+            "λ = 1 (double) : (1, 0)"
+        );
+        assert!(preuve.contains("non diagonalisable"), "{preuve}");
+
+        assert!(matrice_rationnelle(&forme_exacte("[[1, 2]]").unwrap().0, "t").is_err());
+    }
+}